@@ -0,0 +1,809 @@
+//! Named actions and axes bound to rebindable keys, mouse buttons and gamepad inputs.
+//!
+//! Game code asks about "jump" or "move" instead of particular keys; an `ActionMap`
+//! holds the bindings, is updated from a window's `Input` once per frame, and can be
+//! saved to and loaded from a small text format:
+//!
+//! ```text
+//! # comments start with '#'
+//! action jump = key:Space, button:A
+//! action save = chord(key:LeftControl + key:S)
+//! action fire = mouse:Left, axis:RightTrigger>0.5
+//! axis zoom = keys(key:Q / key:E), scroll:y*1, axis:-RightY ; deadzone 0.15
+//! axis2d move = keys(key:W / key:S / key:A / key:D), stick:Left ; deadzone 0.2
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use crate::kat::{GamepadAxis, GamepadButton, Input, Key, MouseButton};
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum AxisDirection {
+    Positive,
+    Negative
+}
+
+impl AxisDirection {
+    const fn sign(&self) -> f32 {
+        match self {
+            AxisDirection::Positive => { 1.0 }
+            AxisDirection::Negative => { -1.0 }
+        }
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum PointerAxis {
+    X,
+    Y
+}
+
+/// A digital input: something that is either held or not.
+#[derive(Clone,PartialEq,Debug)]
+pub enum Binding {
+    Key(Key),
+    MouseButton(MouseButton),
+    GamepadButton(GamepadButton),
+    /// Held while the axis is pushed at least `threshold` in `direction`.
+    GamepadAxis { axis: GamepadAxis, direction: AxisDirection, threshold: f32 },
+    /// Held while every binding in it is held, pressed in any order.
+    Chord(Vec<Binding>)
+}
+
+/// A source for a one-dimensional axis.
+#[derive(Clone,PartialEq,Debug)]
+pub enum AxisBinding {
+    /// -1 while `negative` is held, +1 while `positive` is held, 0 for both or neither.
+    Digital { negative: Binding, positive: Binding },
+    Gamepad { axis: GamepadAxis, inverted: bool },
+    /// Cursor motion this frame times `scale`; unbounded and not subject to the dead zone.
+    MouseMotion { axis: PointerAxis, scale: f32 },
+    /// Scroll this frame times `scale`; unbounded and not subject to the dead zone.
+    Scroll { axis: PointerAxis, scale: f32 }
+}
+
+/// A source for a two-dimensional axis. Y points up for every source.
+#[derive(Clone,PartialEq,Debug)]
+pub enum Axis2Binding {
+    /// Four directions, e.g. WASD. Diagonals are normalized so they aren't faster.
+    Digital { up: Binding, down: Binding, left: Binding, right: Binding },
+    /// A stick, with a radial dead zone.
+    Stick { x: GamepadAxis, y: GamepadAxis },
+    /// Cursor motion this frame times `scale`.
+    MouseMotion { scale: f32 }
+}
+
+impl Binding {
+    pub fn is_held(&self, input: &Input) -> bool {
+        match self {
+            Binding::Key(key) => input.is_key_down(*key),
+            Binding::MouseButton(button) => input.is_button_down(*button),
+            Binding::GamepadButton(button) => input.gamepad().button(*button),
+            Binding::GamepadAxis { axis, direction, threshold } => {
+                input.gamepad().axis(*axis) * direction.sign() >= *threshold
+            }
+            Binding::Chord(bindings) => !bindings.is_empty() && bindings.iter().all(|b| b.is_held(input))
+        }
+    }
+
+    fn strength(&self, input: &Input) -> f32 {
+        if self.is_held(input) { 1.0 } else { 0.0 }
+    }
+}
+
+impl AxisBinding {
+    pub fn value(&self, input: &Input, dead_zone: f32) -> f32 {
+        match self {
+            AxisBinding::Digital { negative, positive } => positive.strength(input) - negative.strength(input),
+            AxisBinding::Gamepad { axis, inverted } => {
+                let value = apply_dead_zone(input.gamepad().axis(*axis), dead_zone);
+                if *inverted { -value } else { value }
+            }
+            AxisBinding::MouseMotion { axis, scale } => pointer_component(input.mouse_delta(), *axis) * scale,
+            AxisBinding::Scroll { axis, scale } => pointer_component(input.scroll_delta(), *axis) * scale
+        }
+    }
+}
+
+impl Axis2Binding {
+    pub fn value(&self, input: &Input, dead_zone: f32) -> (f32, f32) {
+        match self {
+            Axis2Binding::Digital { up, down, left, right } => {
+                let x = right.strength(input) - left.strength(input);
+                let y = up.strength(input) - down.strength(input);
+                let length = (x * x + y * y).sqrt();
+                if length > 1.0 { (x / length, y / length) } else { (x, y) }
+            }
+            Axis2Binding::Stick { x, y } => {
+                let gamepad = input.gamepad();
+                apply_radial_dead_zone((gamepad.axis(*x), -gamepad.axis(*y)), dead_zone)
+            }
+            Axis2Binding::MouseMotion { scale } => {
+                let (dx, dy) = input.mouse_delta();
+                (dx as f32 * scale, -dy as f32 * scale)
+            }
+        }
+    }
+}
+
+fn pointer_component(value: (f64, f64), axis: PointerAxis) -> f32 {
+    match axis {
+        PointerAxis::X => value.0 as f32,
+        PointerAxis::Y => value.1 as f32
+    }
+}
+
+/// Zeroes values inside the dead zone and rescales the rest so output still starts at 0.
+pub fn apply_dead_zone(value: f32, dead_zone: f32) -> f32 {
+    let magnitude = value.abs();
+    if magnitude <= dead_zone || dead_zone >= 1.0 {
+        return 0.0;
+    }
+    return value.signum() * ((magnitude - dead_zone) / (1.0 - dead_zone)).min(1.0);
+}
+
+/// Like `apply_dead_zone`, but on the stick's distance from center so diagonals aren't clipped.
+pub fn apply_radial_dead_zone(value: (f32, f32), dead_zone: f32) -> (f32, f32) {
+    let length = (value.0 * value.0 + value.1 * value.1).sqrt();
+    if length <= dead_zone || dead_zone >= 1.0 {
+        return (0.0, 0.0);
+    }
+    let scaled = ((length - dead_zone) / (1.0 - dead_zone)).min(1.0);
+    return (value.0 / length * scaled, value.1 / length * scaled);
+}
+
+#[derive(Clone,Debug,Default)]
+struct ActionEntry {
+    bindings: Vec<Binding>,
+    down: bool,
+    pressed: bool,
+    released: bool
+}
+
+#[derive(Clone,Debug,Default)]
+struct AxisEntry {
+    bindings: Vec<AxisBinding>,
+    dead_zone: f32,
+    value: f32
+}
+
+#[derive(Clone,Debug,Default)]
+struct Axis2Entry {
+    bindings: Vec<Axis2Binding>,
+    dead_zone: f32,
+    value: (f32, f32)
+}
+
+/// Named actions, axes and 2D axes with their bindings and current values.
+///
+/// Queries for names that were never bound report released / zero.
+#[derive(Clone,Debug,Default)]
+pub struct ActionMap {
+    actions: BTreeMap<String, ActionEntry>,
+    axes: BTreeMap<String, AxisEntry>,
+    axes2d: BTreeMap<String, Axis2Entry>
+}
+
+pub const DEFAULT_DEAD_ZONE: f32 = 0.15;
+
+impl ActionMap {
+    pub fn new() -> ActionMap {
+        ActionMap::default()
+    }
+
+    pub fn bind_action(&mut self, name: &str, binding: Binding) -> &mut ActionMap {
+        check_name(name);
+        self.actions.entry(name.to_string()).or_default().bindings.push(binding);
+        self
+    }
+
+    pub fn bind_axis(&mut self, name: &str, binding: AxisBinding) -> &mut ActionMap {
+        check_name(name);
+        self.axes.entry(name.to_string()).or_insert_with(|| AxisEntry { dead_zone: DEFAULT_DEAD_ZONE, ..Default::default() })
+            .bindings.push(binding);
+        self
+    }
+
+    pub fn bind_axis2d(&mut self, name: &str, binding: Axis2Binding) -> &mut ActionMap {
+        check_name(name);
+        self.axes2d.entry(name.to_string()).or_insert_with(|| Axis2Entry { dead_zone: DEFAULT_DEAD_ZONE, ..Default::default() })
+            .bindings.push(binding);
+        self
+    }
+
+    /// Sets the dead zone of the 1D or 2D axis called `name`.
+    pub fn set_dead_zone(&mut self, name: &str, dead_zone: f32) -> &mut ActionMap {
+        if let Some(axis) = self.axes.get_mut(name) {
+            axis.dead_zone = dead_zone;
+        }
+        if let Some(axis) = self.axes2d.get_mut(name) {
+            axis.dead_zone = dead_zone;
+        }
+        self
+    }
+
+    /// Removes every binding for `name`, keeping its dead zone.
+    pub fn unbind(&mut self, name: &str) {
+        if let Some(action) = self.actions.get_mut(name) {
+            action.bindings.clear();
+        }
+        if let Some(axis) = self.axes.get_mut(name) {
+            axis.bindings.clear();
+        }
+        if let Some(axis) = self.axes2d.get_mut(name) {
+            axis.bindings.clear();
+        }
+    }
+
+    /// Replaces the bindings of an action, e.g. from a rebinding menu.
+    pub fn rebind_action(&mut self, name: &str, bindings: Vec<Binding>) {
+        check_name(name);
+        self.actions.entry(name.to_string()).or_default().bindings = bindings;
+    }
+
+    pub fn action_bindings(&self, name: &str) -> &[Binding] {
+        self.actions.get(name).map_or(&[], |a| &a.bindings)
+    }
+
+    pub fn axis_bindings(&self, name: &str) -> &[AxisBinding] {
+        self.axes.get(name).map_or(&[], |a| &a.bindings)
+    }
+
+    pub fn axis2d_bindings(&self, name: &str) -> &[Axis2Binding] {
+        self.axes2d.get(name).map_or(&[], |a| &a.bindings)
+    }
+
+    /// Recomputes every action and axis. Call once per frame after events are polled.
+    ///
+    /// When several bindings feed one axis, the one pushed furthest wins.
+    pub fn update(&mut self, input: &Input) {
+        for action in self.actions.values_mut() {
+            let down = action.bindings.iter().any(|b| b.is_held(input));
+            action.pressed = down && !action.down;
+            action.released = !down && action.down;
+            action.down = down;
+        }
+        for axis in self.axes.values_mut() {
+            axis.value = axis.bindings.iter()
+                .map(|b| b.value(input, axis.dead_zone))
+                .fold(0.0, |best, v| if v.abs() > best.abs() { v } else { best });
+        }
+        for axis in self.axes2d.values_mut() {
+            axis.value = axis.bindings.iter()
+                .map(|b| b.value(input, axis.dead_zone))
+                .fold((0.0, 0.0), |best, v| {
+                    if v.0 * v.0 + v.1 * v.1 > best.0 * best.0 + best.1 * best.1 { v } else { best }
+                });
+        }
+    }
+
+    pub fn is_down(&self, action: &str) -> bool {
+        self.actions.get(action).is_some_and(|a| a.down)
+    }
+
+    pub fn was_pressed_this_frame(&self, action: &str) -> bool {
+        self.actions.get(action).is_some_and(|a| a.pressed)
+    }
+
+    pub fn was_released_this_frame(&self, action: &str) -> bool {
+        self.actions.get(action).is_some_and(|a| a.released)
+    }
+
+    pub fn axis(&self, name: &str) -> f32 {
+        self.axes.get(name).map_or(0.0, |a| a.value)
+    }
+
+    pub fn axis2d(&self, name: &str) -> glm::Vec2 {
+        let (x, y) = self.axes2d.get(name).map_or((0.0, 0.0), |a| a.value);
+        glm::vec2(x, y)
+    }
+
+    /// Writes every binding in the format described in the module docs.
+    pub fn to_config(&self) -> String {
+        let mut out = String::new();
+        for (name, action) in &self.actions {
+            out.push_str(&format!("action {} = {}\n", name, join(&action.bindings)));
+        }
+        for (name, axis) in &self.axes {
+            out.push_str(&format!("axis {} = {} ; deadzone {}\n", name, join(&axis.bindings), axis.dead_zone));
+        }
+        for (name, axis) in &self.axes2d {
+            out.push_str(&format!("axis2d {} = {} ; deadzone {}\n", name, join(&axis.bindings), axis.dead_zone));
+        }
+        return out;
+    }
+
+    /// Replaces the bindings of every name listed in `config`. Names the config doesn't
+    /// mention keep their current bindings, so defaults survive older config files.
+    ///
+    /// Nothing is changed if any line fails to parse.
+    pub fn load_config(&mut self, config: &str) -> Result<(), BindingError> {
+        let mut parsed = ActionMap::new();
+        let mut dead_zones = Vec::new();
+        for (index, line) in config.lines().enumerate() {
+            let parse_error = |message: String| BindingError::Parse { line: index + 1, message };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (entry, options) = match line.split_once(';') {
+                Some((entry, options)) => (entry, Some(options)),
+                None => (line, None)
+            };
+            let (head, bindings) = entry.split_once('=').ok_or_else(|| parse_error("expected '='".to_string()))?;
+            let mut head = head.split_whitespace();
+            let (kind, name) = match (head.next(), head.next(), head.next()) {
+                (Some(kind), Some(name), None) => (kind, name),
+                _ => return Err(parse_error("expected '<kind> <name> = <bindings>'".to_string()))
+            };
+            let dead_zone = match options {
+                Some(options) => Some(parse_dead_zone(options).map_err(parse_error)?),
+                None => None
+            };
+            if dead_zone.is_some() && kind == "action" {
+                return Err(parse_error("actions don't have a dead zone".to_string()));
+            }
+            if !is_valid_name(name) {
+                return Err(parse_error(format!("invalid name '{}'", name)));
+            }
+
+            // An empty list is a name that was unbound on purpose.
+            let bindings = if bindings.trim().is_empty() { Vec::new() } else { split_top_level(bindings, ',') };
+            match kind {
+                "action" => {
+                    let bindings = bindings.iter().map(|b| b.parse()).collect::<Result<Vec<Binding>, _>>().map_err(parse_error)?;
+                    parsed.actions.entry(name.to_string()).or_default().bindings.extend(bindings);
+                }
+                "axis" => {
+                    let bindings = bindings.iter().map(|b| b.parse()).collect::<Result<Vec<AxisBinding>, _>>().map_err(parse_error)?;
+                    parsed.axes.entry(name.to_string()).or_default().bindings.extend(bindings);
+                }
+                "axis2d" => {
+                    let bindings = bindings.iter().map(|b| b.parse()).collect::<Result<Vec<Axis2Binding>, _>>().map_err(parse_error)?;
+                    parsed.axes2d.entry(name.to_string()).or_default().bindings.extend(bindings);
+                }
+                _ => return Err(parse_error(format!("unknown kind '{}', expected action, axis or axis2d", kind)))
+            }
+            if let Some(dead_zone) = dead_zone {
+                dead_zones.push((name.to_string(), dead_zone));
+            }
+        }
+
+        for (name, action) in parsed.actions {
+            self.actions.entry(name).or_default().bindings = action.bindings;
+        }
+        for (name, axis) in parsed.axes {
+            self.axes.entry(name).or_insert_with(|| AxisEntry { dead_zone: DEFAULT_DEAD_ZONE, ..Default::default() })
+                .bindings = axis.bindings;
+        }
+        for (name, axis) in parsed.axes2d {
+            self.axes2d.entry(name).or_insert_with(|| Axis2Entry { dead_zone: DEFAULT_DEAD_ZONE, ..Default::default() })
+                .bindings = axis.bindings;
+        }
+        for (name, dead_zone) in dead_zones {
+            self.set_dead_zone(&name, dead_zone);
+        }
+        Ok(())
+    }
+
+    pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), BindingError> {
+        let config = std::fs::read_to_string(path).map_err(BindingError::Io)?;
+        self.load_config(&config)
+    }
+
+    pub fn save_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        std::fs::write(path, self.to_config())
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(|c: char| c.is_whitespace() || c == '=' || c == ';' || c == '#')
+}
+
+fn check_name(name: &str) {
+    if !is_valid_name(name) {
+        panic!("Invalid action name '{}': names can't be empty or contain whitespace, '=', ';' or '#'", name);
+    }
+}
+
+fn join<T: Display>(bindings: &[T]) -> String {
+    bindings.iter().map(|b| b.to_string()).collect::<Vec<String>>().join(", ")
+}
+
+fn parse_dead_zone(options: &str) -> Result<f32, String> {
+    let mut words = options.split_whitespace();
+    match (words.next(), words.next(), words.next()) {
+        (Some("deadzone"), Some(value), None) => {
+            let value: f32 = value.parse().map_err(|_| format!("invalid dead zone '{}'", value))?;
+            if !(0.0..1.0).contains(&value) {
+                return Err(format!("dead zone {} is outside [0, 1)", value));
+            }
+            Ok(value)
+        }
+        _ => Err(format!("expected 'deadzone <value>' after ';', found '{}'", options.trim()))
+    }
+}
+
+/// Splits on `separator` outside of parentheses, trimming each part.
+fn split_top_level(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    return parts;
+}
+
+/// Strips `prefix(` and `)`, returning what's inside.
+fn call_args<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    s.strip_prefix(prefix)?.trim_start().strip_prefix('(')?.strip_suffix(')')
+}
+
+fn parse_scale(s: &str) -> Result<(&str, f32), String> {
+    match s.split_once('*') {
+        Some((source, scale)) => {
+            let scale = scale.trim().parse().map_err(|_| format!("invalid scale '{}'", scale.trim()))?;
+            Ok((source.trim(), scale))
+        }
+        None => Ok((s, 1.0))
+    }
+}
+
+fn parse_pointer_axis(s: &str) -> Result<PointerAxis, String> {
+    match s {
+        "x" => Ok(PointerAxis::X),
+        "y" => Ok(PointerAxis::Y),
+        _ => Err(format!("unknown pointer axis '{}', expected x or y", s))
+    }
+}
+
+fn parse_gamepad_axis(s: &str) -> Result<GamepadAxis, String> {
+    GamepadAxis::from_name(s).ok_or_else(|| format!("unknown gamepad axis '{}'", s))
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Binding::Key(key) => write!(f, "key:{}", key.name()),
+            Binding::MouseButton(button) => write!(f, "mouse:{}", button.name()),
+            Binding::GamepadButton(button) => write!(f, "button:{}", button.name()),
+            Binding::GamepadAxis { axis, direction: AxisDirection::Positive, threshold } => {
+                write!(f, "axis:{}>{}", axis.name(), threshold)
+            }
+            Binding::GamepadAxis { axis, direction: AxisDirection::Negative, threshold } => {
+                write!(f, "axis:{}<-{}", axis.name(), threshold)
+            }
+            Binding::Chord(bindings) => {
+                write!(f, "chord({})", bindings.iter().map(|b| b.to_string()).collect::<Vec<String>>().join(" + "))
+            }
+        }
+    }
+}
+
+impl FromStr for Binding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Binding, String> {
+        let s = s.trim();
+        if let Some(inner) = call_args(s, "chord") {
+            let bindings = split_top_level(inner, '+').iter().map(|b| b.parse()).collect::<Result<Vec<Binding>, _>>()?;
+            return Ok(Binding::Chord(bindings));
+        }
+        let (source, value) = s.split_once(':').ok_or_else(|| format!("expected '<source>:<input>', found '{}'", s))?;
+        let value = value.trim();
+        match source.trim() {
+            "key" => Key::from_name(value).map(Binding::Key).ok_or_else(|| format!("unknown key '{}'", value)),
+            "mouse" => MouseButton::from_name(value).map(Binding::MouseButton)
+                .ok_or_else(|| format!("unknown mouse button '{}'", value)),
+            "button" => GamepadButton::from_name(value).map(Binding::GamepadButton)
+                .ok_or_else(|| format!("unknown gamepad button '{}'", value)),
+            "axis" => {
+                let (axis, direction, threshold) = if let Some((axis, threshold)) = value.split_once('>') {
+                    (axis, AxisDirection::Positive, threshold.trim())
+                } else if let Some((axis, threshold)) = value.split_once("<-") {
+                    (axis, AxisDirection::Negative, threshold.trim())
+                } else {
+                    return Err(format!("expected 'axis:<axis>>threshold' or 'axis:<axis><-threshold', found '{}'", s));
+                };
+                let threshold = threshold.parse().map_err(|_| format!("invalid threshold '{}'", threshold))?;
+                Ok(Binding::GamepadAxis { axis: parse_gamepad_axis(axis.trim())?, direction, threshold })
+            }
+            _ => Err(format!("unknown input source '{}', expected key, mouse, button, axis or chord", source.trim()))
+        }
+    }
+}
+
+impl Display for AxisBinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let pointer = |axis: &PointerAxis| match axis {
+            PointerAxis::X => "x",
+            PointerAxis::Y => "y"
+        };
+        match self {
+            AxisBinding::Digital { negative, positive } => write!(f, "keys({} / {})", negative, positive),
+            AxisBinding::Gamepad { axis, inverted } => write!(f, "axis:{}{}", if *inverted { "-" } else { "" }, axis.name()),
+            AxisBinding::MouseMotion { axis, scale } => write!(f, "mouse:{}*{}", pointer(axis), scale),
+            AxisBinding::Scroll { axis, scale } => write!(f, "scroll:{}*{}", pointer(axis), scale)
+        }
+    }
+}
+
+impl FromStr for AxisBinding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<AxisBinding, String> {
+        let s = s.trim();
+        if let Some(inner) = call_args(s, "keys") {
+            return match split_top_level(inner, '/').as_slice() {
+                [negative, positive] => Ok(AxisBinding::Digital { negative: negative.parse()?, positive: positive.parse()? }),
+                _ => Err(format!("expected 'keys(<negative> / <positive>)', found '{}'", s))
+            };
+        }
+        let (source, value) = s.split_once(':').ok_or_else(|| format!("expected '<source>:<axis>', found '{}'", s))?;
+        let value = value.trim();
+        match source.trim() {
+            "axis" => match value.strip_prefix('-') {
+                Some(axis) => Ok(AxisBinding::Gamepad { axis: parse_gamepad_axis(axis.trim())?, inverted: true }),
+                None => Ok(AxisBinding::Gamepad { axis: parse_gamepad_axis(value)?, inverted: false })
+            },
+            "mouse" => {
+                let (axis, scale) = parse_scale(value)?;
+                Ok(AxisBinding::MouseMotion { axis: parse_pointer_axis(axis)?, scale })
+            }
+            "scroll" => {
+                let (axis, scale) = parse_scale(value)?;
+                Ok(AxisBinding::Scroll { axis: parse_pointer_axis(axis)?, scale })
+            }
+            _ => Err(format!("unknown axis source '{}', expected keys, axis, mouse or scroll", source.trim()))
+        }
+    }
+}
+
+impl Display for Axis2Binding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Axis2Binding::Digital { up, down, left, right } => write!(f, "keys({} / {} / {} / {})", up, down, left, right),
+            Axis2Binding::Stick { x: GamepadAxis::LeftX, y: GamepadAxis::LeftY } => write!(f, "stick:Left"),
+            Axis2Binding::Stick { x: GamepadAxis::RightX, y: GamepadAxis::RightY } => write!(f, "stick:Right"),
+            Axis2Binding::Stick { x, y } => write!(f, "stick:{}/{}", x.name(), y.name()),
+            Axis2Binding::MouseMotion { scale } => write!(f, "mouse:xy*{}", scale)
+        }
+    }
+}
+
+impl FromStr for Axis2Binding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Axis2Binding, String> {
+        let s = s.trim();
+        if let Some(inner) = call_args(s, "keys") {
+            return match split_top_level(inner, '/').as_slice() {
+                [up, down, left, right] => Ok(Axis2Binding::Digital {
+                    up: up.parse()?,
+                    down: down.parse()?,
+                    left: left.parse()?,
+                    right: right.parse()?
+                }),
+                _ => Err(format!("expected 'keys(<up> / <down> / <left> / <right>)', found '{}'", s))
+            };
+        }
+        let (source, value) = s.split_once(':').ok_or_else(|| format!("expected '<source>:<axes>', found '{}'", s))?;
+        let value = value.trim();
+        match source.trim() {
+            "stick" => match value {
+                "Left" => Ok(Axis2Binding::Stick { x: GamepadAxis::LeftX, y: GamepadAxis::LeftY }),
+                "Right" => Ok(Axis2Binding::Stick { x: GamepadAxis::RightX, y: GamepadAxis::RightY }),
+                _ => match value.split_once('/') {
+                    Some((x, y)) => Ok(Axis2Binding::Stick { x: parse_gamepad_axis(x.trim())?, y: parse_gamepad_axis(y.trim())? }),
+                    None => Err(format!("expected 'stick:Left', 'stick:Right' or 'stick:<x>/<y>', found '{}'", s))
+                }
+            },
+            "mouse" => match parse_scale(value)? {
+                ("xy", scale) => Ok(Axis2Binding::MouseMotion { scale }),
+                _ => Err(format!("expected 'mouse:xy*<scale>', found '{}'", s))
+            },
+            _ => Err(format!("unknown 2D axis source '{}', expected keys, stick or mouse", source.trim()))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BindingError {
+    Io(io::Error),
+    Parse { line: usize, message: String }
+}
+
+impl Display for BindingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindingError::Io(e) => write!(f, "Failed to read bindings: {}", e),
+            BindingError::Parse { line, message } => write!(f, "Bindings line {}: {}", line, message)
+        }
+    }
+}
+
+impl std::error::Error for BindingError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kat::{Action, Event, GamepadState, Modifiers};
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-5
+    }
+
+    /// Starts a frame with `events`, then updates `map` from it.
+    fn frame(input: &mut Input, map: &mut ActionMap, events: &[Event]) {
+        input.begin_frame();
+        for event in events {
+            input.handle_event(event);
+        }
+        map.update(input);
+    }
+
+    fn press(key: Key) -> Event {
+        Event::Key { key, scancode: 0, action: Action::Press, modifiers: Modifiers::default() }
+    }
+
+    fn release(key: Key) -> Event {
+        Event::Key { key, scancode: 0, action: Action::Release, modifiers: Modifiers::default() }
+    }
+
+    fn gamepad(axes: &[(GamepadAxis, f32)]) -> Option<GamepadState> {
+        let mut state = GamepadState::default();
+        for &(axis, value) in axes {
+            state.axes[axis as usize] = value;
+        }
+        Some(state)
+    }
+
+    #[test]
+    fn actions_report_edges_once() {
+        let (mut input, mut map) = (Input::new(), ActionMap::new());
+        map.bind_action("jump", Binding::Key(Key::Space)).bind_action("jump", Binding::Key(Key::Enter));
+        frame(&mut input, &mut map, &[press(Key::Space)]);
+        assert!(map.is_down("jump") && map.was_pressed_this_frame("jump"));
+        // A second binding going down while the first is held is no new press.
+        frame(&mut input, &mut map, &[press(Key::Enter)]);
+        assert!(map.is_down("jump") && !map.was_pressed_this_frame("jump"));
+        frame(&mut input, &mut map, &[release(Key::Space)]);
+        assert!(map.is_down("jump") && !map.was_released_this_frame("jump"));
+        frame(&mut input, &mut map, &[release(Key::Enter)]);
+        assert!(!map.is_down("jump") && map.was_released_this_frame("jump"));
+        frame(&mut input, &mut map, &[]);
+        assert!(!map.was_released_this_frame("jump"));
+        assert!(!map.is_down("unbound") && map.axis("unbound") == 0.0);
+    }
+
+    #[test]
+    fn chords_need_every_binding() {
+        let (mut input, mut map) = (Input::new(), ActionMap::new());
+        map.bind_action("save", "chord(key:LeftControl + key:S)".parse().unwrap());
+        frame(&mut input, &mut map, &[press(Key::S)]);
+        assert!(!map.is_down("save"));
+        frame(&mut input, &mut map, &[press(Key::LeftControl)]);
+        assert!(map.was_pressed_this_frame("save"));
+        frame(&mut input, &mut map, &[release(Key::S)]);
+        assert!(map.was_released_this_frame("save"));
+        assert!(!Binding::Chord(Vec::new()).is_held(&input));
+    }
+
+    #[test]
+    fn dead_zones_rescale_what_is_left() {
+        assert_eq!(apply_dead_zone(0.1, 0.2), 0.0);
+        assert_eq!(apply_dead_zone(-0.2, 0.2), 0.0);
+        assert!(close(apply_dead_zone(0.6, 0.2), 0.5) && close(apply_dead_zone(-0.6, 0.2), -0.5));
+        assert_eq!(apply_dead_zone(1.0, 0.2), 1.0);
+        assert_eq!(apply_dead_zone(1.0, 1.0), 0.0);
+
+        assert_eq!(apply_radial_dead_zone((0.1, 0.1), 0.2), (0.0, 0.0));
+        // Both components alone are inside the dead zone, but not the diagonal.
+        let (x, y) = apply_radial_dead_zone((0.6 / 2f32.sqrt(), 0.6 / 2f32.sqrt()), 0.2);
+        assert!(close(x, y) && close((x * x + y * y).sqrt(), 0.5));
+        let (x, y) = apply_radial_dead_zone((1.0, 1.0), 0.2);
+        assert!(close((x * x + y * y).sqrt(), 1.0));
+    }
+
+    #[test]
+    fn digital_2d_axes_are_normalized() {
+        let (mut input, mut map) = (Input::new(), ActionMap::new());
+        map.bind_axis2d("move", "keys(key:W / key:S / key:A / key:D)".parse().unwrap());
+        frame(&mut input, &mut map, &[press(Key::W)]);
+        assert_eq!((map.axis2d("move").x, map.axis2d("move").y), (0.0, 1.0));
+        frame(&mut input, &mut map, &[press(Key::D)]);
+        let v = map.axis2d("move");
+        assert!(close(v.x, v.y) && close((v.x * v.x + v.y * v.y).sqrt(), 1.0));
+        frame(&mut input, &mut map, &[press(Key::S)]);
+        assert_eq!((map.axis2d("move").x, map.axis2d("move").y), (1.0, 0.0));
+    }
+
+    #[test]
+    fn furthest_binding_wins() {
+        let (mut input, mut map) = (Input::new(), ActionMap::new());
+        map.bind_axis("steer", "keys(key:A / key:D)".parse().unwrap())
+            .bind_axis("steer", AxisBinding::Gamepad { axis: GamepadAxis::LeftX, inverted: false })
+            .set_dead_zone("steer", 0.2);
+        input.set_gamepad(gamepad(&[(GamepadAxis::LeftX, -0.6)]));
+        frame(&mut input, &mut map, &[]);
+        assert!(close(map.axis("steer"), -0.5));
+        frame(&mut input, &mut map, &[press(Key::D)]);
+        assert_eq!(map.axis("steer"), 1.0);
+        input.set_gamepad(gamepad(&[(GamepadAxis::LeftX, 0.1)]));
+        frame(&mut input, &mut map, &[release(Key::D)]);
+        assert_eq!(map.axis("steer"), 0.0);
+
+        map.bind_axis2d("look", Axis2Binding::Stick { x: GamepadAxis::RightX, y: GamepadAxis::RightY })
+            .bind_axis2d("look", Axis2Binding::MouseMotion { scale: 0.1 });
+        // Stick y points down; the axis points up.
+        input.set_gamepad(gamepad(&[(GamepadAxis::RightY, 1.0)]));
+        frame(&mut input, &mut map, &[Event::CursorMoved { x: 0.0, y: 0.0 }, Event::CursorMoved { x: 5.0, y: 0.0 }]);
+        let look = map.axis2d("look");
+        assert_eq!((look.x, look.y), (0.0, -1.0));
+        frame(&mut input, &mut map, &[Event::CursorMoved { x: 25.0, y: 0.0 }]);
+        assert_eq!(map.axis2d("look").x, 2.0);
+    }
+
+    #[test]
+    fn pointer_axes_follow_this_frame() {
+        let (mut input, mut map) = (Input::new(), ActionMap::new());
+        map.bind_axis("zoom", "scroll:y*2".parse().unwrap());
+        frame(&mut input, &mut map, &[Event::Scroll { x: 0.0, y: 1.5 }]);
+        assert_eq!(map.axis("zoom"), 3.0);
+        frame(&mut input, &mut map, &[]);
+        assert_eq!(map.axis("zoom"), 0.0);
+    }
+
+    #[test]
+    fn config_round_trip() {
+        let mut map = ActionMap::new();
+        map.bind_action("jump", "key:Space".parse().unwrap())
+            .bind_action("jump", "button:A".parse().unwrap())
+            .bind_action("fire", "mouse:Left".parse().unwrap())
+            .bind_axis("zoom", "keys(key:Q / key:E)".parse().unwrap())
+            .bind_axis2d("move", "stick:Left".parse().unwrap())
+            .set_dead_zone("move", 0.25);
+        map.unbind("fire");
+        map.unbind("zoom");
+
+        let config = map.to_config();
+        let mut loaded = ActionMap::new();
+        loaded.bind_action("fire", "key:F".parse().unwrap());
+        loaded.load_config(&config).unwrap();
+
+        assert_eq!(loaded.to_config(), config);
+        assert_eq!(loaded.action_bindings("jump"), map.action_bindings("jump"));
+        assert!(loaded.action_bindings("fire").is_empty());
+        assert!(loaded.axis_bindings("zoom").is_empty());
+        assert_eq!(loaded.axis2d_bindings("move"), map.axis2d_bindings("move"));
+    }
+
+    #[test]
+    fn config_rejects_invalid_names() {
+        let mut map = ActionMap::new();
+        let error = map.load_config("action ok = key:Space\naction bad#name = key:A\n").unwrap_err();
+        assert!(matches!(error, BindingError::Parse { line: 2, .. }), "{}", error);
+        assert!(map.action_bindings("ok").is_empty());
+    }
+
+    #[test]
+    fn config_reports_the_failing_line() {
+        let mut map = ActionMap::new();
+        let error = map.load_config("# bindings\n\naction jump = key:NoSuchKey\n").unwrap_err();
+        assert!(matches!(error, BindingError::Parse { line: 3, .. }), "{}", error);
+    }
+}
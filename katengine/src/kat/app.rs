@@ -0,0 +1,238 @@
+//! The managed game loop.
+//!
+//! `Engine::run` owns the loop every project would otherwise write by hand: poll events,
+//! step the simulation at a fixed rate, update and render once per frame, swap, and
+//! optionally sleep to cap the frame rate.
+
+use std::time::Duration;
+use crate::kat::{Engine, Event, Window, WindowBuilder, WindowError};
+
+/// A program driven by `Engine::run`. Only `render` is required.
+#[allow(unused_variables)]
+pub trait App {
+    /// The window to create. Its context is current for every other callback.
+    fn window(&self) -> WindowBuilder {
+        WindowBuilder::new(800, 600, "katengine")
+    }
+
+    fn loop_settings(&self) -> LoopSettings {
+        LoopSettings::default()
+    }
+
+    /// Called once the window exists, before the first frame. Create GL resources here.
+    fn init(&mut self, engine: &mut Engine, window: &mut Window) {}
+
+    fn on_event(&mut self, window: &mut Window, event: Event) {}
+
+    /// Advances the simulation by exactly `LoopSettings::fixed_timestep` seconds. May run
+    /// zero or several times per frame.
+    fn fixed_update(&mut self, window: &mut Window, dt: f64) {}
+
+    /// Called once per frame with the scaled, clamped time since the previous frame.
+    /// `engine.time()` has the rest of the frame timing.
+    fn update(&mut self, engine: &mut Engine, window: &mut Window, dt: f64) {}
+
+    /// Draws a frame. `alpha` in [0, 1) is how far real time has run past the last fixed
+    /// update, relative to the timestep, for interpolating between the last two states.
+    fn render(&mut self, engine: &Engine, window: &mut Window, alpha: f64);
+
+    /// Called once after the window is closed, while its context is still alive.
+    fn shutdown(&mut self, window: &mut Window) {}
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct LoopSettings {
+    /// Seconds simulated by each `fixed_update`.
+    pub fixed_timestep: f64,
+    /// Longest frame time fed to the simulation. After a hitch (a breakpoint, a window
+    /// drag) the game slows down instead of trying to catch up all at once.
+    pub max_frame_time: f64,
+    /// Most fixed updates in one frame. When the simulation can't keep up with real time
+    /// the backlog is dropped rather than growing each frame (the "spiral of death").
+    pub max_fixed_steps: u32,
+    /// Upper bound on frames per second, on top of any vsync.
+    pub frame_limit: Option<f64>
+}
+
+impl LoopSettings {
+    pub fn with_fixed_rate(mut self, updates_per_second: f64) -> LoopSettings {
+        self.fixed_timestep = 1.0 / updates_per_second;
+        self
+    }
+
+    pub fn with_max_frame_time(mut self, seconds: f64) -> LoopSettings {
+        self.max_frame_time = seconds;
+        self
+    }
+
+    pub fn with_max_fixed_steps(mut self, steps: u32) -> LoopSettings {
+        self.max_fixed_steps = steps;
+        self
+    }
+
+    pub fn with_frame_limit(mut self, frames_per_second: f64) -> LoopSettings {
+        self.frame_limit = Some(frames_per_second);
+        self
+    }
+
+    /// Why these settings can't drive a loop, if they can't.
+    fn check(&self) -> Result<(), String> {
+        if !self.fixed_timestep.is_finite() || self.fixed_timestep <= 0.0 {
+            return Err(format!("fixed timestep must be positive, got {}", self.fixed_timestep));
+        }
+        if self.max_fixed_steps == 0 {
+            return Err("max fixed steps must be at least 1, or fixed_update never runs".to_string());
+        }
+        if !self.max_frame_time.is_finite() || self.max_frame_time <= 0.0 {
+            return Err(format!("max frame time must be positive, got {}", self.max_frame_time));
+        }
+        if let Some(fps) = self.frame_limit {
+            // The frame period has to fit a `Duration` to sleep for it.
+            if !fps.is_finite() || fps <= 0.0 || Duration::try_from_secs_f64(1.0 / fps).is_err() {
+                return Err(format!("frame limit must be positive, got {}", fps));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for LoopSettings {
+    fn default() -> Self {
+        LoopSettings {
+            fixed_timestep: 1.0 / 60.0,
+            max_frame_time: 0.25,
+            max_fixed_steps: 8,
+            frame_limit: None
+        }
+    }
+}
+
+/// Real time not yet consumed by fixed updates.
+struct FixedStepper {
+    accumulator: f64
+}
+
+impl FixedStepper {
+    /// Runs fixed updates for `elapsed` more seconds, returning the interpolation factor.
+    fn advance<F>(&mut self, settings: &LoopSettings, elapsed: f64, mut step: F) -> f64 where F: FnMut(f64) {
+        self.accumulator += elapsed;
+        let mut steps = 0;
+        while self.accumulator >= settings.fixed_timestep {
+            if steps == settings.max_fixed_steps {
+                self.accumulator %= settings.fixed_timestep;
+                break;
+            }
+            step(settings.fixed_timestep);
+            self.accumulator -= settings.fixed_timestep;
+            steps += 1;
+        }
+        return self.accumulator / settings.fixed_timestep;
+    }
+}
+
+impl Engine {
+    /// Creates the app's window and runs the loop until the window closes. Engine resources
+    /// are dropped together with the window.
+    pub fn run<A: App>(&mut self, mut app: A) -> Result<(), WindowError> {
+        let settings = app.loop_settings();
+        settings.check().map_err(WindowError::InvalidRequest)?;
+        let mut window = app.window().build(self)?;
+        app.init(self, &mut window);
+
+        let mut stepper = FixedStepper { accumulator: 0.0 };
+        while window.is_open() {
+            let frame_start = self.glfw_ctx.get_time();
+            self.time.tick();
+            let dt = self.time.delta().min(settings.max_frame_time);
+
+            window.update_events(self, |w, e| app.on_event(w, e));
+            let alpha = stepper.advance(&settings, dt, |step| app.fixed_update(&mut window, step));
+            app.update(self, &mut window, dt);
+            app.render(self, &mut window, alpha);
+            window.swap();
+            self.end_frame();
+
+            if let Some(fps) = settings.frame_limit {
+                self.wait_until(frame_start + 1.0 / fps);
+            }
+        }
+
+        app.shutdown(&mut window);
+        // GL objects the app owns must go before the context does.
+        drop(app);
+        self.resources.clear();
+        self.finish_deletions();
+        Ok(())
+    }
+
+    /// Sleeps most of the way to `deadline`, then spins, since sleeps overshoot by up to a
+    /// scheduler tick.
+    fn wait_until(&self, deadline: f64) {
+        const SPIN: f64 = 0.002;
+        let remaining = deadline - self.glfw_ctx.get_time();
+        if remaining > SPIN {
+            std::thread::sleep(Duration::from_secs_f64(remaining - SPIN));
+        }
+        while self.glfw_ctx.get_time() < deadline {
+            std::thread::yield_now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> LoopSettings {
+        LoopSettings::default().with_fixed_rate(4.0).with_max_fixed_steps(4)
+    }
+
+    fn advance(stepper: &mut FixedStepper, settings: &LoopSettings, elapsed: f64) -> (u32, f64) {
+        let mut steps = 0;
+        let alpha = stepper.advance(settings, elapsed, |step| {
+            assert_eq!(step, settings.fixed_timestep);
+            steps += 1;
+        });
+        (steps, alpha)
+    }
+
+    #[test]
+    fn steps_accumulate_across_frames() {
+        let (settings, mut stepper) = (settings(), FixedStepper { accumulator: 0.0 });
+        assert_eq!(advance(&mut stepper, &settings, 0.125), (0, 0.5));
+        assert_eq!(advance(&mut stepper, &settings, 0.25), (1, 0.5));
+        assert_eq!(advance(&mut stepper, &settings, 0.625), (3, 0.0));
+    }
+
+    #[test]
+    fn long_frames_drop_time_past_the_step_limit() {
+        let (settings, mut stepper) = (settings(), FixedStepper { accumulator: 0.0 });
+        assert_eq!(advance(&mut stepper, &settings, 10.125), (4, 0.5));
+        assert_eq!(advance(&mut stepper, &settings, 0.0), (0, 0.5));
+    }
+
+    #[test]
+    fn alpha_stays_below_one() {
+        let (settings, mut stepper) = (settings(), FixedStepper { accumulator: 0.0 });
+        for i in 0..1000 {
+            let (_, alpha) = advance(&mut stepper, &settings, 0.0173 * (i % 7) as f64);
+            assert!((0.0..1.0).contains(&alpha), "alpha {} after frame {}", alpha, i);
+        }
+    }
+
+    #[test]
+    fn settings_are_checked() {
+        assert!(LoopSettings::default().check().is_ok());
+        assert!(LoopSettings::default().with_max_fixed_steps(0).check().is_err());
+        assert!(LoopSettings::default().with_fixed_rate(f64::INFINITY).check().is_err());
+        assert!(LoopSettings::default().with_fixed_rate(f64::NAN).check().is_err());
+        assert!(LoopSettings::default().with_fixed_rate(0.0).check().is_err());
+        assert!(LoopSettings::default().with_max_frame_time(-0.25).check().is_err());
+        assert!(LoopSettings::default().with_max_frame_time(0.0).check().is_err());
+        assert!(LoopSettings::default().with_max_frame_time(f64::INFINITY).check().is_err());
+        assert!(LoopSettings::default().with_frame_limit(144.0).check().is_ok());
+        for fps in [0.0, -60.0, 1e-300, f64::NAN, f64::INFINITY] {
+            assert!(LoopSettings::default().with_frame_limit(fps).check().is_err(), "frame limit {}", fps);
+        }
+    }
+}
@@ -0,0 +1,440 @@
+//! Chooses how GL objects are created and edited.
+//!
+//! With direct state access (GL 4.5 or `ARB_direct_state_access`) objects are edited by
+//! name. Without it objects are bound to a scratch binding point, edited, and the previous
+//! binding is restored, which works on any 3.3 core context.
+
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+use crate::gl;
+use crate::gl::types::*;
+use crate::kat::{GlFeature, GlInfo, GlVersion};
+use crate::kat::glsl::GlslTarget;
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum Backend {
+    DirectStateAccess,
+    BindToEdit
+}
+
+static BACKEND: AtomicU8 = AtomicU8::new(0);
+static DEBUG_LABELS: AtomicU8 = AtomicU8::new(0);
+static QUERY_BUFFERS: AtomicU8 = AtomicU8::new(0);
+static TIMER_QUERIES: AtomicU8 = AtomicU8::new(0);
+static TRANSFORM_FEEDBACKS: AtomicU8 = AtomicU8::new(0);
+/// `major * 100 + minor` of the OpenGL ES context, zero on desktop GL.
+static GLES_VERSION: AtomicU32 = AtomicU32::new(0);
+
+/// The backend used for every GL object created from now on.
+pub fn current() -> Backend {
+    match BACKEND.load(Ordering::Relaxed) {
+        0 => Backend::DirectStateAccess,
+        _ => Backend::BindToEdit
+    }
+}
+
+/// Overrides the backend chosen from the context's capabilities, e.g. to exercise the
+/// bind-to-edit path on a 4.5 context. Selecting direct state access on a context
+/// without it crashes on the first object created.
+pub fn set(backend: Backend) {
+    BACKEND.store(match backend {
+        Backend::DirectStateAccess => 0,
+        Backend::BindToEdit => 1
+    }, Ordering::Relaxed);
+}
+
+/// Picks the backend for a freshly loaded context.
+pub fn select(info: &GlInfo) {
+    set(if info.supports(GlFeature::DirectStateAccess) { Backend::DirectStateAccess } else { Backend::BindToEdit });
+    DEBUG_LABELS.store(info.supports(GlFeature::DebugOutput) as u8, Ordering::Relaxed);
+    QUERY_BUFFERS.store(info.supports(GlFeature::QueryBufferObjects) as u8, Ordering::Relaxed);
+    TIMER_QUERIES.store(info.supports(GlFeature::TimerQueries) as u8, Ordering::Relaxed);
+    TRANSFORM_FEEDBACKS.store(info.supports(GlFeature::TransformFeedbackObjects) as u8, Ordering::Relaxed);
+    GLES_VERSION.store(if info.es { info.version.major * 100 + info.version.minor } else { 0 }, Ordering::Relaxed);
+}
+
+/// Whether the current context is OpenGL ES. ES contexts always use bind-to-edit.
+pub fn is_gles() -> bool {
+    GLES_VERSION.load(Ordering::Relaxed) != 0
+}
+
+pub(crate) fn glsl_target() -> GlslTarget {
+    match GLES_VERSION.load(Ordering::Relaxed) {
+        0 => GlslTarget::Desktop,
+        v => GlslTarget::Es(GlVersion::of(v / 100, v % 100))
+    }
+}
+
+/// Whether object labels and debug groups can be used; they are skipped otherwise.
+pub fn has_debug_labels() -> bool {
+    DEBUG_LABELS.load(Ordering::Relaxed) != 0
+}
+
+/// Whether query results can be written into buffers (`GlFeature::QueryBufferObjects`).
+pub fn has_query_buffers() -> bool {
+    QUERY_BUFFERS.load(Ordering::Relaxed) != 0
+}
+
+/// Whether timestamp queries can be used (`GlFeature::TimerQueries`).
+pub fn has_timer_queries() -> bool {
+    TIMER_QUERIES.load(Ordering::Relaxed) != 0
+}
+
+/// Whether transform feedback objects exist (`GlFeature::TransformFeedbackObjects`).
+pub fn has_transform_feedback_objects() -> bool {
+    TRANSFORM_FEEDBACKS.load(Ordering::Relaxed) != 0
+}
+
+fn is_dsa() -> bool {
+    current() == Backend::DirectStateAccess
+}
+
+fn get_integer(name: GLenum) -> GLint {
+    let mut value: GLint = 0;
+    unsafe { gl::GetIntegerv(name, &mut value); }
+    value
+}
+
+/// Binds `buffer` to the copy-write target for the duration of `f`. That target is not
+/// part of vertex array state, so editing never disturbs the bound vertex array.
+fn with_buffer<F: FnOnce()>(buffer: u32, f: F) {
+    let previous = get_integer(gl::COPY_WRITE_BUFFER_BINDING) as GLuint;
+    unsafe { gl::BindBuffer(gl::COPY_WRITE_BUFFER, buffer); }
+    f();
+    unsafe { gl::BindBuffer(gl::COPY_WRITE_BUFFER, previous); }
+}
+
+fn with_vertex_array<F: FnOnce()>(vao: u32, f: F) {
+    let previous = get_integer(gl::VERTEX_ARRAY_BINDING) as GLuint;
+    unsafe { gl::BindVertexArray(vao); }
+    f();
+    unsafe { gl::BindVertexArray(previous); }
+}
+
+fn with_program<F: FnOnce()>(program: u32, f: F) {
+    let previous = get_integer(gl::CURRENT_PROGRAM) as GLuint;
+    unsafe { gl::UseProgram(program); }
+    f();
+    unsafe { gl::UseProgram(previous); }
+}
+
+fn with_draw_framebuffer<F: FnOnce()>(framebuffer: u32, f: F) {
+    let previous = get_integer(gl::DRAW_FRAMEBUFFER_BINDING) as GLuint;
+    unsafe { gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, framebuffer); }
+    f();
+    unsafe { gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, previous); }
+}
+
+pub(crate) fn create_buffer() -> u32 {
+    let mut i: u32 = 0;
+    unsafe {
+        if is_dsa() {
+            gl::CreateBuffers(1, &mut i);
+        } else {
+            gl::GenBuffers(1, &mut i);
+            with_buffer(i, || {});
+        }
+    }
+    i
+}
+
+pub(crate) fn buffer_data(buffer: u32, size: usize, data: *const c_void, usage: GLenum) {
+    if is_dsa() {
+        unsafe { gl::NamedBufferData(buffer, size as GLsizeiptr, data, usage); }
+    } else {
+        with_buffer(buffer, || unsafe { gl::BufferData(gl::COPY_WRITE_BUFFER, size as GLsizeiptr, data, usage); });
+    }
+}
+
+pub(crate) fn create_vertex_array() -> u32 {
+    let mut i: u32 = 0;
+    unsafe {
+        if is_dsa() {
+            gl::CreateVertexArrays(1, &mut i);
+        } else {
+            gl::GenVertexArrays(1, &mut i);
+            with_vertex_array(i, || {});
+        }
+    }
+    i
+}
+
+/// A float vertex attribute sourced from a vertex buffer.
+pub(crate) struct FloatAttrib {
+    pub index: u32,
+    pub components: i32,
+    pub offset: usize
+}
+
+pub(crate) fn vertex_buffer(vao: u32, binding: u32, buffer: u32, stride: usize, attribs: &[FloatAttrib]) {
+    if is_dsa() {
+        unsafe {
+            for a in attribs {
+                gl::VertexArrayAttribFormat(vao, a.index, a.components, gl::FLOAT, gl::FALSE, a.offset as GLuint);
+                gl::VertexArrayAttribBinding(vao, a.index, binding);
+                gl::EnableVertexArrayAttrib(vao, a.index);
+            }
+            gl::VertexArrayVertexBuffer(vao, binding, buffer, 0, stride as GLsizei);
+        }
+    } else {
+        // Separate attribute formats need 4.3, so attributes capture the buffer bound to ARRAY_BUFFER.
+        with_vertex_array(vao, || unsafe {
+            let previous = get_integer(gl::ARRAY_BUFFER_BINDING) as GLuint;
+            gl::BindBuffer(gl::ARRAY_BUFFER, buffer);
+            for a in attribs {
+                gl::VertexAttribPointer(a.index, a.components, gl::FLOAT, gl::FALSE, stride as GLsizei, a.offset as *const c_void);
+                gl::EnableVertexAttribArray(a.index);
+            }
+            gl::BindBuffer(gl::ARRAY_BUFFER, previous);
+        });
+    }
+}
+
+pub(crate) fn element_buffer(vao: u32, buffer: u32) {
+    if is_dsa() {
+        unsafe { gl::VertexArrayElementBuffer(vao, buffer); }
+    } else {
+        with_vertex_array(vao, || unsafe { gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, buffer); });
+    }
+}
+
+pub(crate) fn uniform_1f(program: u32, location: GLint, x: f32) {
+    if is_dsa() {
+        unsafe { gl::ProgramUniform1f(program, location, x); }
+    } else {
+        with_program(program, || unsafe { gl::Uniform1f(location, x); });
+    }
+}
+
+pub(crate) fn uniform_2f(program: u32, location: GLint, x: f32, y: f32) {
+    if is_dsa() {
+        unsafe { gl::ProgramUniform2f(program, location, x, y); }
+    } else {
+        with_program(program, || unsafe { gl::Uniform2f(location, x, y); });
+    }
+}
+
+pub(crate) fn uniform_3f(program: u32, location: GLint, x: f32, y: f32, z: f32) {
+    if is_dsa() {
+        unsafe { gl::ProgramUniform3f(program, location, x, y, z); }
+    } else {
+        with_program(program, || unsafe { gl::Uniform3f(location, x, y, z); });
+    }
+}
+
+pub(crate) fn uniform_4f(program: u32, location: GLint, x: f32, y: f32, z: f32, w: f32) {
+    if is_dsa() {
+        unsafe { gl::ProgramUniform4f(program, location, x, y, z, w); }
+    } else {
+        with_program(program, || unsafe { gl::Uniform4f(location, x, y, z, w); });
+    }
+}
+
+pub(crate) fn create_framebuffer() -> u32 {
+    let mut i: u32 = 0;
+    unsafe {
+        if is_dsa() {
+            gl::CreateFramebuffers(1, &mut i);
+        } else {
+            gl::GenFramebuffers(1, &mut i);
+            with_draw_framebuffer(i, || {});
+        }
+    }
+    i
+}
+
+pub(crate) fn clear_framebuffer_fv(framebuffer: u32, buffer: GLenum, draw_buffer: GLint, values: *const f32) {
+    if is_dsa() {
+        unsafe { gl::ClearNamedFramebufferfv(framebuffer, buffer, draw_buffer, values); }
+    } else {
+        with_draw_framebuffer(framebuffer, || unsafe { gl::ClearBufferfv(buffer, draw_buffer, values); });
+    }
+}
+
+pub(crate) fn clear_framebuffer_iv(framebuffer: u32, buffer: GLenum, draw_buffer: GLint, values: *const i32) {
+    if is_dsa() {
+        unsafe { gl::ClearNamedFramebufferiv(framebuffer, buffer, draw_buffer, values); }
+    } else {
+        with_draw_framebuffer(framebuffer, || unsafe { gl::ClearBufferiv(buffer, draw_buffer, values); });
+    }
+}
+
+pub(crate) fn clear_framebuffer_fi(framebuffer: u32, depth: f32, stencil: i32) {
+    if is_dsa() {
+        unsafe { gl::ClearNamedFramebufferfi(framebuffer, gl::DEPTH_STENCIL, 0, depth, stencil); }
+    } else {
+        with_draw_framebuffer(framebuffer, || unsafe { gl::ClearBufferfi(gl::DEPTH_STENCIL, 0, depth, stencil); });
+    }
+}
+
+fn with_texture_2d<F: FnOnce()>(texture: u32, f: F) {
+    let previous = get_integer(gl::TEXTURE_BINDING_2D) as GLuint;
+    unsafe { gl::BindTexture(gl::TEXTURE_2D, texture); }
+    f();
+    unsafe { gl::BindTexture(gl::TEXTURE_2D, previous); }
+}
+
+/// Creates a 2D texture without mipmaps, filtered linearly and clamped to its edges.
+/// `format` and `ty` only matter without direct state access, where storage is allocated
+/// with `TexImage2D` since `TexStorage2D` needs GL 4.2.
+pub(crate) fn create_texture_2d(internal_format: GLenum, format: GLenum, ty: GLenum, width: i32, height: i32) -> u32 {
+    let mut i: u32 = 0;
+    unsafe {
+        if is_dsa() {
+            gl::CreateTextures(gl::TEXTURE_2D, 1, &mut i);
+            gl::TextureStorage2D(i, 1, internal_format, width, height);
+            gl::TextureParameteri(i, gl::TEXTURE_MIN_FILTER, gl::LINEAR as GLint);
+            gl::TextureParameteri(i, gl::TEXTURE_MAG_FILTER, gl::LINEAR as GLint);
+            gl::TextureParameteri(i, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as GLint);
+            gl::TextureParameteri(i, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as GLint);
+        } else {
+            gl::GenTextures(1, &mut i);
+            with_texture_2d(i, || {
+                gl::TexImage2D(gl::TEXTURE_2D, 0, internal_format as GLint, width, height, 0, format, ty, std::ptr::null());
+                gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as GLint);
+                gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as GLint);
+                gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as GLint);
+                gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as GLint);
+            });
+        }
+    }
+    i
+}
+
+pub(crate) fn bind_texture_unit(unit: u32, texture: u32) {
+    unsafe {
+        if is_dsa() {
+            gl::BindTextureUnit(unit, texture);
+        } else {
+            gl::ActiveTexture(gl::TEXTURE0 + unit);
+            gl::BindTexture(gl::TEXTURE_2D, texture);
+        }
+    }
+}
+
+pub(crate) fn framebuffer_texture(framebuffer: u32, attachment: GLenum, texture: u32) {
+    if is_dsa() {
+        unsafe { gl::NamedFramebufferTexture(framebuffer, attachment, texture, 0); }
+    } else {
+        with_draw_framebuffer(framebuffer, || unsafe {
+            gl::FramebufferTexture2D(gl::DRAW_FRAMEBUFFER, attachment, gl::TEXTURE_2D, texture, 0);
+        });
+    }
+}
+
+pub(crate) fn framebuffer_draw_buffers(framebuffer: u32, buffers: &[GLenum]) {
+    if is_dsa() {
+        unsafe { gl::NamedFramebufferDrawBuffers(framebuffer, buffers.len() as GLsizei, buffers.as_ptr()); }
+    } else {
+        with_draw_framebuffer(framebuffer, || unsafe { gl::DrawBuffers(buffers.len() as GLsizei, buffers.as_ptr()); });
+    }
+}
+
+pub(crate) fn framebuffer_status(framebuffer: u32) -> GLenum {
+    if is_dsa() {
+        unsafe { gl::CheckNamedFramebufferStatus(framebuffer, gl::DRAW_FRAMEBUFFER) }
+    } else {
+        let mut status = 0;
+        with_draw_framebuffer(framebuffer, || status = unsafe { gl::CheckFramebufferStatus(gl::DRAW_FRAMEBUFFER) });
+        status
+    }
+}
+
+/// Creates a query object. Without direct state access the name only becomes an object
+/// the first time it is begun or counted.
+pub(crate) fn create_query(target: GLenum) -> u32 {
+    let mut i: u32 = 0;
+    unsafe {
+        if is_dsa() {
+            gl::CreateQueries(target, 1, &mut i);
+        } else {
+            gl::GenQueries(1, &mut i);
+        }
+    }
+    i
+}
+
+pub(crate) fn create_transform_feedback() -> u32 {
+    let mut i: u32 = 0;
+    unsafe {
+        if is_dsa() {
+            gl::CreateTransformFeedbacks(1, &mut i);
+        } else {
+            gl::GenTransformFeedbacks(1, &mut i);
+            with_transform_feedback(i, || {});
+        }
+    }
+    i
+}
+
+fn with_transform_feedback<F: FnOnce()>(xfb: u32, f: F) {
+    let previous = get_integer(gl::TRANSFORM_FEEDBACK_BINDING) as GLuint;
+    unsafe { gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, xfb); }
+    f();
+    unsafe { gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, previous); }
+}
+
+pub(crate) fn transform_feedback_buffer_range(xfb: u32, index: u32, buffer: u32, range: Option<(usize, usize)>) {
+    if is_dsa() {
+        unsafe {
+            match range {
+                Some((offset, size)) => gl::TransformFeedbackBufferRange(xfb, index, buffer, offset as GLintptr, size as GLsizeiptr),
+                None => gl::TransformFeedbackBufferBase(xfb, index, buffer)
+            }
+        }
+    } else {
+        with_transform_feedback(xfb, || unsafe {
+            match range {
+                Some((offset, size)) => gl::BindBufferRange(gl::TRANSFORM_FEEDBACK_BUFFER, index, buffer, offset as GLintptr, size as GLsizeiptr),
+                None => gl::BindBufferBase(gl::TRANSFORM_FEEDBACK_BUFFER, index, buffer)
+            }
+        });
+    }
+}
+
+pub(crate) fn query_available(query: u32) -> bool {
+    let mut available: GLuint = 0;
+    unsafe { gl::GetQueryObjectuiv(query, gl::QUERY_RESULT_AVAILABLE, &mut available); }
+    available != 0
+}
+
+/// Reads a query result, blocking until it is available. OpenGL ES only has 32 bit results
+/// unless `EXT_disjoint_timer_query` adds 64 bit ones.
+pub(crate) fn query_result(query: u32) -> u64 {
+    unsafe {
+        if is_gles() && TIMER_QUERIES.load(Ordering::Relaxed) == 0 {
+            let mut value: GLuint = 0;
+            gl::GetQueryObjectuiv(query, gl::QUERY_RESULT, &mut value);
+            value as u64
+        } else {
+            let mut value: GLuint64 = 0;
+            gl::GetQueryObjectui64v(query, gl::QUERY_RESULT, &mut value);
+            value
+        }
+    }
+}
+
+/// Has the GPU write a query result at byte `offset` of `buffer`, as a 64 bit value if
+/// `wide` is set and a 32 bit one otherwise.
+pub(crate) fn query_result_to_buffer(query: u32, buffer: u32, pname: GLenum, offset: usize, wide: bool) {
+    unsafe {
+        if is_dsa() {
+            let offset = offset as GLintptr;
+            if wide {
+                gl::GetQueryBufferObjectui64v(query, buffer, pname, offset);
+            } else {
+                gl::GetQueryBufferObjectuiv(query, buffer, pname, offset);
+            }
+        } else {
+            // With a buffer bound to QUERY_BUFFER the pointer argument is an offset into it.
+            let previous = get_integer(gl::QUERY_BUFFER_BINDING) as GLuint;
+            gl::BindBuffer(gl::QUERY_BUFFER, buffer);
+            if wide {
+                gl::GetQueryObjectui64v(query, pname, offset as *mut GLuint64);
+            } else {
+                gl::GetQueryObjectuiv(query, pname, offset as *mut GLuint);
+            }
+            gl::BindBuffer(gl::QUERY_BUFFER, previous);
+        }
+    }
+}
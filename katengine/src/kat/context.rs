@@ -0,0 +1,334 @@
+//! Tracks which context is current so objects are only used where they exist.
+//!
+//! Buffers, shaders and textures belong to a share group and may be used from any window
+//! whose context is in that group. Vertex arrays, framebuffers, queries and transform
+//! feedback objects are containers that GL never shares; they belong to the single
+//! context they were created on.
+//!
+//! Every object holds a token for its context or share group. Tokens are `Rc`s, which
+//! keeps objects on the thread their context lives on (`!Send`). An object dropped while
+//! no context that owns it is current is deleted the next time one is made current, and
+//! objects still alive when the last context able to delete them is destroyed are logged
+//! as leaks. While an `Engine` exists, deletion also waits for frames in flight; see
+//! `deletion`.
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use crate::gl;
+use crate::kat::deletion;
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct ContextId(u32);
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct ShareGroupId(u32);
+
+#[derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug)]
+pub enum ObjectKind {
+    Buffer,
+    Shader,
+    Texture,
+    VertexArray,
+    Framebuffer,
+    Query,
+    TransformFeedback
+}
+
+impl fmt::Display for ObjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// An object that hasn't been dropped yet.
+#[derive(Clone,PartialEq,Eq,Debug)]
+pub struct LiveObject {
+    pub kind: ObjectKind,
+    pub handle: u32,
+    /// The label given through `Labeled::set_label`, if any.
+    pub label: Option<String>
+}
+
+impl fmt::Display for LiveObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.label {
+            Some(label) => write!(f, "{} {} \"{}\"", self.kind, self.handle, label),
+            None => write!(f, "{} {}", self.kind, self.handle)
+        }
+    }
+}
+
+fn delete_object(kind: ObjectKind, handle: u32) {
+    unsafe {
+        match kind {
+            ObjectKind::Buffer => gl::DeleteBuffers(1, &handle),
+            ObjectKind::Shader => gl::DeleteProgram(handle),
+            ObjectKind::Texture => gl::DeleteTextures(1, &handle),
+            ObjectKind::VertexArray => gl::DeleteVertexArrays(1, &handle),
+            ObjectKind::Framebuffer => gl::DeleteFramebuffers(1, &handle),
+            ObjectKind::Query => gl::DeleteQueries(1, &handle),
+            ObjectKind::TransformFeedback => gl::DeleteTransformFeedbacks(1, &handle)
+        }
+    }
+}
+
+/// The objects owned by one context or share group.
+#[derive(Debug,Default)]
+struct Objects {
+    live: RefCell<BTreeMap<(ObjectKind, u32), Option<String>>>,
+    /// Dropped while no owning context was current.
+    pending: RefCell<Vec<(ObjectKind, u32)>>
+}
+
+impl Objects {
+    fn register(&self, kind: ObjectKind, handle: u32) {
+        self.live.borrow_mut().insert((kind, handle), None);
+    }
+
+    fn set_label(&self, kind: ObjectKind, handle: u32, label: &str) {
+        if let Some(entry) = self.live.borrow_mut().get_mut(&(kind, handle)) {
+            *entry = Some(label.to_string());
+        }
+    }
+
+    fn forget(&self, kind: ObjectKind, handle: u32) {
+        self.live.borrow_mut().remove(&(kind, handle));
+    }
+
+    fn flush(&self) {
+        for (kind, handle) in self.pending.borrow_mut().drain(..) {
+            delete_object(kind, handle);
+        }
+    }
+
+    fn live_objects(&self) -> Vec<LiveObject> {
+        self.live.borrow().iter()
+            .map(|(&(kind, handle), label)| LiveObject { kind, handle, label: label.clone() })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct GroupState {
+    id: ShareGroupId,
+    /// Contexts in the group that haven't been destroyed.
+    contexts: Cell<u32>,
+    objects: Objects
+}
+
+#[derive(Debug)]
+struct ContextState {
+    id: ContextId,
+    group: Rc<GroupState>,
+    alive: Cell<bool>,
+    objects: Objects
+}
+
+/// Ties a container object to its context, and a window to its own context.
+#[derive(Clone,Debug)]
+pub(crate) struct ContextToken(Rc<ContextState>);
+
+/// Ties a shareable object to its share group.
+#[derive(Clone,Debug)]
+pub(crate) struct GroupToken(Rc<GroupState>);
+
+/// Whoever can delete an object: its context, or any context in its share group.
+#[derive(Clone,Debug)]
+pub(crate) enum Owner {
+    Context(ContextToken),
+    Group(GroupToken)
+}
+
+impl Owner {
+    fn objects(&self) -> &Objects {
+        match self {
+            Owner::Context(ctx) => &ctx.0.objects,
+            Owner::Group(group) => &group.0.objects
+        }
+    }
+
+    pub(crate) fn group(&self) -> GroupToken {
+        match self {
+            Owner::Context(ctx) => ctx.group(),
+            Owner::Group(group) => group.clone()
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        match self {
+            Owner::Context(ctx) => ctx.0.alive.get(),
+            Owner::Group(group) => group.is_alive()
+        }
+    }
+
+    fn is_current(&self) -> bool {
+        match self {
+            Owner::Context(ctx) => current_context() == Some(ctx.0.id),
+            Owner::Group(group) => current_share_group() == Some(group.0.id)
+        }
+    }
+
+    /// Deletes the object now if possible, later if the owner isn't current, and not at all
+    /// if the owner is already gone and took the object with it.
+    pub(crate) fn delete(&self, kind: ObjectKind, handle: u32) {
+        if self.is_current() {
+            delete_object(kind, handle);
+        } else if self.is_alive() {
+            self.objects().pending.borrow_mut().push((kind, handle));
+        }
+    }
+
+    /// Called when the object is dropped. Deletion waits for in-flight frames while an
+    /// `Engine` is collecting deletions.
+    fn release(self, kind: ObjectKind, handle: u32) {
+        self.objects().forget(kind, handle);
+        if let Some(owner) = deletion::defer(self, kind, handle) {
+            owner.delete(kind, handle);
+        }
+    }
+}
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+thread_local! {
+    static CURRENT: RefCell<Option<ContextToken>> = const { RefCell::new(None) };
+}
+
+/// A new context, in a new share group unless it joins `group`.
+pub(crate) fn new_context(group: Option<GroupToken>) -> ContextToken {
+    let group = match group {
+        Some(group) => group.0,
+        None => Rc::new(GroupState {
+            id: ShareGroupId(NEXT_ID.fetch_add(1, Ordering::Relaxed)),
+            contexts: Cell::new(0),
+            objects: Objects::default()
+        })
+    };
+    group.contexts.set(group.contexts.get() + 1);
+    ContextToken(Rc::new(ContextState {
+        id: ContextId(NEXT_ID.fetch_add(1, Ordering::Relaxed)),
+        group,
+        alive: Cell::new(true),
+        objects: Objects::default()
+    }))
+}
+
+/// Records `context` as current and deletes objects that were waiting for it.
+pub(crate) fn set_current(context: &ContextToken) {
+    CURRENT.with(|c| *c.borrow_mut() = Some(context.clone()));
+    context.0.objects.flush();
+    context.0.group.objects.flush();
+}
+
+/// Tears down the bookkeeping for a context about to be destroyed, which must be current.
+/// Returns the objects that leaked with it: its own, plus the share group's if it was the
+/// group's last context.
+pub(crate) fn destroy_context(context: &ContextToken) -> Vec<LiveObject> {
+    set_current(context);
+    let state = &context.0;
+    state.alive.set(false);
+    state.group.contexts.set(state.group.contexts.get() - 1);
+    CURRENT.with(|c| *c.borrow_mut() = None);
+
+    let mut leaked = state.objects.live_objects();
+    if state.group.contexts.get() == 0 {
+        leaked.extend(state.group.objects.live_objects());
+    }
+    leaked
+}
+
+fn current() -> Option<ContextToken> {
+    CURRENT.with(|c| c.borrow().clone())
+}
+
+/// The context current on this thread, if a window has made one current.
+pub fn current_context() -> Option<ContextId> {
+    current().map(|c| c.0.id)
+}
+
+pub fn current_share_group() -> Option<ShareGroupId> {
+    current().map(|c| c.0.group.id)
+}
+
+pub(crate) fn current_group() -> Option<GroupToken> {
+    current().map(|c| c.group())
+}
+
+/// The context new objects are created on.
+pub(crate) fn require_current() -> ContextToken {
+    current().expect("No window context is current; create a window before creating GL objects.")
+}
+
+impl ContextToken {
+    pub(crate) fn id(&self) -> ContextId {
+        self.0.id
+    }
+
+    pub(crate) fn group(&self) -> GroupToken {
+        GroupToken(self.0.group.clone())
+    }
+
+    /// Registers a container object created on this context.
+    pub(crate) fn adopt(&self, kind: ObjectKind, handle: u32) -> ContextToken {
+        self.0.objects.register(kind, handle);
+        self.clone()
+    }
+
+    /// Registers a shareable object created on this context with its share group.
+    pub(crate) fn adopt_shared(&self, kind: ObjectKind, handle: u32) -> GroupToken {
+        self.0.group.objects.register(kind, handle);
+        self.group()
+    }
+
+    /// Panics unless this is the current context.
+    pub(crate) fn check(&self, what: &str) {
+        let current = current_context();
+        assert!(current == Some(self.0.id),
+                "{} belongs to context {:?} but {:?} is current; container objects are not shared between contexts.",
+                what, self.0.id, current);
+    }
+
+    pub(crate) fn set_label(&self, kind: ObjectKind, handle: u32, label: &str) {
+        self.0.objects.set_label(kind, handle, label);
+    }
+
+    pub(crate) fn release(&self, kind: ObjectKind, handle: u32) {
+        Owner::Context(self.clone()).release(kind, handle);
+    }
+
+    /// Objects owned by this context or its share group.
+    pub(crate) fn live_objects(&self) -> Vec<LiveObject> {
+        let mut objects = self.0.objects.live_objects();
+        objects.extend(self.0.group.objects.live_objects());
+        objects
+    }
+}
+
+impl GroupToken {
+    pub(crate) fn id(&self) -> ShareGroupId {
+        self.0.id
+    }
+
+    /// Panics unless the current context belongs to this group.
+    pub(crate) fn check(&self, what: &str) {
+        let current = current_share_group();
+        assert!(current == Some(self.0.id),
+                "{} belongs to share group {:?} but the current context is in {:?}; make a window of its share group current first.",
+                what, self.0.id, current);
+    }
+
+    pub(crate) fn set_label(&self, kind: ObjectKind, handle: u32, label: &str) {
+        self.0.objects.set_label(kind, handle, label);
+    }
+
+    pub(crate) fn is_alive(&self) -> bool {
+        self.0.contexts.get() > 0
+    }
+
+    pub(crate) fn release(&self, kind: ObjectKind, handle: u32) {
+        Owner::Group(self.clone()).release(kind, handle);
+    }
+}
@@ -0,0 +1,202 @@
+//! Deferred deletion of GL objects.
+//!
+//! GL keeps an object alive until the commands already submitted with it have finished,
+//! so deleting early is only a hazard for persistently mapped buffers and for other
+//! contexts still using a shared object. While an `Engine` exists, dropped objects are
+//! queued instead and deleted once a fence placed at the end of the frame they were dropped
+//! in has signaled: every draw that could have used them was submitted before that fence.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::{Rc, Weak};
+use crate::gl;
+use crate::gl::types::*;
+use crate::kat::context::{self, GroupToken, ObjectKind, Owner};
+use crate::kat::Engine;
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub struct DeletionStats {
+    /// Dropped since the last `end_frame`, not yet behind a fence.
+    pub queued: usize,
+    /// Behind a fence the GPU hasn't passed yet.
+    pub in_flight: usize,
+    pub fences_in_flight: usize,
+    pub deleted_last_frame: usize,
+    pub deleted_total: u64
+}
+
+struct Retired {
+    owner: Owner,
+    kind: ObjectKind,
+    handle: u32
+}
+
+/// Objects dropped during one frame of one share group, and the fence after that frame.
+struct Batch {
+    fence: GLsync,
+    group: GroupToken,
+    objects: Vec<Retired>
+}
+
+pub(crate) struct DeletionQueue {
+    enabled: bool,
+    queued: Vec<Retired>,
+    in_flight: VecDeque<Batch>,
+    deleted_last_frame: usize,
+    deleted_total: u64
+}
+
+thread_local! {
+    /// The queue of the `Engine` on this thread. Weak, so the engine alone owns it.
+    static ACTIVE: RefCell<Weak<RefCell<DeletionQueue>>> = const { RefCell::new(Weak::new()) };
+}
+
+/// Creates a queue and routes drops on this thread to it.
+pub(crate) fn install() -> Rc<RefCell<DeletionQueue>> {
+    let queue = Rc::new(RefCell::new(DeletionQueue {
+        enabled: true,
+        queued: Vec::new(),
+        in_flight: VecDeque::new(),
+        deleted_last_frame: 0,
+        deleted_total: 0
+    }));
+    ACTIVE.with(|a| *a.borrow_mut() = Rc::downgrade(&queue));
+    queue
+}
+
+/// Queues a dropped object, or hands the owner back if it should be deleted right away.
+pub(crate) fn defer(owner: Owner, kind: ObjectKind, handle: u32) -> Option<Owner> {
+    match ACTIVE.with(|a| a.borrow().upgrade()) {
+        Some(queue) if queue.borrow().enabled => {
+            queue.borrow_mut().queued.push(Retired { owner, kind, handle });
+            None
+        }
+        _ => Some(owner)
+    }
+}
+
+fn is_signaled(fence: GLsync) -> bool {
+    let status = unsafe { gl::ClientWaitSync(fence, gl::SYNC_FLUSH_COMMANDS_BIT, 0) };
+    // A failed wait would otherwise keep the batch forever.
+    status != gl::TIMEOUT_EXPIRED
+}
+
+impl DeletionQueue {
+    fn delete(&mut self, objects: Vec<Retired>) -> usize {
+        let count = objects.len();
+        for retired in objects {
+            retired.owner.delete(retired.kind, retired.handle);
+        }
+        self.deleted_total += count as u64;
+        count
+    }
+
+    /// Fences what was dropped in the current share group, then deletes every batch whose
+    /// fence has signaled. Batches of groups that aren't current wait for their own frames.
+    fn end_frame(&mut self) {
+        let Some(group) = context::current_group() else {
+            self.deleted_last_frame = 0;
+            return;
+        };
+
+        let (mine, others): (Vec<Retired>, Vec<Retired>) = self.queued.drain(..)
+            .partition(|r| r.owner.group().id() == group.id());
+        self.queued = others;
+        if !mine.is_empty() {
+            let fence = unsafe { gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0) };
+            self.in_flight.push_back(Batch { fence, group: group.clone(), objects: mine });
+        }
+
+        let mut deleted = 0;
+        let mut waiting = VecDeque::new();
+        while let Some(batch) = self.in_flight.pop_front() {
+            if !batch.group.is_alive() {
+                // The group's last context took the objects and the fence with it.
+                continue;
+            }
+            if batch.group.id() == group.id() && is_signaled(batch.fence) {
+                unsafe { gl::DeleteSync(batch.fence); }
+                deleted += self.delete(batch.objects);
+            } else {
+                waiting.push_back(batch);
+            }
+        }
+        self.in_flight = waiting;
+        self.deleted_last_frame = deleted;
+    }
+
+    /// Waits for the GPU and deletes everything the current share group can delete.
+    fn finish(&mut self) {
+        let Some(group) = context::current_group() else {
+            return;
+        };
+        unsafe { gl::Finish(); }
+
+        let (mine, others): (Vec<Retired>, Vec<Retired>) = self.queued.drain(..)
+            .partition(|r| r.owner.group().id() == group.id());
+        self.queued = others;
+        self.delete(mine);
+
+        let mut waiting = VecDeque::new();
+        while let Some(batch) = self.in_flight.pop_front() {
+            if !batch.group.is_alive() {
+                continue;
+            }
+            if batch.group.id() == group.id() {
+                unsafe { gl::DeleteSync(batch.fence); }
+                self.delete(batch.objects);
+            } else {
+                waiting.push_back(batch);
+            }
+        }
+        self.in_flight = waiting;
+    }
+
+    /// Deletes what it can when the engine goes away. Objects of share groups that aren't
+    /// current are deleted when one of their contexts next becomes current.
+    pub(crate) fn shutdown(&mut self) {
+        self.finish();
+        self.enabled = false;
+        let mut remaining: Vec<Retired> = self.queued.drain(..).collect();
+        remaining.extend(self.in_flight.drain(..).flat_map(|b| b.objects));
+        self.delete(remaining);
+    }
+
+    fn stats(&self) -> DeletionStats {
+        DeletionStats {
+            queued: self.queued.len(),
+            in_flight: self.in_flight.iter().map(|b| b.objects.len()).sum(),
+            fences_in_flight: self.in_flight.len(),
+            deleted_last_frame: self.deleted_last_frame,
+            deleted_total: self.deleted_total
+        }
+    }
+}
+
+impl Engine {
+    /// Closes the frame for deferred deletion: fences objects dropped during it and
+    /// deletes those from earlier frames the GPU is done with. `run` calls this after every
+    /// swap; manual loops should too, with the window's context current.
+    pub fn end_frame(&mut self) {
+        self.deletions.borrow_mut().end_frame();
+    }
+
+    /// Blocks until the GPU is idle and deletes everything queued for the current share
+    /// group, e.g. before a level change frees a lot of memory at once.
+    pub fn finish_deletions(&mut self) {
+        self.deletions.borrow_mut().finish();
+    }
+
+    pub fn deletion_stats(&self) -> DeletionStats {
+        self.deletions.borrow().stats()
+    }
+
+    /// With deferral off, dropped objects are deleted immediately (or as soon as their
+    /// context is current). Turning it off first finishes what is already queued.
+    pub fn set_deferred_deletion(&mut self, enabled: bool) {
+        if !enabled {
+            self.finish_deletions();
+        }
+        self.deletions.borrow_mut().enabled = enabled;
+    }
+}
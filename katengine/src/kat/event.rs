@@ -0,0 +1,670 @@
+//! Window and input events, independent of the windowing library.
+
+use std::path::PathBuf;
+use crate::kat::GamepadId;
+
+/// A key, identified by its position on a US keyboard layout.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum Key {
+    Space,
+    Apostrophe,
+    Comma,
+    Minus,
+    Period,
+    Slash,
+    Num0,
+    Num1,
+    Num2,
+    Num3,
+    Num4,
+    Num5,
+    Num6,
+    Num7,
+    Num8,
+    Num9,
+    Semicolon,
+    Equal,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    LeftBracket,
+    Backslash,
+    RightBracket,
+    GraveAccent,
+    World1,
+    World2,
+    Escape,
+    Enter,
+    Tab,
+    Backspace,
+    Insert,
+    Delete,
+    Right,
+    Left,
+    Down,
+    Up,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    CapsLock,
+    ScrollLock,
+    NumLock,
+    PrintScreen,
+    Pause,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    F25,
+    Kp0,
+    Kp1,
+    Kp2,
+    Kp3,
+    Kp4,
+    Kp5,
+    Kp6,
+    Kp7,
+    Kp8,
+    Kp9,
+    KpDecimal,
+    KpDivide,
+    KpMultiply,
+    KpSubtract,
+    KpAdd,
+    KpEnter,
+    KpEqual,
+    LeftShift,
+    LeftControl,
+    LeftAlt,
+    LeftSuper,
+    RightShift,
+    RightControl,
+    RightAlt,
+    RightSuper,
+    Menu,
+    Unknown
+}
+
+impl Key {
+    /// The variant name, as used in binding files.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Key::Space => "Space",
+            Key::Apostrophe => "Apostrophe",
+            Key::Comma => "Comma",
+            Key::Minus => "Minus",
+            Key::Period => "Period",
+            Key::Slash => "Slash",
+            Key::Num0 => "Num0",
+            Key::Num1 => "Num1",
+            Key::Num2 => "Num2",
+            Key::Num3 => "Num3",
+            Key::Num4 => "Num4",
+            Key::Num5 => "Num5",
+            Key::Num6 => "Num6",
+            Key::Num7 => "Num7",
+            Key::Num8 => "Num8",
+            Key::Num9 => "Num9",
+            Key::Semicolon => "Semicolon",
+            Key::Equal => "Equal",
+            Key::A => "A",
+            Key::B => "B",
+            Key::C => "C",
+            Key::D => "D",
+            Key::E => "E",
+            Key::F => "F",
+            Key::G => "G",
+            Key::H => "H",
+            Key::I => "I",
+            Key::J => "J",
+            Key::K => "K",
+            Key::L => "L",
+            Key::M => "M",
+            Key::N => "N",
+            Key::O => "O",
+            Key::P => "P",
+            Key::Q => "Q",
+            Key::R => "R",
+            Key::S => "S",
+            Key::T => "T",
+            Key::U => "U",
+            Key::V => "V",
+            Key::W => "W",
+            Key::X => "X",
+            Key::Y => "Y",
+            Key::Z => "Z",
+            Key::LeftBracket => "LeftBracket",
+            Key::Backslash => "Backslash",
+            Key::RightBracket => "RightBracket",
+            Key::GraveAccent => "GraveAccent",
+            Key::World1 => "World1",
+            Key::World2 => "World2",
+            Key::Escape => "Escape",
+            Key::Enter => "Enter",
+            Key::Tab => "Tab",
+            Key::Backspace => "Backspace",
+            Key::Insert => "Insert",
+            Key::Delete => "Delete",
+            Key::Right => "Right",
+            Key::Left => "Left",
+            Key::Down => "Down",
+            Key::Up => "Up",
+            Key::PageUp => "PageUp",
+            Key::PageDown => "PageDown",
+            Key::Home => "Home",
+            Key::End => "End",
+            Key::CapsLock => "CapsLock",
+            Key::ScrollLock => "ScrollLock",
+            Key::NumLock => "NumLock",
+            Key::PrintScreen => "PrintScreen",
+            Key::Pause => "Pause",
+            Key::F1 => "F1",
+            Key::F2 => "F2",
+            Key::F3 => "F3",
+            Key::F4 => "F4",
+            Key::F5 => "F5",
+            Key::F6 => "F6",
+            Key::F7 => "F7",
+            Key::F8 => "F8",
+            Key::F9 => "F9",
+            Key::F10 => "F10",
+            Key::F11 => "F11",
+            Key::F12 => "F12",
+            Key::F13 => "F13",
+            Key::F14 => "F14",
+            Key::F15 => "F15",
+            Key::F16 => "F16",
+            Key::F17 => "F17",
+            Key::F18 => "F18",
+            Key::F19 => "F19",
+            Key::F20 => "F20",
+            Key::F21 => "F21",
+            Key::F22 => "F22",
+            Key::F23 => "F23",
+            Key::F24 => "F24",
+            Key::F25 => "F25",
+            Key::Kp0 => "Kp0",
+            Key::Kp1 => "Kp1",
+            Key::Kp2 => "Kp2",
+            Key::Kp3 => "Kp3",
+            Key::Kp4 => "Kp4",
+            Key::Kp5 => "Kp5",
+            Key::Kp6 => "Kp6",
+            Key::Kp7 => "Kp7",
+            Key::Kp8 => "Kp8",
+            Key::Kp9 => "Kp9",
+            Key::KpDecimal => "KpDecimal",
+            Key::KpDivide => "KpDivide",
+            Key::KpMultiply => "KpMultiply",
+            Key::KpSubtract => "KpSubtract",
+            Key::KpAdd => "KpAdd",
+            Key::KpEnter => "KpEnter",
+            Key::KpEqual => "KpEqual",
+            Key::LeftShift => "LeftShift",
+            Key::LeftControl => "LeftControl",
+            Key::LeftAlt => "LeftAlt",
+            Key::LeftSuper => "LeftSuper",
+            Key::RightShift => "RightShift",
+            Key::RightControl => "RightControl",
+            Key::RightAlt => "RightAlt",
+            Key::RightSuper => "RightSuper",
+            Key::Menu => "Menu",
+            Key::Unknown => "Unknown",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Key> {
+        Some(match name {
+            "Space" => Key::Space,
+            "Apostrophe" => Key::Apostrophe,
+            "Comma" => Key::Comma,
+            "Minus" => Key::Minus,
+            "Period" => Key::Period,
+            "Slash" => Key::Slash,
+            "Num0" => Key::Num0,
+            "Num1" => Key::Num1,
+            "Num2" => Key::Num2,
+            "Num3" => Key::Num3,
+            "Num4" => Key::Num4,
+            "Num5" => Key::Num5,
+            "Num6" => Key::Num6,
+            "Num7" => Key::Num7,
+            "Num8" => Key::Num8,
+            "Num9" => Key::Num9,
+            "Semicolon" => Key::Semicolon,
+            "Equal" => Key::Equal,
+            "A" => Key::A,
+            "B" => Key::B,
+            "C" => Key::C,
+            "D" => Key::D,
+            "E" => Key::E,
+            "F" => Key::F,
+            "G" => Key::G,
+            "H" => Key::H,
+            "I" => Key::I,
+            "J" => Key::J,
+            "K" => Key::K,
+            "L" => Key::L,
+            "M" => Key::M,
+            "N" => Key::N,
+            "O" => Key::O,
+            "P" => Key::P,
+            "Q" => Key::Q,
+            "R" => Key::R,
+            "S" => Key::S,
+            "T" => Key::T,
+            "U" => Key::U,
+            "V" => Key::V,
+            "W" => Key::W,
+            "X" => Key::X,
+            "Y" => Key::Y,
+            "Z" => Key::Z,
+            "LeftBracket" => Key::LeftBracket,
+            "Backslash" => Key::Backslash,
+            "RightBracket" => Key::RightBracket,
+            "GraveAccent" => Key::GraveAccent,
+            "World1" => Key::World1,
+            "World2" => Key::World2,
+            "Escape" => Key::Escape,
+            "Enter" => Key::Enter,
+            "Tab" => Key::Tab,
+            "Backspace" => Key::Backspace,
+            "Insert" => Key::Insert,
+            "Delete" => Key::Delete,
+            "Right" => Key::Right,
+            "Left" => Key::Left,
+            "Down" => Key::Down,
+            "Up" => Key::Up,
+            "PageUp" => Key::PageUp,
+            "PageDown" => Key::PageDown,
+            "Home" => Key::Home,
+            "End" => Key::End,
+            "CapsLock" => Key::CapsLock,
+            "ScrollLock" => Key::ScrollLock,
+            "NumLock" => Key::NumLock,
+            "PrintScreen" => Key::PrintScreen,
+            "Pause" => Key::Pause,
+            "F1" => Key::F1,
+            "F2" => Key::F2,
+            "F3" => Key::F3,
+            "F4" => Key::F4,
+            "F5" => Key::F5,
+            "F6" => Key::F6,
+            "F7" => Key::F7,
+            "F8" => Key::F8,
+            "F9" => Key::F9,
+            "F10" => Key::F10,
+            "F11" => Key::F11,
+            "F12" => Key::F12,
+            "F13" => Key::F13,
+            "F14" => Key::F14,
+            "F15" => Key::F15,
+            "F16" => Key::F16,
+            "F17" => Key::F17,
+            "F18" => Key::F18,
+            "F19" => Key::F19,
+            "F20" => Key::F20,
+            "F21" => Key::F21,
+            "F22" => Key::F22,
+            "F23" => Key::F23,
+            "F24" => Key::F24,
+            "F25" => Key::F25,
+            "Kp0" => Key::Kp0,
+            "Kp1" => Key::Kp1,
+            "Kp2" => Key::Kp2,
+            "Kp3" => Key::Kp3,
+            "Kp4" => Key::Kp4,
+            "Kp5" => Key::Kp5,
+            "Kp6" => Key::Kp6,
+            "Kp7" => Key::Kp7,
+            "Kp8" => Key::Kp8,
+            "Kp9" => Key::Kp9,
+            "KpDecimal" => Key::KpDecimal,
+            "KpDivide" => Key::KpDivide,
+            "KpMultiply" => Key::KpMultiply,
+            "KpSubtract" => Key::KpSubtract,
+            "KpAdd" => Key::KpAdd,
+            "KpEnter" => Key::KpEnter,
+            "KpEqual" => Key::KpEqual,
+            "LeftShift" => Key::LeftShift,
+            "LeftControl" => Key::LeftControl,
+            "LeftAlt" => Key::LeftAlt,
+            "LeftSuper" => Key::LeftSuper,
+            "RightShift" => Key::RightShift,
+            "RightControl" => Key::RightControl,
+            "RightAlt" => Key::RightAlt,
+            "RightSuper" => Key::RightSuper,
+            "Menu" => Key::Menu,
+            "Unknown" => Key::Unknown,
+            _ => return None
+        })
+    }
+
+    pub(crate) fn from_glfw(key: glfw::Key) -> Key {
+        match key {
+            glfw::Key::Space => Key::Space,
+            glfw::Key::Apostrophe => Key::Apostrophe,
+            glfw::Key::Comma => Key::Comma,
+            glfw::Key::Minus => Key::Minus,
+            glfw::Key::Period => Key::Period,
+            glfw::Key::Slash => Key::Slash,
+            glfw::Key::Num0 => Key::Num0,
+            glfw::Key::Num1 => Key::Num1,
+            glfw::Key::Num2 => Key::Num2,
+            glfw::Key::Num3 => Key::Num3,
+            glfw::Key::Num4 => Key::Num4,
+            glfw::Key::Num5 => Key::Num5,
+            glfw::Key::Num6 => Key::Num6,
+            glfw::Key::Num7 => Key::Num7,
+            glfw::Key::Num8 => Key::Num8,
+            glfw::Key::Num9 => Key::Num9,
+            glfw::Key::Semicolon => Key::Semicolon,
+            glfw::Key::Equal => Key::Equal,
+            glfw::Key::A => Key::A,
+            glfw::Key::B => Key::B,
+            glfw::Key::C => Key::C,
+            glfw::Key::D => Key::D,
+            glfw::Key::E => Key::E,
+            glfw::Key::F => Key::F,
+            glfw::Key::G => Key::G,
+            glfw::Key::H => Key::H,
+            glfw::Key::I => Key::I,
+            glfw::Key::J => Key::J,
+            glfw::Key::K => Key::K,
+            glfw::Key::L => Key::L,
+            glfw::Key::M => Key::M,
+            glfw::Key::N => Key::N,
+            glfw::Key::O => Key::O,
+            glfw::Key::P => Key::P,
+            glfw::Key::Q => Key::Q,
+            glfw::Key::R => Key::R,
+            glfw::Key::S => Key::S,
+            glfw::Key::T => Key::T,
+            glfw::Key::U => Key::U,
+            glfw::Key::V => Key::V,
+            glfw::Key::W => Key::W,
+            glfw::Key::X => Key::X,
+            glfw::Key::Y => Key::Y,
+            glfw::Key::Z => Key::Z,
+            glfw::Key::LeftBracket => Key::LeftBracket,
+            glfw::Key::Backslash => Key::Backslash,
+            glfw::Key::RightBracket => Key::RightBracket,
+            glfw::Key::GraveAccent => Key::GraveAccent,
+            glfw::Key::World1 => Key::World1,
+            glfw::Key::World2 => Key::World2,
+            glfw::Key::Escape => Key::Escape,
+            glfw::Key::Enter => Key::Enter,
+            glfw::Key::Tab => Key::Tab,
+            glfw::Key::Backspace => Key::Backspace,
+            glfw::Key::Insert => Key::Insert,
+            glfw::Key::Delete => Key::Delete,
+            glfw::Key::Right => Key::Right,
+            glfw::Key::Left => Key::Left,
+            glfw::Key::Down => Key::Down,
+            glfw::Key::Up => Key::Up,
+            glfw::Key::PageUp => Key::PageUp,
+            glfw::Key::PageDown => Key::PageDown,
+            glfw::Key::Home => Key::Home,
+            glfw::Key::End => Key::End,
+            glfw::Key::CapsLock => Key::CapsLock,
+            glfw::Key::ScrollLock => Key::ScrollLock,
+            glfw::Key::NumLock => Key::NumLock,
+            glfw::Key::PrintScreen => Key::PrintScreen,
+            glfw::Key::Pause => Key::Pause,
+            glfw::Key::F1 => Key::F1,
+            glfw::Key::F2 => Key::F2,
+            glfw::Key::F3 => Key::F3,
+            glfw::Key::F4 => Key::F4,
+            glfw::Key::F5 => Key::F5,
+            glfw::Key::F6 => Key::F6,
+            glfw::Key::F7 => Key::F7,
+            glfw::Key::F8 => Key::F8,
+            glfw::Key::F9 => Key::F9,
+            glfw::Key::F10 => Key::F10,
+            glfw::Key::F11 => Key::F11,
+            glfw::Key::F12 => Key::F12,
+            glfw::Key::F13 => Key::F13,
+            glfw::Key::F14 => Key::F14,
+            glfw::Key::F15 => Key::F15,
+            glfw::Key::F16 => Key::F16,
+            glfw::Key::F17 => Key::F17,
+            glfw::Key::F18 => Key::F18,
+            glfw::Key::F19 => Key::F19,
+            glfw::Key::F20 => Key::F20,
+            glfw::Key::F21 => Key::F21,
+            glfw::Key::F22 => Key::F22,
+            glfw::Key::F23 => Key::F23,
+            glfw::Key::F24 => Key::F24,
+            glfw::Key::F25 => Key::F25,
+            glfw::Key::Kp0 => Key::Kp0,
+            glfw::Key::Kp1 => Key::Kp1,
+            glfw::Key::Kp2 => Key::Kp2,
+            glfw::Key::Kp3 => Key::Kp3,
+            glfw::Key::Kp4 => Key::Kp4,
+            glfw::Key::Kp5 => Key::Kp5,
+            glfw::Key::Kp6 => Key::Kp6,
+            glfw::Key::Kp7 => Key::Kp7,
+            glfw::Key::Kp8 => Key::Kp8,
+            glfw::Key::Kp9 => Key::Kp9,
+            glfw::Key::KpDecimal => Key::KpDecimal,
+            glfw::Key::KpDivide => Key::KpDivide,
+            glfw::Key::KpMultiply => Key::KpMultiply,
+            glfw::Key::KpSubtract => Key::KpSubtract,
+            glfw::Key::KpAdd => Key::KpAdd,
+            glfw::Key::KpEnter => Key::KpEnter,
+            glfw::Key::KpEqual => Key::KpEqual,
+            glfw::Key::LeftShift => Key::LeftShift,
+            glfw::Key::LeftControl => Key::LeftControl,
+            glfw::Key::LeftAlt => Key::LeftAlt,
+            glfw::Key::LeftSuper => Key::LeftSuper,
+            glfw::Key::RightShift => Key::RightShift,
+            glfw::Key::RightControl => Key::RightControl,
+            glfw::Key::RightAlt => Key::RightAlt,
+            glfw::Key::RightSuper => Key::RightSuper,
+            glfw::Key::Menu => Key::Menu,
+            glfw::Key::Unknown => Key::Unknown,
+        }
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    Button4,
+    Button5,
+    Button6,
+    Button7,
+    Button8
+}
+
+impl MouseButton {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            MouseButton::Left => "Left",
+            MouseButton::Right => "Right",
+            MouseButton::Middle => "Middle",
+            MouseButton::Button4 => "Button4",
+            MouseButton::Button5 => "Button5",
+            MouseButton::Button6 => "Button6",
+            MouseButton::Button7 => "Button7",
+            MouseButton::Button8 => "Button8",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<MouseButton> {
+        Some(match name {
+            "Left" => MouseButton::Left,
+            "Right" => MouseButton::Right,
+            "Middle" => MouseButton::Middle,
+            "Button4" => MouseButton::Button4,
+            "Button5" => MouseButton::Button5,
+            "Button6" => MouseButton::Button6,
+            "Button7" => MouseButton::Button7,
+            "Button8" => MouseButton::Button8,
+            _ => return None
+        })
+    }
+
+    pub(crate) fn from_glfw(button: glfw::MouseButton) -> MouseButton {
+        match button {
+            glfw::MouseButton::Button1 => MouseButton::Left,
+            glfw::MouseButton::Button2 => MouseButton::Right,
+            glfw::MouseButton::Button3 => MouseButton::Middle,
+            glfw::MouseButton::Button4 => MouseButton::Button4,
+            glfw::MouseButton::Button5 => MouseButton::Button5,
+            glfw::MouseButton::Button6 => MouseButton::Button6,
+            glfw::MouseButton::Button7 => MouseButton::Button7,
+            glfw::MouseButton::Button8 => MouseButton::Button8
+        }
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum Action {
+    Press,
+    Release,
+    /// Sent while a key is held down, at the system's key repeat rate.
+    Repeat
+}
+
+impl Action {
+    pub(crate) fn from_glfw(action: glfw::Action) -> Action {
+        match action {
+            glfw::Action::Press => Action::Press,
+            glfw::Action::Release => Action::Release,
+            glfw::Action::Repeat => Action::Repeat
+        }
+    }
+}
+
+/// Modifier keys held when an event happened.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    pub super_key: bool,
+    pub caps_lock: bool,
+    pub num_lock: bool
+}
+
+impl Modifiers {
+    pub(crate) fn from_glfw(mods: glfw::Modifiers) -> Modifiers {
+        Modifiers {
+            shift: mods.contains(glfw::Modifiers::Shift),
+            control: mods.contains(glfw::Modifiers::Control),
+            alt: mods.contains(glfw::Modifiers::Alt),
+            super_key: mods.contains(glfw::Modifiers::Super),
+            caps_lock: mods.contains(glfw::Modifiers::CapsLock),
+            num_lock: mods.contains(glfw::Modifiers::NumLock)
+        }
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Event {
+    Key { key: Key, scancode: i32, action: Action, modifiers: Modifiers },
+    /// Text input, after keyboard layout and dead keys are applied.
+    Text(char),
+    MouseButton { button: MouseButton, action: Action, modifiers: Modifiers },
+    /// Cursor position in screen coordinates relative to the top-left of the content area.
+    CursorMoved { x: f64, y: f64 },
+    CursorEntered(bool),
+    Scroll { x: f64, y: f64 },
+    /// Window size in screen coordinates.
+    Resized { width: i32, height: i32 },
+    /// Framebuffer size in pixels, which differs from the window size on HiDPI displays.
+    FramebufferResized { width: i32, height: i32 },
+    Moved { x: i32, y: i32 },
+    Focused(bool),
+    Minimized(bool),
+    Maximized(bool),
+    CloseRequested,
+    /// The window contents need to be redrawn.
+    Refresh,
+    FilesDropped(Vec<PathBuf>),
+    ContentScale { x: f32, y: f32 },
+    /// Sent to every window being polled, since joysticks don't belong to a window.
+    GamepadConnected(GamepadId),
+    GamepadDisconnected(GamepadId)
+}
+
+impl Event {
+    /// Converts a GLFW event. Events that duplicate others (`CharModifiers`) yield `None`.
+    pub(crate) fn from_glfw(event: glfw::WindowEvent) -> Option<Event> {
+        Some(match event {
+            glfw::WindowEvent::Key(key, scancode, action, mods) => Event::Key {
+                key: Key::from_glfw(key),
+                scancode,
+                action: Action::from_glfw(action),
+                modifiers: Modifiers::from_glfw(mods)
+            },
+            glfw::WindowEvent::Char(c) => Event::Text(c),
+            glfw::WindowEvent::CharModifiers(_, _) => return None,
+            glfw::WindowEvent::MouseButton(button, action, mods) => Event::MouseButton {
+                button: MouseButton::from_glfw(button),
+                action: Action::from_glfw(action),
+                modifiers: Modifiers::from_glfw(mods)
+            },
+            glfw::WindowEvent::CursorPos(x, y) => Event::CursorMoved { x, y },
+            glfw::WindowEvent::CursorEnter(entered) => Event::CursorEntered(entered),
+            glfw::WindowEvent::Scroll(x, y) => Event::Scroll { x, y },
+            glfw::WindowEvent::Size(width, height) => Event::Resized { width, height },
+            glfw::WindowEvent::FramebufferSize(width, height) => Event::FramebufferResized { width, height },
+            glfw::WindowEvent::Pos(x, y) => Event::Moved { x, y },
+            glfw::WindowEvent::Focus(focused) => Event::Focused(focused),
+            glfw::WindowEvent::Iconify(minimized) => Event::Minimized(minimized),
+            glfw::WindowEvent::Maximize(maximized) => Event::Maximized(maximized),
+            glfw::WindowEvent::Close => Event::CloseRequested,
+            glfw::WindowEvent::Refresh => Event::Refresh,
+            glfw::WindowEvent::FileDrop(paths) => Event::FilesDropped(paths),
+            glfw::WindowEvent::ContentScale(x, y) => Event::ContentScale { x, y }
+        })
+    }
+}
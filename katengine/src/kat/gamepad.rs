@@ -0,0 +1,469 @@
+//! Joysticks and gamepads.
+//!
+//! GLFW reports up to sixteen joysticks. Those with an SDL_GameControllerDB mapping are
+//! gamepads and also report standardized state in the Xbox-style layout below. GLFW ships
+//! with a mapping database; `Engine::add_gamepad_mappings` adds to or overrides it.
+
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+use glfw::Glfw;
+use crate::kat::{Engine, Event};
+
+/// A button, named by its position on an Xbox controller.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum GamepadButton {
+    A,
+    B,
+    X,
+    Y,
+    LeftBumper,
+    RightBumper,
+    Back,
+    Start,
+    Guide,
+    LeftThumb,
+    RightThumb,
+    DpadUp,
+    DpadRight,
+    DpadDown,
+    DpadLeft
+}
+
+impl GamepadButton {
+    pub const ALL: [GamepadButton; 15] = [GamepadButton::A, GamepadButton::B, GamepadButton::X, GamepadButton::Y, GamepadButton::LeftBumper, GamepadButton::RightBumper, GamepadButton::Back, GamepadButton::Start, GamepadButton::Guide, GamepadButton::LeftThumb, GamepadButton::RightThumb, GamepadButton::DpadUp, GamepadButton::DpadRight, GamepadButton::DpadDown, GamepadButton::DpadLeft];
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            GamepadButton::A => "A",
+            GamepadButton::B => "B",
+            GamepadButton::X => "X",
+            GamepadButton::Y => "Y",
+            GamepadButton::LeftBumper => "LeftBumper",
+            GamepadButton::RightBumper => "RightBumper",
+            GamepadButton::Back => "Back",
+            GamepadButton::Start => "Start",
+            GamepadButton::Guide => "Guide",
+            GamepadButton::LeftThumb => "LeftThumb",
+            GamepadButton::RightThumb => "RightThumb",
+            GamepadButton::DpadUp => "DpadUp",
+            GamepadButton::DpadRight => "DpadRight",
+            GamepadButton::DpadDown => "DpadDown",
+            GamepadButton::DpadLeft => "DpadLeft",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<GamepadButton> {
+        Some(match name {
+            "A" => GamepadButton::A,
+            "B" => GamepadButton::B,
+            "X" => GamepadButton::X,
+            "Y" => GamepadButton::Y,
+            "LeftBumper" => GamepadButton::LeftBumper,
+            "RightBumper" => GamepadButton::RightBumper,
+            "Back" => GamepadButton::Back,
+            "Start" => GamepadButton::Start,
+            "Guide" => GamepadButton::Guide,
+            "LeftThumb" => GamepadButton::LeftThumb,
+            "RightThumb" => GamepadButton::RightThumb,
+            "DpadUp" => GamepadButton::DpadUp,
+            "DpadRight" => GamepadButton::DpadRight,
+            "DpadDown" => GamepadButton::DpadDown,
+            "DpadLeft" => GamepadButton::DpadLeft,
+            _ => return None
+        })
+    }
+
+    const fn to_glfw(self) -> glfw::GamepadButton {
+        match self {
+            GamepadButton::A => glfw::GamepadButton::ButtonA,
+            GamepadButton::B => glfw::GamepadButton::ButtonB,
+            GamepadButton::X => glfw::GamepadButton::ButtonX,
+            GamepadButton::Y => glfw::GamepadButton::ButtonY,
+            GamepadButton::LeftBumper => glfw::GamepadButton::ButtonLeftBumper,
+            GamepadButton::RightBumper => glfw::GamepadButton::ButtonRightBumper,
+            GamepadButton::Back => glfw::GamepadButton::ButtonBack,
+            GamepadButton::Start => glfw::GamepadButton::ButtonStart,
+            GamepadButton::Guide => glfw::GamepadButton::ButtonGuide,
+            GamepadButton::LeftThumb => glfw::GamepadButton::ButtonLeftThumb,
+            GamepadButton::RightThumb => glfw::GamepadButton::ButtonRightThumb,
+            GamepadButton::DpadUp => glfw::GamepadButton::ButtonDpadUp,
+            GamepadButton::DpadRight => glfw::GamepadButton::ButtonDpadRight,
+            GamepadButton::DpadDown => glfw::GamepadButton::ButtonDpadDown,
+            GamepadButton::DpadLeft => glfw::GamepadButton::ButtonDpadLeft
+        }
+    }
+}
+
+/// Sticks range over [-1, 1] with +Y pointing down; triggers over [-1, 1] from released to pressed.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum GamepadAxis {
+    LeftX,
+    LeftY,
+    RightX,
+    RightY,
+    LeftTrigger,
+    RightTrigger
+}
+
+impl GamepadAxis {
+    pub const ALL: [GamepadAxis; 6] = [GamepadAxis::LeftX, GamepadAxis::LeftY, GamepadAxis::RightX, GamepadAxis::RightY, GamepadAxis::LeftTrigger, GamepadAxis::RightTrigger];
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            GamepadAxis::LeftX => "LeftX",
+            GamepadAxis::LeftY => "LeftY",
+            GamepadAxis::RightX => "RightX",
+            GamepadAxis::RightY => "RightY",
+            GamepadAxis::LeftTrigger => "LeftTrigger",
+            GamepadAxis::RightTrigger => "RightTrigger",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<GamepadAxis> {
+        Some(match name {
+            "LeftX" => GamepadAxis::LeftX,
+            "LeftY" => GamepadAxis::LeftY,
+            "RightX" => GamepadAxis::RightX,
+            "RightY" => GamepadAxis::RightY,
+            "LeftTrigger" => GamepadAxis::LeftTrigger,
+            "RightTrigger" => GamepadAxis::RightTrigger,
+            _ => return None
+        })
+    }
+
+    const fn to_glfw(self) -> glfw::GamepadAxis {
+        match self {
+            GamepadAxis::LeftX => glfw::GamepadAxis::AxisLeftX,
+            GamepadAxis::LeftY => glfw::GamepadAxis::AxisLeftY,
+            GamepadAxis::RightX => glfw::GamepadAxis::AxisRightX,
+            GamepadAxis::RightY => glfw::GamepadAxis::AxisRightY,
+            GamepadAxis::LeftTrigger => glfw::GamepadAxis::AxisLeftTrigger,
+            GamepadAxis::RightTrigger => glfw::GamepadAxis::AxisRightTrigger
+        }
+    }
+}
+
+/// Button and axis state of one gamepad.
+#[derive(Clone,Copy,PartialEq,Debug,Default)]
+pub struct GamepadState {
+    pub buttons: [bool; 15],
+    pub axes: [f32; 6]
+}
+
+impl GamepadState {
+    fn from_glfw(state: &glfw::GamepadState) -> GamepadState {
+        let mut result = GamepadState::default();
+        for button in GamepadButton::ALL {
+            result.buttons[button as usize] = state.get_button_state(button.to_glfw()) != glfw::Action::Release;
+        }
+        for axis in GamepadAxis::ALL {
+            result.axes[axis as usize] = state.get_axis(axis.to_glfw());
+        }
+        return result;
+    }
+
+    pub fn button(&self, button: GamepadButton) -> bool {
+        self.buttons[button as usize]
+    }
+
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes[axis as usize]
+    }
+}
+
+const JOYSTICK_COUNT: usize = 16;
+
+/// One of the joystick slots. A device keeps its slot until it is disconnected.
+#[derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug)]
+pub struct GamepadId(u8);
+
+impl GamepadId {
+    pub fn of(index: usize) -> Option<GamepadId> {
+        if index < JOYSTICK_COUNT { Some(GamepadId(index as u8)) } else { None }
+    }
+
+    pub const fn index(&self) -> usize {
+        self.0 as usize
+    }
+
+    fn to_glfw(self) -> glfw::JoystickId {
+        glfw::JoystickId::from_i32(self.0 as i32).unwrap()
+    }
+}
+
+impl Display for GamepadId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "joystick {}", self.0 + 1)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug)]
+pub struct JoystickInfo {
+    pub id: GamepadId,
+    pub name: String,
+    /// SDL-style GUID, the key mappings are looked up by.
+    pub guid: String,
+    /// The mapping's name when the joystick has one, i.e. when it is a gamepad.
+    pub gamepad_name: Option<String>
+}
+
+impl JoystickInfo {
+    pub fn is_gamepad(&self) -> bool {
+        self.gamepad_name.is_some()
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub struct Hat {
+    pub up: bool,
+    pub right: bool,
+    pub down: bool,
+    pub left: bool
+}
+
+/// Unmapped joystick state, in whatever order the device reports it.
+#[derive(Clone,PartialEq,Debug,Default)]
+pub struct JoystickState {
+    pub axes: Vec<f32>,
+    pub buttons: Vec<bool>,
+    pub hats: Vec<Hat>
+}
+
+/// Connection and gamepad state of every joystick slot, refreshed each event poll.
+#[derive(Debug)]
+pub(crate) struct Gamepads {
+    connected: [bool; JOYSTICK_COUNT],
+    states: [Option<GamepadState>; JOYSTICK_COUNT]
+}
+
+impl Gamepads {
+    pub(crate) fn new() -> Gamepads {
+        Gamepads { connected: [false; JOYSTICK_COUNT], states: [None; JOYSTICK_COUNT] }
+    }
+
+    /// Re-reads every slot, returning connection changes. Joysticks present before the first
+    /// update are reported as connected then.
+    pub(crate) fn update(&mut self, glfw: &Glfw) -> Vec<Event> {
+        let mut events = Vec::new();
+        for index in 0..JOYSTICK_COUNT {
+            let id = GamepadId(index as u8);
+            let joystick = glfw.get_joystick(id.to_glfw());
+            let present = joystick.is_present();
+            if present != self.connected[index] {
+                self.connected[index] = present;
+                events.push(if present { Event::GamepadConnected(id) } else { Event::GamepadDisconnected(id) });
+            }
+            self.states[index] = if present {
+                joystick.get_gamepad_state().map(|s| GamepadState::from_glfw(&s))
+            } else {
+                None
+            };
+        }
+        return events;
+    }
+
+    pub(crate) fn state(&self, id: GamepadId) -> Option<GamepadState> {
+        self.states[id.index()]
+    }
+
+    /// The lowest slot holding a gamepad.
+    pub(crate) fn first(&self) -> Option<GamepadId> {
+        self.states.iter().position(|s| s.is_some()).map(|i| GamepadId(i as u8))
+    }
+}
+
+impl Engine {
+    /// Connected joysticks, gamepads or not.
+    pub fn joysticks(&self) -> Vec<JoystickInfo> {
+        (0..JOYSTICK_COUNT).map(|i| GamepadId(i as u8))
+            .filter(|id| self.gamepads.connected[id.index()])
+            .filter_map(|id| {
+                let joystick = self.glfw_ctx.get_joystick(id.to_glfw());
+                Some(JoystickInfo {
+                    id,
+                    name: joystick.get_name()?,
+                    guid: joystick.get_guid().unwrap_or_default(),
+                    gamepad_name: if joystick.is_gamepad() { joystick.get_gamepad_name() } else { None }
+                })
+            })
+            .collect()
+    }
+
+    /// Standardized state of a gamepad as of the last event poll, or `None` if nothing is
+    /// connected there or the joystick has no mapping.
+    pub fn gamepad_state(&self, id: GamepadId) -> Option<GamepadState> {
+        self.gamepads.state(id)
+    }
+
+    /// Raw state of any connected joystick, read now.
+    pub fn joystick_state(&self, id: GamepadId) -> Option<JoystickState> {
+        let joystick = self.glfw_ctx.get_joystick(id.to_glfw());
+        if !joystick.is_present() {
+            return None;
+        }
+        Some(JoystickState {
+            axes: joystick.get_axes(),
+            buttons: joystick.get_buttons().iter().map(|&b| b != 0).collect(),
+            hats: joystick.get_hats().iter().map(|h| Hat {
+                up: h.contains(glfw::JoystickHats::Up),
+                right: h.contains(glfw::JoystickHats::Right),
+                down: h.contains(glfw::JoystickHats::Down),
+                left: h.contains(glfw::JoystickHats::Left)
+            }).collect()
+        })
+    }
+
+    /// Adds mappings in SDL_GameControllerDB format, one per line, replacing existing
+    /// mappings for the same GUID. Returns the number of mappings read.
+    ///
+    /// Lines are checked before anything is handed to GLFW, so a bad line changes nothing.
+    pub fn add_gamepad_mappings(&mut self, mappings: &str) -> Result<usize, MappingError> {
+        let count = check_mappings(mappings)?;
+        if !self.glfw_ctx.update_gamepad_mappings(mappings) {
+            return Err(MappingError::Rejected);
+        }
+        Ok(count)
+    }
+
+    /// Adds the mappings in a file such as SDL's `gamecontrollerdb.txt`.
+    pub fn load_gamepad_mappings<P: AsRef<Path>>(&mut self, path: P) -> Result<usize, MappingError> {
+        let mappings = std::fs::read_to_string(path).map_err(MappingError::Io)?;
+        self.add_gamepad_mappings(&mappings)
+    }
+}
+
+const MAPPING_FIELDS: [&str; 21] = [
+    "a", "b", "x", "y", "back", "start", "guide", "leftshoulder", "rightshoulder", "leftstick", "rightstick",
+    "dpup", "dpright", "dpdown", "dpleft", "leftx", "lefty", "rightx", "righty", "lefttrigger", "righttrigger"
+];
+
+/// Checks every mapping line, returning how many there are.
+fn check_mappings(mappings: &str) -> Result<usize, MappingError> {
+    let mut count = 0;
+    for (index, line) in mappings.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        check_mapping(line).map_err(|message| MappingError::Invalid { line: index + 1, message })?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Checks one `GUID,name,field:input,...` line. Fields GLFW doesn't use (`misc1`,
+/// `paddle1`, ...) are allowed, as in the upstream database.
+fn check_mapping(line: &str) -> Result<(), String> {
+    let mut parts = line.split(',');
+    let guid = parts.next().unwrap_or("");
+    if guid.len() != 32 || !guid.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("'{}' is not a 32 digit hexadecimal GUID", guid));
+    }
+    match parts.next() {
+        Some(name) if !name.trim().is_empty() => {}
+        _ => return Err("missing controller name after the GUID".to_string())
+    }
+    for part in parts {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        let (field, input) = part.split_once(':').ok_or_else(|| format!("expected 'field:input', found '{}'", part))?;
+        if field == "platform" {
+            continue;
+        }
+        let field = field.trim_start_matches(['+', '-']);
+        if MAPPING_FIELDS.contains(&field) || field.is_empty() {
+            check_mapping_input(input).map_err(|e| format!("{}: {}", field, e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Checks an input reference: `b<n>`, `a<n>` with optional `+`/`-` range and `~` inversion,
+/// or `h<hat>.<mask>`.
+fn check_mapping_input(input: &str) -> Result<(), String> {
+    let digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    let stripped = input.trim_start_matches(['+', '-']).trim_end_matches('~');
+    let mut chars = stripped.chars();
+    let valid = match (chars.next(), chars.as_str()) {
+        (Some('b' | 'a'), n) => digits(n),
+        (Some('h'), n) => match n.split_once('.') {
+            Some((hat, mask)) => digits(hat) && digits(mask),
+            None => false
+        },
+        _ => false
+    };
+    if !valid {
+        return Err(format!("'{}' is not a button (b0), axis (a0) or hat (h0.1)", input));
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum MappingError {
+    Io(io::Error),
+    Invalid { line: usize, message: String },
+    /// GLFW refused the mappings despite them looking well-formed.
+    Rejected
+}
+
+impl Display for MappingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MappingError::Io(e) => write!(f, "Failed to read gamepad mappings: {}", e),
+            MappingError::Invalid { line, message } => write!(f, "Gamepad mapping line {}: {}", line, message),
+            MappingError::Rejected => write!(f, "GLFW rejected the gamepad mappings")
+        }
+    }
+}
+
+impl std::error::Error for MappingError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XBOX: &str = "030000005e0400008e02000014010000,Xbox 360 Controller,a:b0,b:b1,x:b2,y:b3,back:b6,start:b7,guide:b8,\
+leftshoulder:b4,rightshoulder:b5,leftstick:b9,rightstick:b10,dpup:h0.1,dpright:h0.2,dpdown:h0.4,dpleft:h0.8,\
+leftx:a0,lefty:a1,rightx:a3,righty:a4,lefttrigger:a2,righttrigger:a5,platform:Linux,";
+
+    fn invalid_line(mappings: &str) -> usize {
+        match check_mappings(mappings) {
+            Err(MappingError::Invalid { line, .. }) => line,
+            other => panic!("expected an invalid mapping, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn accepts_database_lines() {
+        let mappings = format!("# Linux\n{}\n\n{}\n", XBOX,
+                               "03000000de280000ff11000001000000,Steam Virtual Gamepad,+leftx:a0,-leftx:a1,lefttrigger:+a2~,misc1:b11,paddle1:b12,");
+        assert_eq!(check_mappings(&mappings).unwrap(), 2);
+    }
+
+    #[test]
+    fn rejects_malformed_guids() {
+        assert_eq!(invalid_line(&XBOX.replacen("030000005e", "030000005g", 1)), 1);
+        assert_eq!(invalid_line(&format!("{}\n{}", XBOX, &XBOX[2..])), 2);
+        assert_eq!(invalid_line("030000005e0400008e02000014010000,\n"), 1);
+    }
+
+    #[test]
+    fn checks_inputs_of_known_fields_only() {
+        assert!(check_mapping("030000005e0400008e02000014010000,Pad,a:b0,frobnicate:zz").is_ok());
+        assert!(check_mapping("030000005e0400008e02000014010000,Pad,a:x0").is_err());
+        assert!(check_mapping("030000005e0400008e02000014010000,Pad,dpup:h0").is_err());
+        assert!(check_mapping("030000005e0400008e02000014010000,Pad,a").is_err());
+    }
+
+    #[test]
+    fn rejects_non_ascii_inputs_without_panicking() {
+        assert!(check_mapping_input("é").is_err());
+        assert!(check_mapping_input("bé").is_err());
+        assert!(check_mapping_input("").is_err());
+        assert!(check_mapping("030000005e0400008e02000014010000,Pad,a:é").is_err());
+        assert_eq!(invalid_line("030000005e0400008e02000014010000,Pad,a:é"), 1);
+    }
+}
@@ -0,0 +1,139 @@
+//! Adapts GLSL sources to the shading language of the current context.
+//!
+//! Desktop sources (`#version 330 core` and newer) are rewritten to the GLSL ES version
+//! with the matching feature set when running on OpenGL ES, and rejected if the context
+//! cannot provide it. Sources already written for GLSL ES are passed through unchanged.
+
+use std::fmt;
+use crate::kat::GlVersion;
+
+/// The shading language a source is compiled for.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum GlslTarget {
+    Desktop,
+    /// OpenGL ES with the given context version.
+    Es(GlVersion)
+}
+
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct GlslError {
+    pub message: String
+}
+
+impl fmt::Display for GlslError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for GlslError {}
+
+fn error(message: String) -> GlslError {
+    GlslError { message }
+}
+
+/// Defaults that GLSL ES leaves undefined but desktop GLSL sources rely on.
+const ES_PRECISION: &str = "precision highp float;\nprecision highp int;\nprecision highp sampler3D;\nprecision highp sampler2DArray;\nprecision highp sampler2DShadow;\nprecision highp samplerCubeShadow;\nprecision highp sampler2DArrayShadow;\n";
+
+/// The GLSL ES version offering the features of desktop GLSL `version`, with the
+/// context version that introduced it.
+fn es_equivalent(version: u32) -> Option<(u32, GlVersion)> {
+    match version {
+        330 => Some((300, GlVersion::of(3, 0))),
+        400..=430 => Some((310, GlVersion::of(3, 1))),
+        440..=460 => Some((320, GlVersion::of(3, 2))),
+        _ => None
+    }
+}
+
+/// Rewrites the `#version` directive of `source` for `target`.
+pub fn prepare_source(source: &str, target: GlslTarget) -> Result<String, GlslError> {
+    let context = match target {
+        GlslTarget::Desktop => return Ok(source.to_string()),
+        GlslTarget::Es(v) => v
+    };
+
+    let (line_index, directive) = source.lines().enumerate()
+        .find(|(_, l)| l.trim_start().starts_with("#version"))
+        .ok_or_else(|| error("OpenGL ES shaders need a #version directive.".to_string()))?;
+
+    let mut parts = directive.trim_start()["#version".len()..].split_whitespace();
+    let version: u32 = parts.next()
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| error(format!("Malformed version directive '{}'.", directive.trim())))?;
+    let profile = parts.next().unwrap_or("");
+
+    if profile == "es" {
+        return Ok(source.to_string());
+    }
+
+    if profile == "compatibility" {
+        return Err(error(format!("Compatibility profile shaders (#version {} compatibility) cannot run on OpenGL ES.", version)));
+    }
+
+    let (es_version, required) = es_equivalent(version)
+        .ok_or_else(|| error(format!("GLSL {} has no OpenGL ES equivalent; use #version 330 core or newer.", version)))?;
+
+    if context < required {
+        return Err(error(format!("GLSL {} needs OpenGL ES {} (GLSL {} es) but the context is OpenGL ES {}.",
+                                 version, required, es_version, context)));
+    }
+
+    let mut out = String::with_capacity(source.len() + ES_PRECISION.len() + 32);
+    for (i, line) in source.lines().enumerate() {
+        if i == line_index {
+            out.push_str(&format!("#version {} es\n", es_version));
+            out.push_str(ES_PRECISION);
+            // Keep compiler messages pointing at the lines of the original file.
+            out.push_str(&format!("#line {}\n", i + 2));
+        } else {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn es(major: u32, minor: u32) -> GlslTarget {
+        GlslTarget::Es(GlVersion::of(major, minor))
+    }
+
+    #[test]
+    fn desktop_sources_become_es() {
+        let source = "// A comment\n#version 330 core\nout vec4 color;\nvoid main() {}";
+        let out = prepare_source(source, es(3, 0)).unwrap();
+        assert_eq!(out, format!("// A comment\n#version 300 es\n{}#line 3\nout vec4 color;\nvoid main() {{}}\n", ES_PRECISION));
+        assert!(prepare_source("#version 430\nvoid main() {}", es(3, 2)).unwrap().starts_with("#version 310 es\n"));
+        assert!(prepare_source("  #version 450 core\nvoid main() {}", es(3, 2)).unwrap().starts_with("#version 320 es\n"));
+    }
+
+    #[test]
+    fn es_and_desktop_targets_pass_through() {
+        let source = "#version 310 es\nvoid main() {}\n";
+        assert_eq!(prepare_source(source, es(3, 2)).unwrap(), source);
+        let source = "#version 120 compatibility\nvoid main() {}\n";
+        assert_eq!(prepare_source(source, GlslTarget::Desktop).unwrap(), source);
+    }
+
+    #[test]
+    fn rejects_what_es_cannot_run() {
+        let message = |source: &str, target| prepare_source(source, target).unwrap_err().message;
+        assert!(message("#version 330 compatibility\n", es(3, 2)).starts_with("Compatibility profile shaders"));
+        assert!(message("#version 150 core\n", es(3, 2)).starts_with("GLSL 150 has no OpenGL ES equivalent"));
+        assert!(message("void main() {}\n", es(3, 0)).contains("#version"));
+        assert!(message("#version core\n", es(3, 0)).starts_with("Malformed version directive"));
+    }
+
+    #[test]
+    fn rejects_sources_newer_than_the_context() {
+        let message = prepare_source("#version 430 core\n", es(3, 0)).unwrap_err().message;
+        assert!(message.starts_with("GLSL 430 needs OpenGL ES 3.1 (GLSL 310 es)"), "{}", message);
+        assert!(prepare_source("#version 460 core\n", es(3, 1)).is_err());
+        assert!(prepare_source("#version 460 core\n", es(3, 2)).is_ok());
+    }
+}
@@ -0,0 +1,1820 @@
+//! glTF 2.0 scenes, from `.gltf` JSON or binary `.glb` files.
+//!
+//! Buffers may be embedded as base64 data URIs, stored next to the file, or held in the
+//! GLB binary chunk. Everything the file references is checked while loading, so a broken
+//! file gives a `GltfError` naming the offending JSON pointer instead of a panic later on.
+//!
+//! Texture coordinates are flipped to the bottom-left origin the rest of the engine uses.
+//! Images are not decoded; `GltfImage` holds their bytes or path. Morph targets are skipped.
+
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use serde_json::Value;
+use crate::kat::DrawMode;
+use crate::kat::mesh::{MeshData, Submesh};
+
+/// Extensions a file may list as required.
+const SUPPORTED_EXTENSIONS: [&str; 1] = ["KHR_lights_punctual"];
+
+/// Most components an accessor without a buffer view may have. Its zeros aren't backed by
+/// any data, so without a limit a tiny file could ask for any amount of memory.
+const MAX_ZEROED_COMPONENTS: usize = 1 << 24;
+
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const GLB_JSON: u32 = 0x4E4F_534A;
+const GLB_BIN: u32 = 0x004E_4942;
+
+#[derive(Debug)]
+pub enum GltfError {
+    Io { path: PathBuf, error: io::Error },
+    Json(serde_json::Error),
+    /// A malformed GLB container.
+    Glb(String),
+    /// `pointer` is the JSON pointer of the value at fault, like `/meshes/0/primitives/1`.
+    Invalid { pointer: String, message: String },
+    UnsupportedExtension(String)
+}
+
+impl Display for GltfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GltfError::Io { path, error } => write!(f, "Failed to read {}: {}", path.display(), error),
+            GltfError::Json(error) => write!(f, "Invalid JSON: {}", error),
+            GltfError::Glb(message) => write!(f, "Invalid GLB: {}", message),
+            GltfError::Invalid { pointer, message } => write!(f, "{}: {}", pointer, message),
+            GltfError::UnsupportedExtension(name) => write!(f, "Unsupported required extension {}", name)
+        }
+    }
+}
+
+impl std::error::Error for GltfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GltfError::Io { error, .. } => Some(error),
+            GltfError::Json(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+/// A whole glTF file. Indices between its parts are checked to be in range.
+#[derive(Clone,PartialEq,Debug,Default)]
+pub struct Gltf {
+    pub meshes: Vec<GltfMesh>,
+    pub materials: Vec<GltfMaterial>,
+    pub textures: Vec<GltfTexture>,
+    pub images: Vec<GltfImage>,
+    pub samplers: Vec<GltfSampler>,
+    pub nodes: Vec<GltfNode>,
+    pub scenes: Vec<GltfScene>,
+    /// The scene to show when the file is opened.
+    pub scene: Option<usize>,
+    pub cameras: Vec<GltfCamera>,
+    pub lights: Vec<GltfLight>,
+    pub skins: Vec<GltfSkin>,
+    pub animations: Vec<GltfAnimation>
+}
+
+impl Gltf {
+    /// Nodes without a parent.
+    pub fn roots(&self) -> Vec<usize> {
+        let mut is_child = vec!(false; self.nodes.len());
+        for node in &self.nodes {
+            for &child in &node.children {
+                is_child[child] = true;
+            }
+        }
+        (0..self.nodes.len()).filter(|&i| !is_child[i]).collect()
+    }
+
+    /// Every node's transform relative to the scene root.
+    pub fn world_transforms(&self) -> Vec<glm::Mat4> {
+        let mut world = vec!(identity(); self.nodes.len());
+        let mut stack: Vec<(usize, glm::Mat4)> = self.roots().into_iter().map(|i| (i, identity())).collect();
+        while let Some((index, parent)) = stack.pop() {
+            let node = &self.nodes[index];
+            world[index] = mul(&parent, &node.local_transform());
+            stack.extend(node.children.iter().map(|&child| (child, world[index])));
+        }
+        world
+    }
+}
+
+#[derive(Clone,PartialEq,Debug,Default)]
+pub struct GltfMesh {
+    pub name: Option<String>,
+    pub primitives: Vec<GltfPrimitive>,
+    /// Default morph target weights.
+    pub weights: Vec<f32>
+}
+
+/// One draw of a mesh. Strips and fans are converted to lists, and unindexed primitives
+/// get indices. Triangles always have normals and, when they have uvs, tangents.
+#[derive(Clone,PartialEq,Debug)]
+pub struct GltfPrimitive {
+    /// A single submesh with material 0.
+    pub mesh: MeshData,
+    /// `Triangles`, `Lines` or `Points`.
+    pub mode: DrawMode,
+    pub material: Option<usize>,
+    /// The following attributes are empty or have one entry per position.
+    pub uvs1: Vec<glm::Vec2>,
+    pub colors: Vec<glm::Vec4>,
+    pub joints: Vec<[u32; 4]>,
+    pub weights: Vec<glm::Vec4>
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum AlphaMode {
+    Opaque,
+    /// Fragments below `alpha_cutoff` are discarded.
+    Mask,
+    Blend
+}
+
+/// A texture used by a material, sampled with the given set of texture coordinates.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct TextureRef {
+    pub texture: usize,
+    pub tex_coord: u32
+}
+
+/// A metallic-roughness PBR material. Metalness is read from the blue channel of
+/// `metallic_roughness_texture`, roughness from green.
+#[derive(Clone,PartialEq,Debug)]
+pub struct GltfMaterial {
+    pub name: Option<String>,
+    pub base_color: glm::Vec4,
+    pub base_color_texture: Option<TextureRef>,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub metallic_roughness_texture: Option<TextureRef>,
+    pub normal_texture: Option<TextureRef>,
+    pub normal_scale: f32,
+    pub occlusion_texture: Option<TextureRef>,
+    pub occlusion_strength: f32,
+    pub emissive: glm::Vec3,
+    pub emissive_texture: Option<TextureRef>,
+    pub alpha_mode: AlphaMode,
+    pub alpha_cutoff: f32,
+    pub double_sided: bool
+}
+
+impl Default for GltfMaterial {
+    /// The material glTF uses for primitives without one.
+    fn default() -> GltfMaterial {
+        GltfMaterial {
+            name: None,
+            base_color: glm::vec4(1.0, 1.0, 1.0, 1.0),
+            base_color_texture: None,
+            metallic: 1.0,
+            roughness: 1.0,
+            metallic_roughness_texture: None,
+            normal_texture: None,
+            normal_scale: 1.0,
+            occlusion_texture: None,
+            occlusion_strength: 1.0,
+            emissive: glm::vec3(0.0, 0.0, 0.0),
+            emissive_texture: None,
+            alpha_mode: AlphaMode::Opaque,
+            alpha_cutoff: 0.5,
+            double_sided: false
+        }
+    }
+}
+
+/// An image with how to sample it. Without a sampler, use repeat wrapping and filtering of
+/// your choice.
+#[derive(Clone,PartialEq,Eq,Debug)]
+pub struct GltfTexture {
+    pub name: Option<String>,
+    pub image: Option<usize>,
+    pub sampler: Option<usize>
+}
+
+#[derive(Clone,PartialEq,Eq,Debug)]
+pub enum ImageSource {
+    /// From a data URI or buffer view.
+    Embedded { data: Vec<u8>, mime_type: Option<String> },
+    /// Resolved against the directory of the glTF file.
+    File(PathBuf)
+}
+
+#[derive(Clone,PartialEq,Eq,Debug)]
+pub struct GltfImage {
+    pub name: Option<String>,
+    pub source: ImageSource
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum MagFilter {
+    Nearest,
+    Linear
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum MinFilter {
+    Nearest,
+    Linear,
+    NearestMipmapNearest,
+    LinearMipmapNearest,
+    NearestMipmapLinear,
+    LinearMipmapLinear
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum WrapMode {
+    ClampToEdge,
+    MirroredRepeat,
+    Repeat
+}
+
+#[derive(Clone,PartialEq,Eq,Debug)]
+pub struct GltfSampler {
+    pub name: Option<String>,
+    /// `None` leaves the choice to the renderer.
+    pub mag_filter: Option<MagFilter>,
+    pub min_filter: Option<MinFilter>,
+    pub wrap_s: WrapMode,
+    pub wrap_t: WrapMode
+}
+
+/// A node of the scene graph. Its transform is `matrix` if given, otherwise translation,
+/// rotation and scale.
+#[derive(Clone,PartialEq,Debug)]
+pub struct GltfNode {
+    pub name: Option<String>,
+    pub children: Vec<usize>,
+    pub mesh: Option<usize>,
+    pub camera: Option<usize>,
+    pub light: Option<usize>,
+    pub skin: Option<usize>,
+    pub translation: glm::Vec3,
+    /// A unit quaternion as x, y, z, w.
+    pub rotation: glm::Vec4,
+    pub scale: glm::Vec3,
+    pub matrix: Option<glm::Mat4>,
+    /// Morph target weights overriding the mesh's.
+    pub weights: Vec<f32>
+}
+
+impl GltfNode {
+    pub fn local_transform(&self) -> glm::Mat4 {
+        if let Some(matrix) = self.matrix {
+            return matrix;
+        }
+        let glm::Vec4 { x, y, z, w } = self.rotation;
+        let (s, t) = (self.scale, self.translation);
+        glm::Mat4::new(
+            glm::vec4((1.0 - 2.0 * (y * y + z * z)) * s.x, 2.0 * (x * y + z * w) * s.x, 2.0 * (x * z - y * w) * s.x, 0.0),
+            glm::vec4(2.0 * (x * y - z * w) * s.y, (1.0 - 2.0 * (x * x + z * z)) * s.y, 2.0 * (y * z + x * w) * s.y, 0.0),
+            glm::vec4(2.0 * (x * z + y * w) * s.z, 2.0 * (y * z - x * w) * s.z, (1.0 - 2.0 * (x * x + y * y)) * s.z, 0.0),
+            glm::vec4(t.x, t.y, t.z, 1.0))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug)]
+pub struct GltfScene {
+    pub name: Option<String>,
+    pub nodes: Vec<usize>
+}
+
+/// Looks down the node's -Z axis, with +Y up.
+#[derive(Clone,PartialEq,Debug)]
+pub struct GltfCamera {
+    pub name: Option<String>,
+    pub projection: Projection
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub enum Projection {
+    /// `yfov` is in radians. Without `zfar` the projection is infinite, and without
+    /// `aspect_ratio` the viewport's is used.
+    Perspective { yfov: f32, aspect_ratio: Option<f32>, znear: f32, zfar: Option<f32> },
+    Orthographic { xmag: f32, ymag: f32, znear: f32, zfar: f32 }
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub enum LightKind {
+    /// Intensity in lux.
+    Directional,
+    /// Intensity in candela.
+    Point,
+    /// Cone angles in radians from the -Z axis.
+    Spot { inner_cone_angle: f32, outer_cone_angle: f32 }
+}
+
+/// A light from `KHR_lights_punctual`, shining down the node's -Z axis.
+#[derive(Clone,PartialEq,Debug)]
+pub struct GltfLight {
+    pub name: Option<String>,
+    pub kind: LightKind,
+    pub color: glm::Vec3,
+    pub intensity: f32,
+    /// Distance where the light reaches zero, or infinite.
+    pub range: Option<f32>
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct GltfSkin {
+    pub name: Option<String>,
+    pub joints: Vec<usize>,
+    pub skeleton: Option<usize>,
+    /// One per joint; identities when the file gives none.
+    pub inverse_bind_matrices: Vec<glm::Mat4>
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum AnimationPath {
+    Translation,
+    Rotation,
+    Scale,
+    Weights
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum Interpolation {
+    Linear,
+    Step,
+    /// Each keyframe holds an in-tangent, the value and an out-tangent, in that order.
+    CubicSpline
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct GltfAnimationSampler {
+    pub interpolation: Interpolation,
+    /// Keyframe times in seconds, ascending.
+    pub times: Vec<f32>,
+    /// Keyframe values, flattened. Each keyframe has as many floats as the animated
+    /// property, or three times that for cubic splines.
+    pub values: Vec<f32>
+}
+
+/// Drives one property of a node. Channels without a node are kept but animate nothing.
+#[derive(Clone,Copy,PartialEq,Eq,Debug)]
+pub struct GltfChannel {
+    pub sampler: usize,
+    pub node: Option<usize>,
+    pub path: AnimationPath
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct GltfAnimation {
+    pub name: Option<String>,
+    pub channels: Vec<GltfChannel>,
+    pub samplers: Vec<GltfAnimationSampler>
+}
+
+/// Loads a `.gltf` or `.glb` file. External buffers and image paths are resolved against
+/// its directory.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Gltf, GltfError> {
+    let path = path.as_ref();
+    let data = read(path)?;
+    parse(&data, path.parent().unwrap_or(Path::new("")))
+}
+
+/// Parses glTF JSON or a GLB container. `base` is where relative URIs point to.
+pub fn parse(data: &[u8], base: &Path) -> Result<Gltf, GltfError> {
+    let (json, bin) = if data.len() >= 4 && u32_at(data, 0) == GLB_MAGIC {
+        split_glb(data)?
+    } else {
+        (data, None)
+    };
+    let root: Value = serde_json::from_slice(json).map_err(GltfError::Json)?;
+    Loader::new(&root, bin, base)?.load()
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, GltfError> {
+    std::fs::read(path).map_err(|error| GltfError::Io { path: path.to_path_buf(), error })
+}
+
+fn u32_at(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+}
+
+/// The JSON chunk and, if present, the binary chunk of a GLB file.
+fn split_glb(data: &[u8]) -> Result<(&[u8], Option<&[u8]>), GltfError> {
+    if data.len() < 12 {
+        return Err(GltfError::Glb("header is truncated".to_string()));
+    }
+    let version = u32_at(data, 4);
+    if version != 2 {
+        return Err(GltfError::Glb(format!("version {} is not supported", version)));
+    }
+    let length = u32_at(data, 8) as usize;
+    if length > data.len() {
+        return Err(GltfError::Glb(format!("header claims {} bytes but the file has {}", length, data.len())));
+    }
+
+    let mut chunks = Vec::new();
+    let mut offset = 12;
+    while offset < length {
+        if offset + 8 > length {
+            return Err(GltfError::Glb(format!("chunk header at byte {} is truncated", offset)));
+        }
+        let (chunk_length, kind) = (u32_at(data, offset) as usize, u32_at(data, offset + 4));
+        let start = offset + 8;
+        if chunk_length > length - start {
+            return Err(GltfError::Glb(format!("chunk at byte {} runs past the end of the file", offset)));
+        }
+        chunks.push((kind, &data[start..start + chunk_length]));
+        // Chunks are padded to four bytes.
+        offset = start + chunk_length.div_ceil(4) * 4;
+    }
+
+    match chunks.as_slice() {
+        [(GLB_JSON, json), rest @ ..] => {
+            let bin = rest.first().filter(|(kind, _)| *kind == GLB_BIN).map(|(_, bin)| *bin);
+            Ok((*json, bin))
+        }
+        _ => Err(GltfError::Glb("the first chunk is not JSON".to_string()))
+    }
+}
+
+/// Decodes the payload of a base64 `data:` URI.
+fn decode_data_uri(uri: &str) -> Option<(Vec<u8>, Option<String>)> {
+    let (header, payload) = uri.strip_prefix("data:")?.split_once(',')?;
+    let mime_type = header.strip_suffix(";base64")?;
+    let mime_type = if mime_type.is_empty() { None } else { Some(mime_type.to_string()) };
+    Some((decode_base64(payload)?, mime_type))
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let (mut bits, mut count) = (0u32, 0);
+    for c in text.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None
+        };
+        bits = (bits << 6) | value as u32;
+        count += 6;
+        if count >= 8 {
+            count -= 8;
+            bytes.push((bits >> count) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Undoes `%XX` escapes in a relative URI.
+fn decode_percent(uri: &str) -> String {
+    let bytes = uri.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes.get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], escaped) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn identity() -> glm::Mat4 {
+    glm::Mat4::new(glm::vec4(1.0, 0.0, 0.0, 0.0), glm::vec4(0.0, 1.0, 0.0, 0.0),
+                   glm::vec4(0.0, 0.0, 1.0, 0.0), glm::vec4(0.0, 0.0, 0.0, 1.0))
+}
+
+fn mul(a: &glm::Mat4, b: &glm::Mat4) -> glm::Mat4 {
+    let column = |c: glm::Vec4| a.c0 * c.x + a.c1 * c.y + a.c2 * c.z + a.c3 * c.w;
+    glm::Mat4::new(column(b.c0), column(b.c1), column(b.c2), column(b.c3))
+}
+
+fn matrix_from(values: &[f32]) -> glm::Mat4 {
+    let column = |i: usize| glm::vec4(values[i], values[i + 1], values[i + 2], values[i + 3]);
+    glm::Mat4::new(column(0), column(4), column(8), column(12))
+}
+
+/// A JSON value along with its pointer, for error messages.
+#[derive(Clone)]
+struct At<'a> {
+    value: &'a Value,
+    pointer: String
+}
+
+impl<'a> At<'a> {
+    fn invalid<S: Into<String>>(&self, message: S) -> GltfError {
+        let pointer = if self.pointer.is_empty() { "/".to_string() } else { self.pointer.clone() };
+        GltfError::Invalid { pointer, message: message.into() }
+    }
+
+    fn get(&self, key: &str) -> Option<At<'a>> {
+        self.value.get(key).map(|value| At { value, pointer: format!("{}/{}", self.pointer, key) })
+    }
+
+    fn required(&self, key: &str) -> Result<At<'a>, GltfError> {
+        self.get(key).ok_or_else(|| self.invalid(format!("missing required property '{}'", key)))
+    }
+
+    /// The elements of an array property, or none if it's absent.
+    fn items(&self, key: &str) -> Result<Vec<At<'a>>, GltfError> {
+        match self.get(key) {
+            None => Ok(Vec::new()),
+            Some(at) => {
+                let array = at.value.as_array().ok_or_else(|| at.invalid("expected an array"))?;
+                Ok(array.iter().enumerate().map(|(i, value)| At { value, pointer: format!("{}/{}", at.pointer, i) }).collect())
+            }
+        }
+    }
+
+    fn f32(&self) -> Result<f32, GltfError> {
+        self.value.as_f64().map(|v| v as f32).ok_or_else(|| self.invalid("expected a number"))
+    }
+
+    fn usize(&self) -> Result<usize, GltfError> {
+        self.value.as_u64().map(|v| v as usize).ok_or_else(|| self.invalid("expected a non-negative integer"))
+    }
+
+    fn str(&self) -> Result<&'a str, GltfError> {
+        self.value.as_str().ok_or_else(|| self.invalid("expected a string"))
+    }
+
+    fn f32_or(&self, key: &str, default: f32) -> Result<f32, GltfError> {
+        self.get(key).map_or(Ok(default), |at| at.f32())
+    }
+
+    fn usize_or(&self, key: &str, default: usize) -> Result<usize, GltfError> {
+        self.get(key).map_or(Ok(default), |at| at.usize())
+    }
+
+    fn bool_or(&self, key: &str, default: bool) -> Result<bool, GltfError> {
+        self.get(key).map_or(Ok(default), |at| at.value.as_bool().ok_or_else(|| at.invalid("expected a boolean")))
+    }
+
+    fn string(&self, key: &str) -> Result<Option<String>, GltfError> {
+        self.get(key).map(|at| at.str().map(str::to_string)).transpose()
+    }
+
+    fn name(&self) -> Result<Option<String>, GltfError> {
+        self.string("name")
+    }
+
+    fn floats(&self, key: &str) -> Result<Vec<f32>, GltfError> {
+        self.items(key)?.iter().map(At::f32).collect()
+    }
+
+    fn floats_or<const N: usize>(&self, key: &str, default: [f32; N]) -> Result<[f32; N], GltfError> {
+        let at = match self.get(key) {
+            Some(at) => at,
+            None => return Ok(default)
+        };
+        let values = self.floats(key)?;
+        values.try_into().map_err(|v: Vec<f32>| at.invalid(format!("expected {} numbers, found {}", N, v.len())))
+    }
+
+    /// An index into an array of `len` things.
+    fn index(&self, len: usize, what: &str) -> Result<usize, GltfError> {
+        let index = self.usize()?;
+        if index >= len {
+            return Err(self.invalid(format!("{} {} does not exist; there are {}", what, index, len)));
+        }
+        Ok(index)
+    }
+
+    fn index_of(&self, key: &str, len: usize, what: &str) -> Result<Option<usize>, GltfError> {
+        self.get(key).map(|at| at.index(len, what)).transpose()
+    }
+
+    fn required_index(&self, key: &str, len: usize, what: &str) -> Result<usize, GltfError> {
+        self.required(key)?.index(len, what)
+    }
+
+    fn indices(&self, key: &str, len: usize, what: &str) -> Result<Vec<usize>, GltfError> {
+        self.items(key)?.iter().map(|at| at.index(len, what)).collect()
+    }
+
+    fn positive(&self, key: &str) -> Result<f32, GltfError> {
+        let at = self.required(key)?;
+        let value = at.f32()?;
+        if value <= 0.0 {
+            return Err(at.invalid("must be greater than zero"));
+        }
+        Ok(value)
+    }
+}
+
+#[derive(Clone,Copy)]
+struct BufferView {
+    buffer: usize,
+    offset: usize,
+    length: usize,
+    stride: Option<usize>
+}
+
+#[derive(Clone,Copy,PartialEq,Eq)]
+enum ComponentType {
+    I8,
+    U8,
+    I16,
+    U16,
+    U32,
+    F32
+}
+
+impl ComponentType {
+    fn of(code: usize) -> Option<ComponentType> {
+        match code {
+            5120 => Some(ComponentType::I8),
+            5121 => Some(ComponentType::U8),
+            5122 => Some(ComponentType::I16),
+            5123 => Some(ComponentType::U16),
+            5125 => Some(ComponentType::U32),
+            5126 => Some(ComponentType::F32),
+            _ => None
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            ComponentType::I8 | ComponentType::U8 => 1,
+            ComponentType::I16 | ComponentType::U16 => 2,
+            ComponentType::U32 | ComponentType::F32 => 4
+        }
+    }
+
+    fn read(self, bytes: &[u8], normalized: bool) -> f64 {
+        let (value, max) = match self {
+            ComponentType::I8 => (bytes[0] as i8 as f64, 127.0),
+            ComponentType::U8 => (bytes[0] as f64, 255.0),
+            ComponentType::I16 => (i16::from_le_bytes([bytes[0], bytes[1]]) as f64, 32767.0),
+            ComponentType::U16 => (u16::from_le_bytes([bytes[0], bytes[1]]) as f64, 65535.0),
+            ComponentType::U32 => (u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64, 4294967295.0),
+            ComponentType::F32 => return f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64
+        };
+        if normalized { (value / max).max(-1.0) } else { value }
+    }
+}
+
+
+/// How the components of one accessor element are stored.
+#[derive(Clone,Copy)]
+struct Layout {
+    component_type: ComponentType,
+    normalized: bool,
+    columns: usize,
+    rows: usize
+}
+
+impl Layout {
+    fn components(self) -> usize {
+        self.columns * self.rows
+    }
+
+    /// Matrix columns start on four byte boundaries.
+    fn column_size(self) -> usize {
+        let size = self.rows * self.component_type.size();
+        if self.columns > 1 { size.div_ceil(4) * 4 } else { size }
+    }
+
+    fn size(self) -> usize {
+        self.columns * self.column_size()
+    }
+}
+
+/// The decoded elements of an accessor.
+struct Accessor<'a> {
+    index: usize,
+    /// `SCALAR`, `VEC3`, `MAT4` and so on.
+    kind: &'a str,
+    layout: Layout,
+    count: usize,
+    values: Vec<f64>
+}
+
+impl Accessor<'_> {
+    /// Checks the element type, and that the components are floats (or normalized integers)
+    /// or unsigned integers.
+    fn check(&self, at: &At, kinds: &[&str], float: bool) -> Result<(), GltfError> {
+        if !kinds.contains(&self.kind) {
+            return Err(at.invalid(format!("accessor {} is {}, expected {}", self.index, self.kind, kinds.join(" or "))));
+        }
+        let component_type = self.layout.component_type;
+        let valid = if float {
+            component_type == ComponentType::F32 || self.layout.normalized
+        } else {
+            !self.layout.normalized && matches!(component_type, ComponentType::U8 | ComponentType::U16 | ComponentType::U32)
+        };
+        if !valid {
+            let expected = if float { "floats or normalized integers" } else { "unsigned integers" };
+            return Err(at.invalid(format!("accessor {} must hold {}", self.index, expected)));
+        }
+        Ok(())
+    }
+
+    fn floats(&self) -> Vec<f32> {
+        self.values.iter().map(|&v| v as f32).collect()
+    }
+
+    fn uints(&self) -> Vec<u32> {
+        self.values.iter().map(|&v| v as u32).collect()
+    }
+
+    fn vec2s(&self) -> Vec<glm::Vec2> {
+        self.values.chunks_exact(2).map(|v| glm::vec2(v[0] as f32, v[1] as f32)).collect()
+    }
+
+    fn vec3s(&self) -> Vec<glm::Vec3> {
+        self.values.chunks_exact(3).map(|v| glm::vec3(v[0] as f32, v[1] as f32, v[2] as f32)).collect()
+    }
+
+    /// Three component vectors get a w of one.
+    fn vec4s(&self) -> Vec<glm::Vec4> {
+        let n = self.layout.components();
+        self.values.chunks_exact(n)
+            .map(|v| glm::vec4(v[0] as f32, v[1] as f32, v[2] as f32, if n == 4 { v[3] as f32 } else { 1.0 }))
+            .collect()
+    }
+}
+
+/// Resolves a glTF document. The buffers and buffer views are read up front, everything
+/// else as it is reached.
+struct Loader<'a> {
+    root: At<'a>,
+    base: &'a Path,
+    buffers: Vec<Vec<u8>>,
+    views: Vec<BufferView>,
+    accessors: Vec<At<'a>>
+}
+
+impl<'a> Loader<'a> {
+    fn new(root: &'a Value, bin: Option<&[u8]>, base: &'a Path) -> Result<Loader<'a>, GltfError> {
+        let root = At { value: root, pointer: String::new() };
+        if !root.value.is_object() {
+            return Err(root.invalid("expected an object"));
+        }
+        let version_at = root.required("asset")?.required("version")?;
+        let version = version_at.str()?;
+        if version.split('.').next() != Some("2") {
+            return Err(version_at.invalid(format!("glTF {} is not supported", version)));
+        }
+        for extension in root.items("extensionsRequired")? {
+            let name = extension.str()?;
+            if !SUPPORTED_EXTENSIONS.contains(&name) {
+                return Err(GltfError::UnsupportedExtension(name.to_string()));
+            }
+        }
+
+        let mut buffers = Vec::new();
+        for (i, at) in root.items("buffers")?.iter().enumerate() {
+            let length_at = at.required("byteLength")?;
+            let length = length_at.usize()?;
+            let data = match at.get("uri") {
+                Some(uri_at) => {
+                    let uri = uri_at.str()?;
+                    if uri.starts_with("data:") {
+                        decode_data_uri(uri).ok_or_else(|| uri_at.invalid("malformed base64 data URI"))?.0
+                    } else {
+                        read(&base.join(decode_percent(uri)))?
+                    }
+                }
+                None => match bin {
+                    Some(bin) if i == 0 => bin.to_vec(),
+                    _ => return Err(at.invalid("buffer has no uri and there is no GLB binary chunk for it"))
+                }
+            };
+            if data.len() < length {
+                return Err(length_at.invalid(format!("buffer is {} bytes but only {} were found", length, data.len())));
+            }
+            buffers.push(data);
+        }
+
+        let mut views = Vec::new();
+        for at in root.items("bufferViews")? {
+            let buffer = at.required_index("buffer", buffers.len(), "Buffer")?;
+            let offset = at.usize_or("byteOffset", 0)?;
+            let length = at.required("byteLength")?.usize()?;
+            if offset.checked_add(length).is_none_or(|end| end > buffers[buffer].len()) {
+                return Err(at.invalid(format!("{} bytes from byte {} are past the end of buffer {}", length, offset, buffer)));
+            }
+            let stride = match at.get("byteStride") {
+                Some(stride_at) => {
+                    let stride = stride_at.usize()?;
+                    if !(4..=252).contains(&stride) || !stride.is_multiple_of(4) {
+                        return Err(stride_at.invalid("must be a multiple of 4 from 4 to 252"));
+                    }
+                    Some(stride)
+                }
+                None => None
+            };
+            views.push(BufferView { buffer, offset, length, stride });
+        }
+
+        let accessors = root.items("accessors")?;
+        Ok(Loader { root, base, buffers, views, accessors })
+    }
+
+    /// The length of a top-level array, for checking indices into it.
+    fn count(&self, key: &str) -> usize {
+        self.root.value.get(key).and_then(Value::as_array).map_or(0, Vec::len)
+    }
+
+    fn light_values(&self) -> Result<Vec<At<'a>>, GltfError> {
+        match self.root.get("extensions").and_then(|e| e.get("KHR_lights_punctual")) {
+            Some(at) => at.items("lights"),
+            None => Ok(Vec::new())
+        }
+    }
+
+    fn view_bytes(&self, view: BufferView) -> &[u8] {
+        &self.buffers[view.buffer][view.offset..view.offset + view.length]
+    }
+
+    /// Reads `count` elements from a buffer view, `stride` bytes apart.
+    fn read_elements(&self, at: &At, view: usize, offset: usize, stride: usize, count: usize, layout: Layout) -> Result<Vec<f64>, GltfError> {
+        let view = self.views[view];
+        // Every byte read below is before `needed`, so once it fits nothing else can overflow.
+        let needed = stride.checked_mul(count - 1).and_then(|n| n.checked_add(offset)).and_then(|n| n.checked_add(layout.size()));
+        match needed {
+            Some(needed) if needed <= view.length => {}
+            Some(needed) => return Err(at.invalid(format!("needs {} bytes of a buffer view that has {}", needed, view.length))),
+            None => return Err(at.invalid(format!("{} elements from byte {} are past the end of the buffer view", count, offset)))
+        }
+        let bytes = self.view_bytes(view);
+        let size = layout.component_type.size();
+        let mut values = Vec::with_capacity(count * layout.components());
+        for element in 0..count {
+            for column in 0..layout.columns {
+                let start = offset + element * stride + column * layout.column_size();
+                for row in 0..layout.rows {
+                    let at = start + row * size;
+                    values.push(layout.component_type.read(&bytes[at..at + size], layout.normalized));
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    /// Decodes the accessor `at` refers to.
+    fn accessor(&self, at: &At) -> Result<Accessor<'a>, GltfError> {
+        let index = at.index(self.accessors.len(), "Accessor")?;
+        let accessor = &self.accessors[index];
+        let type_at = accessor.required("componentType")?;
+        let component_type = ComponentType::of(type_at.usize()?).ok_or_else(|| type_at.invalid("unknown component type"))?;
+        let normalized = accessor.bool_or("normalized", false)?;
+        if normalized && matches!(component_type, ComponentType::U32 | ComponentType::F32) {
+            return Err(accessor.invalid("only 8 and 16 bit integers can be normalized"));
+        }
+        let kind_at = accessor.required("type")?;
+        let kind = kind_at.str()?;
+        let (columns, rows) = match kind {
+            "SCALAR" => (1, 1),
+            "VEC2" => (1, 2),
+            "VEC3" => (1, 3),
+            "VEC4" => (1, 4),
+            "MAT2" => (2, 2),
+            "MAT3" => (3, 3),
+            "MAT4" => (4, 4),
+            _ => return Err(kind_at.invalid(format!("unknown accessor type {}", kind)))
+        };
+        let layout = Layout { component_type, normalized, columns, rows };
+        let count_at = accessor.required("count")?;
+        let count = count_at.usize()?;
+        if count == 0 {
+            return Err(count_at.invalid("must be at least 1"));
+        }
+
+        let mut values = match accessor.get("bufferView") {
+            Some(view_at) => {
+                let view = view_at.index(self.views.len(), "Buffer view")?;
+                let offset = accessor.usize_or("byteOffset", 0)?;
+                let stride = self.views[view].stride.unwrap_or(layout.size());
+                self.read_elements(accessor, view, offset, stride, count, layout)?
+            }
+            // Without a buffer view every element is zero, unless sparse values replace it.
+            None => match count.checked_mul(layout.components()) {
+                Some(components) if components <= MAX_ZEROED_COMPONENTS => vec!(0.0; components),
+                _ => return Err(count_at.invalid(format!("{} elements without a buffer view are more than the {} components allowed",
+                                                         count, MAX_ZEROED_COMPONENTS)))
+            }
+        };
+
+        if let Some(sparse) = accessor.get("sparse") {
+            let sparse_count_at = sparse.required("count")?;
+            let sparse_count = sparse_count_at.usize()?;
+            if sparse_count == 0 || sparse_count > count {
+                return Err(sparse_count_at.invalid(format!("must be from 1 to the accessor's count of {}", count)));
+            }
+            let indices_at = sparse.required("indices")?;
+            let index_type_at = indices_at.required("componentType")?;
+            let index_type = match ComponentType::of(index_type_at.usize()?) {
+                Some(t @ (ComponentType::U8 | ComponentType::U16 | ComponentType::U32)) => t,
+                _ => return Err(index_type_at.invalid("sparse indices must be unsigned integers"))
+            };
+            let index_layout = Layout { component_type: index_type, normalized: false, columns: 1, rows: 1 };
+            let indices = self.read_elements(&indices_at, indices_at.required_index("bufferView", self.views.len(), "Buffer view")?,
+                                             indices_at.usize_or("byteOffset", 0)?, index_type.size(), sparse_count, index_layout)?;
+            let values_at = sparse.required("values")?;
+            let replacements = self.read_elements(&values_at, values_at.required_index("bufferView", self.views.len(), "Buffer view")?,
+                                                  values_at.usize_or("byteOffset", 0)?, layout.size(), sparse_count, layout)?;
+            let n = layout.components();
+            for (i, &element) in indices.iter().enumerate() {
+                let element = element as usize;
+                if element >= count {
+                    return Err(indices_at.invalid(format!("sparse index {} is past the accessor's count of {}", element, count)));
+                }
+                values[element * n..(element + 1) * n].copy_from_slice(&replacements[i * n..(i + 1) * n]);
+            }
+        }
+
+        Ok(Accessor { index, kind, layout, count, values })
+    }
+
+    fn load(&self) -> Result<Gltf, GltfError> {
+        let mut gltf = Gltf {
+            images: self.root.items("images")?.iter().map(|at| self.image(at)).collect::<Result<_, _>>()?,
+            samplers: self.root.items("samplers")?.iter().map(sampler).collect::<Result<_, _>>()?,
+            ..Gltf::default()
+        };
+        let (images, samplers) = (gltf.images.len(), gltf.samplers.len());
+        for at in self.root.items("textures")? {
+            gltf.textures.push(GltfTexture {
+                name: at.name()?,
+                image: at.index_of("source", images, "Image")?,
+                sampler: at.index_of("sampler", samplers, "Sampler")?
+            });
+        }
+        for at in self.root.items("materials")? {
+            gltf.materials.push(material(&at, gltf.textures.len())?);
+        }
+        for at in self.root.items("meshes")? {
+            gltf.meshes.push(self.mesh(&at, gltf.materials.len())?);
+        }
+        for at in self.root.items("cameras")? {
+            gltf.cameras.push(camera(&at)?);
+        }
+        for at in self.light_values()? {
+            gltf.lights.push(light(&at)?);
+        }
+        for at in self.root.items("skins")? {
+            gltf.skins.push(self.skin(&at)?);
+        }
+        let nodes = self.root.items("nodes")?;
+        for at in &nodes {
+            gltf.nodes.push(node(at, nodes.len(), &gltf)?);
+        }
+        check_hierarchy(&gltf.nodes, &nodes)?;
+        let roots = gltf.roots();
+        for at in self.root.items("scenes")? {
+            let scene = GltfScene { name: at.name()?, nodes: at.indices("nodes", gltf.nodes.len(), "Node")? };
+            if let Some(&child) = scene.nodes.iter().find(|&n| !roots.contains(n)) {
+                return Err(at.invalid(format!("node {} is not a root node", child)));
+            }
+            gltf.scenes.push(scene);
+        }
+        gltf.scene = self.root.index_of("scene", gltf.scenes.len(), "Scene")?;
+        for at in self.root.items("animations")? {
+            gltf.animations.push(self.animation(&at, gltf.nodes.len())?);
+        }
+        Ok(gltf)
+    }
+
+    fn image(&self, at: &At) -> Result<GltfImage, GltfError> {
+        let source = match (at.get("uri"), at.get("bufferView")) {
+            (Some(uri_at), None) => {
+                let uri = uri_at.str()?;
+                if uri.starts_with("data:") {
+                    let (data, mime_type) = decode_data_uri(uri).ok_or_else(|| uri_at.invalid("malformed base64 data URI"))?;
+                    ImageSource::Embedded { data, mime_type }
+                } else {
+                    ImageSource::File(self.base.join(decode_percent(uri)))
+                }
+            }
+            (None, Some(view_at)) => {
+                let view = self.views[view_at.index(self.views.len(), "Buffer view")?];
+                let mime_type = at.required("mimeType")?.str()?.to_string();
+                ImageSource::Embedded { data: self.view_bytes(view).to_vec(), mime_type: Some(mime_type) }
+            }
+            _ => return Err(at.invalid("an image needs either a uri or a bufferView"))
+        };
+        Ok(GltfImage { name: at.name()?, source })
+    }
+
+    fn mesh(&self, at: &At, materials: usize) -> Result<GltfMesh, GltfError> {
+        let primitives = at.items("primitives")?;
+        if primitives.is_empty() {
+            return Err(at.invalid("a mesh needs at least one primitive"));
+        }
+        Ok(GltfMesh {
+            name: at.name()?,
+            primitives: primitives.iter().map(|p| self.primitive(p, materials)).collect::<Result<_, _>>()?,
+            weights: at.floats("weights")?
+        })
+    }
+
+    fn primitive(&self, at: &At, materials: usize) -> Result<GltfPrimitive, GltfError> {
+        let attributes = at.required("attributes")?;
+        let position_at = attributes.required("POSITION")?;
+        let positions = self.accessor(&position_at)?;
+        positions.check(&position_at, &["VEC3"], true)?;
+        let count = positions.count;
+
+        // Every attribute but POSITION is optional and must match its count.
+        let attribute = |name: &str, kinds: &[&str], float: bool| -> Result<Option<Accessor<'a>>, GltfError> {
+            let attribute_at = match attributes.get(name) {
+                Some(attribute_at) => attribute_at,
+                None => return Ok(None)
+            };
+            let accessor = self.accessor(&attribute_at)?;
+            accessor.check(&attribute_at, kinds, float)?;
+            if accessor.count != count {
+                return Err(attribute_at.invalid(format!("{} has {} elements for {} positions", name, accessor.count, count)));
+            }
+            Ok(Some(accessor))
+        };
+        // The engine puts the uv origin at the bottom left, glTF at the top left.
+        let flip = |uv: glm::Vec2| glm::vec2(uv.x, 1.0 - uv.y);
+        let mut mesh = MeshData {
+            positions: positions.vec3s(),
+            normals: attribute("NORMAL", &["VEC3"], true)?.map_or(Vec::new(), |a| a.vec3s()),
+            uvs: attribute("TEXCOORD_0", &["VEC2"], true)?.map_or(Vec::new(), |a| a.vec2s().into_iter().map(flip).collect()),
+            // Flipping v mirrors the bitangent.
+            tangents: attribute("TANGENT", &["VEC4"], true)?
+                .map_or(Vec::new(), |a| a.vec4s().into_iter().map(|t| glm::vec4(t.x, t.y, t.z, -t.w)).collect()),
+            ..MeshData::new()
+        };
+        let mut primitive = GltfPrimitive {
+            mesh: MeshData::new(),
+            mode: DrawMode::Triangles,
+            material: at.index_of("material", materials, "Material")?,
+            uvs1: attribute("TEXCOORD_1", &["VEC2"], true)?.map_or(Vec::new(), |a| a.vec2s().into_iter().map(flip).collect()),
+            colors: attribute("COLOR_0", &["VEC3", "VEC4"], true)?.map_or(Vec::new(), |a| a.vec4s()),
+            joints: attribute("JOINTS_0", &["VEC4"], false)?
+                .map_or(Vec::new(), |a| a.uints().chunks_exact(4).map(|j| [j[0], j[1], j[2], j[3]]).collect()),
+            weights: attribute("WEIGHTS_0", &["VEC4"], true)?.map_or(Vec::new(), |a| a.vec4s())
+        };
+
+        let indices = match at.get("indices") {
+            Some(indices_at) => {
+                let accessor = self.accessor(&indices_at)?;
+                accessor.check(&indices_at, &["SCALAR"], false)?;
+                let indices = accessor.uints();
+                if let Some(&index) = indices.iter().find(|&&i| i as usize >= count) {
+                    return Err(indices_at.invalid(format!("index {} is past the {} vertices", index, count)));
+                }
+                indices
+            }
+            None => (0..count as u32).collect()
+        };
+        let (mode, indices) = match at.usize_or("mode", 4)? {
+            0 => (DrawMode::Points, indices),
+            1 => (DrawMode::Lines, indices),
+            2 => (DrawMode::Lines, line_strip(&indices, true)),
+            3 => (DrawMode::Lines, line_strip(&indices, false)),
+            4 => (DrawMode::Triangles, indices),
+            5 => (DrawMode::Triangles, triangle_strip(&indices)),
+            6 => (DrawMode::Triangles, triangle_fan(&indices)),
+            other => return Err(at.invalid(format!("unknown primitive mode {}", other)))
+        };
+        let per_primitive = match mode {
+            DrawMode::Triangles => 3,
+            DrawMode::Lines => 2,
+            _ => 1
+        };
+        if !indices.len().is_multiple_of(per_primitive) {
+            return Err(at.invalid(format!("{} indices do not make whole primitives", indices.len())));
+        }
+        mesh.indices = indices;
+
+        if mode == DrawMode::Triangles {
+            if mesh.normals.is_empty() {
+                flat_normals(&mut mesh, &mut primitive);
+            }
+            if mesh.tangents.is_empty() && !mesh.uvs.is_empty() {
+                mesh.compute_tangents();
+            }
+        }
+        mesh.submeshes = vec!(Submesh::of(0, mesh.indices.len(), 0));
+        primitive.mesh = mesh;
+        primitive.mode = mode;
+        Ok(primitive)
+    }
+
+    fn skin(&self, at: &At) -> Result<GltfSkin, GltfError> {
+        let nodes = self.count("nodes");
+        let joints = at.indices("joints", nodes, "Node")?;
+        if joints.is_empty() {
+            return Err(at.invalid("a skin needs at least one joint"));
+        }
+        let inverse_bind_matrices = match at.get("inverseBindMatrices") {
+            Some(matrices_at) => {
+                let accessor = self.accessor(&matrices_at)?;
+                accessor.check(&matrices_at, &["MAT4"], true)?;
+                if accessor.count < joints.len() {
+                    return Err(matrices_at.invalid(format!("{} matrices for {} joints", accessor.count, joints.len())));
+                }
+                accessor.floats().chunks_exact(16).take(joints.len()).map(matrix_from).collect()
+            }
+            None => vec!(identity(); joints.len())
+        };
+        Ok(GltfSkin { name: at.name()?, joints, skeleton: at.index_of("skeleton", nodes, "Node")?, inverse_bind_matrices })
+    }
+
+    fn animation(&self, at: &At, nodes: usize) -> Result<GltfAnimation, GltfError> {
+        let mut samplers = Vec::new();
+        let mut value_sizes = Vec::new();
+        for sampler_at in at.items("samplers")? {
+            let input_at = sampler_at.required("input")?;
+            let input = self.accessor(&input_at)?;
+            input.check(&input_at, &["SCALAR"], true)?;
+            let times = input.floats();
+            if times.windows(2).any(|w| w[1] <= w[0]) {
+                return Err(input_at.invalid("keyframe times must increase"));
+            }
+            let interpolation = match sampler_at.get("interpolation") {
+                None => Interpolation::Linear,
+                Some(interpolation_at) => match interpolation_at.str()? {
+                    "LINEAR" => Interpolation::Linear,
+                    "STEP" => Interpolation::Step,
+                    "CUBICSPLINE" => Interpolation::CubicSpline,
+                    other => return Err(interpolation_at.invalid(format!("unknown interpolation {}", other)))
+                }
+            };
+            let output_at = sampler_at.required("output")?;
+            let output = self.accessor(&output_at)?;
+            output.check(&output_at, &["SCALAR", "VEC3", "VEC4"], true)?;
+            let keyframes = if interpolation == Interpolation::CubicSpline { 3 * times.len() } else { times.len() };
+            if !output.count.is_multiple_of(keyframes) {
+                return Err(output_at.invalid(format!("{} values for {} keyframes", output.count, keyframes)));
+            }
+            value_sizes.push((output.kind, output.count / keyframes));
+            samplers.push(GltfAnimationSampler { interpolation, times, values: output.floats() });
+        }
+
+        let mut channels = Vec::new();
+        for channel_at in at.items("channels")? {
+            let sampler = channel_at.required_index("sampler", samplers.len(), "Animation sampler")?;
+            let target = channel_at.required("target")?;
+            let path_at = target.required("path")?;
+            let (path, expected) = match path_at.str()? {
+                "translation" => (AnimationPath::Translation, "VEC3"),
+                "rotation" => (AnimationPath::Rotation, "VEC4"),
+                "scale" => (AnimationPath::Scale, "VEC3"),
+                "weights" => (AnimationPath::Weights, "SCALAR"),
+                other => return Err(path_at.invalid(format!("unknown animation path {}", other)))
+            };
+            let (kind, per_keyframe) = value_sizes[sampler];
+            if kind != expected || (path != AnimationPath::Weights && per_keyframe != 1) {
+                return Err(channel_at.invalid(format!("sampler {} outputs {} values, but {} needs one {} per keyframe",
+                                                      sampler, kind, path_at.str()?, expected)));
+            }
+            channels.push(GltfChannel { sampler, node: target.index_of("node", nodes, "Node")?, path });
+        }
+        Ok(GltfAnimation { name: at.name()?, channels, samplers })
+    }
+}
+
+fn line_strip(indices: &[u32], closed: bool) -> Vec<u32> {
+    let mut lines: Vec<u32> = indices.windows(2).flatten().copied().collect();
+    if closed && indices.len() > 2 {
+        lines.extend_from_slice(&[indices[indices.len() - 1], indices[0]]);
+    }
+    lines
+}
+
+/// Every other triangle of a strip is flipped back to the strip's winding.
+fn triangle_strip(indices: &[u32]) -> Vec<u32> {
+    indices.windows(3).enumerate()
+        .flat_map(|(i, w)| if i % 2 == 0 { [w[0], w[1], w[2]] } else { [w[0], w[2], w[1]] })
+        .collect()
+}
+
+fn triangle_fan(indices: &[u32]) -> Vec<u32> {
+    if indices.len() < 3 {
+        return Vec::new();
+    }
+    indices[1..].windows(2).flat_map(|w| [w[0], w[1], indices[0]]).collect()
+}
+
+/// Gives each triangle its own vertices, with the triangle's normal.
+fn flat_normals(mesh: &mut MeshData, primitive: &mut GltfPrimitive) {
+    fn unweld<T: Copy>(values: &mut Vec<T>, indices: &[u32]) {
+        if !values.is_empty() {
+            *values = indices.iter().map(|&i| values[i as usize]).collect();
+        }
+    }
+    let indices = std::mem::take(&mut mesh.indices);
+    unweld(&mut mesh.positions, &indices);
+    unweld(&mut mesh.uvs, &indices);
+    unweld(&mut mesh.tangents, &indices);
+    unweld(&mut primitive.uvs1, &indices);
+    unweld(&mut primitive.colors, &indices);
+    unweld(&mut primitive.joints, &indices);
+    unweld(&mut primitive.weights, &indices);
+    mesh.normals = mesh.positions.chunks_exact(3).flat_map(|p| {
+        let normal = glm::cross(p[1] - p[0], p[2] - p[0]);
+        let normal = if glm::length(normal) > 0.0 { glm::normalize(normal) } else { glm::vec3(0.0, 0.0, 1.0) };
+        [normal; 3]
+    }).collect();
+    mesh.indices = (0..indices.len() as u32).collect();
+}
+
+fn texture_ref(at: Option<At>, textures: usize) -> Result<Option<TextureRef>, GltfError> {
+    match at {
+        None => Ok(None),
+        Some(at) => Ok(Some(TextureRef {
+            texture: at.required_index("index", textures, "Texture")?,
+            tex_coord: at.usize_or("texCoord", 0)? as u32
+        }))
+    }
+}
+
+fn material(at: &At, textures: usize) -> Result<GltfMaterial, GltfError> {
+    let mut material = GltfMaterial { name: at.name()?, ..GltfMaterial::default() };
+    if let Some(pbr) = at.get("pbrMetallicRoughness") {
+        let [r, g, b, a] = pbr.floats_or("baseColorFactor", [1.0; 4])?;
+        material.base_color = glm::vec4(r, g, b, a);
+        material.base_color_texture = texture_ref(pbr.get("baseColorTexture"), textures)?;
+        material.metallic = pbr.f32_or("metallicFactor", 1.0)?;
+        material.roughness = pbr.f32_or("roughnessFactor", 1.0)?;
+        material.metallic_roughness_texture = texture_ref(pbr.get("metallicRoughnessTexture"), textures)?;
+    }
+    if let Some(normal) = at.get("normalTexture") {
+        material.normal_scale = normal.f32_or("scale", 1.0)?;
+    }
+    material.normal_texture = texture_ref(at.get("normalTexture"), textures)?;
+    if let Some(occlusion) = at.get("occlusionTexture") {
+        material.occlusion_strength = occlusion.f32_or("strength", 1.0)?;
+    }
+    material.occlusion_texture = texture_ref(at.get("occlusionTexture"), textures)?;
+    let [r, g, b] = at.floats_or("emissiveFactor", [0.0; 3])?;
+    material.emissive = glm::vec3(r, g, b);
+    material.emissive_texture = texture_ref(at.get("emissiveTexture"), textures)?;
+    if let Some(mode_at) = at.get("alphaMode") {
+        material.alpha_mode = match mode_at.str()? {
+            "OPAQUE" => AlphaMode::Opaque,
+            "MASK" => AlphaMode::Mask,
+            "BLEND" => AlphaMode::Blend,
+            other => return Err(mode_at.invalid(format!("unknown alpha mode {}", other)))
+        };
+    }
+    material.alpha_cutoff = at.f32_or("alphaCutoff", 0.5)?;
+    material.double_sided = at.bool_or("doubleSided", false)?;
+    Ok(material)
+}
+
+fn sampler(at: &At) -> Result<GltfSampler, GltfError> {
+    let code = |key: &str| -> Result<Option<(At, usize)>, GltfError> {
+        at.get(key).map(|code_at| code_at.usize().map(|code| (code_at, code))).transpose()
+    };
+    let mag_filter = match code("magFilter")? {
+        None => None,
+        Some((_, 9728)) => Some(MagFilter::Nearest),
+        Some((_, 9729)) => Some(MagFilter::Linear),
+        Some((code_at, _)) => return Err(code_at.invalid("unknown magnification filter"))
+    };
+    let min_filter = match code("minFilter")? {
+        None => None,
+        Some((_, 9728)) => Some(MinFilter::Nearest),
+        Some((_, 9729)) => Some(MinFilter::Linear),
+        Some((_, 9984)) => Some(MinFilter::NearestMipmapNearest),
+        Some((_, 9985)) => Some(MinFilter::LinearMipmapNearest),
+        Some((_, 9986)) => Some(MinFilter::NearestMipmapLinear),
+        Some((_, 9987)) => Some(MinFilter::LinearMipmapLinear),
+        Some((code_at, _)) => return Err(code_at.invalid("unknown minification filter"))
+    };
+    let wrap = |key: &str| -> Result<WrapMode, GltfError> {
+        match code(key)? {
+            None | Some((_, 10497)) => Ok(WrapMode::Repeat),
+            Some((_, 33071)) => Ok(WrapMode::ClampToEdge),
+            Some((_, 33648)) => Ok(WrapMode::MirroredRepeat),
+            Some((code_at, _)) => Err(code_at.invalid("unknown wrap mode"))
+        }
+    };
+    Ok(GltfSampler { name: at.name()?, mag_filter, min_filter, wrap_s: wrap("wrapS")?, wrap_t: wrap("wrapT")? })
+}
+
+fn camera(at: &At) -> Result<GltfCamera, GltfError> {
+    let kind_at = at.required("type")?;
+    let projection = match kind_at.str()? {
+        "perspective" => {
+            let p = at.required("perspective")?;
+            let znear = p.positive("znear")?;
+            let zfar = p.get("zfar").map(|_| p.positive("zfar")).transpose()?;
+            if zfar.is_some_and(|zfar| zfar <= znear) {
+                return Err(p.invalid("zfar must be greater than znear"));
+            }
+            let aspect_ratio = p.get("aspectRatio").map(|_| p.positive("aspectRatio")).transpose()?;
+            Projection::Perspective { yfov: p.positive("yfov")?, aspect_ratio, znear, zfar }
+        }
+        "orthographic" => {
+            let o = at.required("orthographic")?;
+            let (znear, zfar) = (o.required("znear")?.f32()?, o.positive("zfar")?);
+            if znear < 0.0 || zfar <= znear {
+                return Err(o.invalid("znear must be at least zero and less than zfar"));
+            }
+            Projection::Orthographic { xmag: o.required("xmag")?.f32()?, ymag: o.required("ymag")?.f32()?, znear, zfar }
+        }
+        other => return Err(kind_at.invalid(format!("unknown camera type {}", other)))
+    };
+    Ok(GltfCamera { name: at.name()?, projection })
+}
+
+fn light(at: &At) -> Result<GltfLight, GltfError> {
+    let kind_at = at.required("type")?;
+    let kind = match kind_at.str()? {
+        "directional" => LightKind::Directional,
+        "point" => LightKind::Point,
+        "spot" => {
+            let spot = at.required("spot")?;
+            let inner_cone_angle = spot.f32_or("innerConeAngle", 0.0)?;
+            let outer_cone_angle = spot.f32_or("outerConeAngle", std::f32::consts::FRAC_PI_4)?;
+            if inner_cone_angle < 0.0 || outer_cone_angle <= inner_cone_angle || outer_cone_angle > std::f32::consts::FRAC_PI_2 {
+                return Err(spot.invalid("cone angles must satisfy 0 <= inner < outer <= pi/2"));
+            }
+            LightKind::Spot { inner_cone_angle, outer_cone_angle }
+        }
+        other => return Err(kind_at.invalid(format!("unknown light type {}", other)))
+    };
+    let [r, g, b] = at.floats_or("color", [1.0; 3])?;
+    let range = at.get("range").map(|_| at.positive("range")).transpose()?;
+    Ok(GltfLight { name: at.name()?, kind, color: glm::vec3(r, g, b), intensity: at.f32_or("intensity", 1.0)?, range })
+}
+
+fn node(at: &At, nodes: usize, gltf: &Gltf) -> Result<GltfNode, GltfError> {
+    let matrix = match at.get("matrix") {
+        Some(_) => Some(matrix_from(&at.floats_or::<16>("matrix", [0.0; 16])?)),
+        None => None
+    };
+    let [tx, ty, tz] = at.floats_or("translation", [0.0; 3])?;
+    let [rx, ry, rz, rw] = at.floats_or("rotation", [0.0, 0.0, 0.0, 1.0])?;
+    let [sx, sy, sz] = at.floats_or("scale", [1.0; 3])?;
+    let light = match at.get("extensions").and_then(|e| e.get("KHR_lights_punctual")) {
+        Some(extension) => Some(extension.required_index("light", gltf.lights.len(), "Light")?),
+        None => None
+    };
+    let node = GltfNode {
+        name: at.name()?,
+        children: at.indices("children", nodes, "Node")?,
+        mesh: at.index_of("mesh", gltf.meshes.len(), "Mesh")?,
+        camera: at.index_of("camera", gltf.cameras.len(), "Camera")?,
+        light,
+        skin: at.index_of("skin", gltf.skins.len(), "Skin")?,
+        translation: glm::vec3(tx, ty, tz),
+        rotation: glm::vec4(rx, ry, rz, rw),
+        scale: glm::vec3(sx, sy, sz),
+        matrix,
+        weights: at.floats("weights")?
+    };
+    if node.skin.is_some() && node.mesh.is_none() {
+        return Err(at.invalid("a node with a skin needs a mesh"));
+    }
+    Ok(node)
+}
+
+/// Checks that the nodes form trees: no node has two parents or is its own ancestor.
+fn check_hierarchy(nodes: &[GltfNode], ats: &[At]) -> Result<(), GltfError> {
+    let mut parents = vec!(None; nodes.len());
+    for (i, node) in nodes.iter().enumerate() {
+        for &child in &node.children {
+            if let Some(parent) = parents[child] {
+                return Err(ats[i].invalid(format!("node {} is already a child of node {}", child, parent)));
+            }
+            parents[child] = Some(i);
+        }
+    }
+    for start in 0..nodes.len() {
+        let mut current = parents[start];
+        for _ in 0..nodes.len() {
+            match current {
+                Some(parent) if parent == start => return Err(ats[start].invalid("node is its own ancestor")),
+                Some(parent) => current = parents[parent],
+                None => break
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A file with one point cloud whose positions are `accessor`, over a 24 byte buffer.
+    fn document(view: &str, accessor: &str) -> String {
+        format!(r#"{{
+            "asset": {{ "version": "2.0" }},
+            "buffers": [{{ "byteLength": 24, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA" }}],
+            "bufferViews": [{}],
+            "accessors": [{}],
+            "meshes": [{{ "primitives": [{{ "attributes": {{ "POSITION": 0 }}, "mode": 0 }}] }}]
+        }}"#, view, accessor)
+    }
+
+    fn load_document(view: &str, accessor: &str) -> Result<Gltf, GltfError> {
+        parse(document(view, accessor).as_bytes(), Path::new(""))
+    }
+
+    fn invalid_at(result: Result<Gltf, GltfError>) -> String {
+        match result {
+            Err(GltfError::Invalid { pointer, .. }) => pointer,
+            Err(e) => panic!("expected an invalid value, got {}", e),
+            Ok(_) => panic!("expected an invalid value, but the file loaded")
+        }
+    }
+
+    const VIEW: &str = r#"{ "buffer": 0, "byteLength": 24 }"#;
+
+    #[test]
+    fn loads_positions() {
+        let gltf = load_document(VIEW, r#"{ "bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3" }"#).unwrap();
+        assert_eq!(gltf.meshes[0].primitives[0].mesh.positions, vec!(glm::vec3(0.0, 0.0, 0.0); 2));
+    }
+
+    #[test]
+    fn rejects_views_past_the_buffer() {
+        let accessor = r#"{ "bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3" }"#;
+        assert_eq!(invalid_at(load_document(r#"{ "buffer": 0, "byteLength": 25 }"#, accessor)), "/bufferViews/0");
+        assert_eq!(invalid_at(load_document(r#"{ "buffer": 0, "byteOffset": 18446744073709551615, "byteLength": 1 }"#, accessor)), "/bufferViews/0");
+    }
+
+    #[test]
+    fn rejects_accessors_past_the_view() {
+        for accessor in [
+            r#"{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" }"#,
+            r#"{ "bufferView": 0, "byteOffset": 18446744073709551615, "componentType": 5126, "count": 1, "type": "VEC3" }"#,
+            r#"{ "bufferView": 0, "componentType": 5126, "count": 9223372036854775807, "type": "VEC3" }"#,
+            r#"{ "bufferView": 0, "componentType": 5126, "count": 18446744073709551615, "type": "VEC3" }"#
+        ] {
+            assert_eq!(invalid_at(load_document(VIEW, accessor)), "/accessors/0");
+        }
+    }
+
+    #[test]
+    fn limits_accessors_without_a_view() {
+        let gltf = load_document(VIEW, r#"{ "componentType": 5126, "count": 4, "type": "VEC3" }"#).unwrap();
+        assert_eq!(gltf.meshes[0].primitives[0].mesh.positions.len(), 4);
+        for count in ["9223372036854775807", "18446744073709551615", "100000000"] {
+            let accessor = format!(r#"{{ "componentType": 5126, "count": {}, "type": "VEC3" }}"#, count);
+            assert_eq!(invalid_at(load_document(VIEW, &accessor)), "/accessors/0/count");
+        }
+    }
+
+    #[test]
+    fn rejects_sparse_data_past_the_view() {
+        let sparse = |offset: &str| format!(r#"{{ "componentType": 5126, "count": 2, "type": "VEC3", "sparse": {{ "count": 1,
+            "indices": {{ "bufferView": 0, "byteOffset": {}, "componentType": 5125 }}, "values": {{ "bufferView": 0 }} }} }}"#, offset);
+        assert!(load_document(VIEW, &sparse("0")).is_ok());
+        assert_eq!(invalid_at(load_document(VIEW, &sparse("18446744073709551615"))), "/accessors/0/sparse/indices");
+    }
+
+    fn base64(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut text = String::new();
+        for chunk in bytes.chunks(3) {
+            let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, &b)| bits | (b as u32) << (16 - 8 * i));
+            for i in 0..4 {
+                text.push(if i <= chunk.len() { ALPHABET[(bits >> (18 - 6 * i) & 63) as usize] as char } else { '=' });
+            }
+        }
+        text
+    }
+
+    /// Builds a document whose accessors each get their own view of one embedded buffer.
+    #[derive(Default)]
+    struct Builder {
+        bytes: Vec<u8>,
+        views: Vec<String>,
+        accessors: Vec<String>
+    }
+
+    impl Builder {
+        fn accessor(&mut self, kind: &str, component_type: u32, bytes: Vec<u8>, count: usize) -> usize {
+            while !self.bytes.len().is_multiple_of(4) {
+                self.bytes.push(0);
+            }
+            self.views.push(format!(r#"{{ "buffer": 0, "byteOffset": {}, "byteLength": {} }}"#, self.bytes.len(), bytes.len()));
+            self.accessors.push(format!(r#"{{ "bufferView": {}, "componentType": {}, "count": {}, "type": "{}" }}"#,
+                                        self.views.len() - 1, component_type, count, kind));
+            self.bytes.extend(bytes);
+            self.accessors.len() - 1
+        }
+
+        fn floats(&mut self, kind: &str, values: &[f32]) -> usize {
+            let components = match kind {
+                "SCALAR" => 1,
+                "VEC2" => 2,
+                "VEC3" => 3,
+                "VEC4" => 4,
+                _ => 16
+            };
+            self.accessor(kind, 5126, values.iter().flat_map(|v| v.to_le_bytes()).collect(), values.len() / components)
+        }
+
+        fn indices(&mut self, values: &[u16]) -> usize {
+            self.accessor("SCALAR", 5123, values.iter().flat_map(|v| v.to_le_bytes()).collect(), values.len())
+        }
+
+        /// The document with `rest`, more top-level properties, after the buffer data.
+        fn json(&self, rest: &str) -> String {
+            format!(r#"{{ "asset": {{ "version": "2.0" }},
+                "buffers": [{{ "byteLength": {}, "uri": "data:application/octet-stream;base64,{}" }}],
+                "bufferViews": [{}], "accessors": [{}]{}{} }}"#,
+                    self.bytes.len(), base64(&self.bytes), self.views.join(", "), self.accessors.join(", "),
+                    if rest.is_empty() { "" } else { ", " }, rest)
+        }
+
+        fn load(&self, rest: &str) -> Result<Gltf, GltfError> {
+            parse(self.json(rest).as_bytes(), Path::new(""))
+        }
+    }
+
+    fn glb(chunks: &[(u32, &[u8])]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for &(kind, chunk) in chunks {
+            let padded = chunk.len().div_ceil(4) * 4;
+            data.extend_from_slice(&(padded as u32).to_le_bytes());
+            data.extend_from_slice(&kind.to_le_bytes());
+            data.extend_from_slice(chunk);
+            data.resize(data.len() + padded - chunk.len(), if kind == GLB_JSON { b' ' } else { 0 });
+        }
+        let mut header = Vec::new();
+        header.extend_from_slice(&GLB_MAGIC.to_le_bytes());
+        header.extend_from_slice(&2u32.to_le_bytes());
+        header.extend_from_slice(&(data.len() as u32 + 12).to_le_bytes());
+        header.extend(data);
+        header
+    }
+
+    fn glb_error(data: &[u8]) -> String {
+        match parse(data, Path::new("")) {
+            Err(GltfError::Glb(message)) => message,
+            Err(e) => panic!("expected a GLB error, got {}", e),
+            Ok(_) => panic!("expected a GLB error, but the file loaded")
+        }
+    }
+
+    const TRIANGLE: [f32; 9] = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+
+    #[test]
+    fn glb_binary_chunk_backs_the_first_buffer() {
+        let bin: Vec<u8> = TRIANGLE.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let json = r#"{ "asset": { "version": "2.0" }, "buffers": [{ "byteLength": 36 }],
+            "bufferViews": [{ "buffer": 0, "byteLength": 36 }],
+            "accessors": [{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" }],
+            "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }] }"#;
+        let gltf = parse(&glb(&[(GLB_JSON, json.as_bytes()), (GLB_BIN, &bin)]), Path::new("")).unwrap();
+        assert_eq!(gltf.meshes[0].primitives[0].mesh.positions[1], glm::vec3(1.0, 0.0, 0.0));
+
+        // Without the binary chunk the buffer has no data.
+        let data = glb(&[(GLB_JSON, json.as_bytes())]);
+        assert_eq!(invalid_at(parse(&data, Path::new(""))), "/buffers/0");
+    }
+
+    #[test]
+    fn rejects_malformed_glb_containers() {
+        let json: &[u8] = br#"{ "asset": { "version": "2.0" } }"#;
+        let valid = glb(&[(GLB_JSON, json)]);
+        assert!(parse(&valid, Path::new("")).is_ok());
+
+        let mut version = valid.clone();
+        version[4] = 1;
+        assert!(glb_error(&version).contains("version 1"));
+        let mut claimed = valid.clone();
+        claimed[8..12].copy_from_slice(&(valid.len() as u32 + 4).to_le_bytes());
+        assert!(glb_error(&claimed).contains("header claims"));
+        assert!(glb_error(&valid[..10]).contains("truncated"));
+        let mut chunk = valid.clone();
+        chunk[12..16].copy_from_slice(&1000u32.to_le_bytes());
+        assert!(glb_error(&chunk).contains("runs past the end"));
+        let mut header = valid[..12].to_vec();
+        header[8..12].copy_from_slice(&16u32.to_le_bytes());
+        header.extend_from_slice(&[0; 4]);
+        assert!(glb_error(&header).contains("chunk header"));
+        assert!(glb_error(&glb(&[(GLB_BIN, json)])).contains("not JSON"));
+
+        // Without the magic it's read as JSON.
+        let mut magic = valid;
+        magic[0] = b'x';
+        assert!(matches!(parse(&magic, Path::new("")), Err(GltfError::Json(_))));
+    }
+
+    #[test]
+    fn decodes_data_uris() {
+        assert_eq!(decode_data_uri("data:application/octet-stream;base64,AQID"),
+                   Some((vec!(1, 2, 3), Some("application/octet-stream".to_string()))));
+        assert_eq!(decode_data_uri("data:;base64,AQI="), Some((vec!(1, 2), None)));
+        assert_eq!(decode_data_uri("data:;base64,-_8"), Some((vec!(0xfb, 0xff), None)));
+        assert_eq!(decode_data_uri("data:text/plain,hello"), None);
+        assert_eq!(decode_data_uri("data:;base64,AQ*D"), None);
+        assert_eq!(decode_data_uri("file.bin"), None);
+        let bytes: Vec<u8> = (0..=255).collect();
+        for len in 0..8 {
+            assert_eq!(decode_base64(&base64(&bytes[..250 + len.min(6)])).unwrap(), &bytes[..250 + len.min(6)]);
+        }
+        assert_eq!(decode_percent("my%20buf.bin"), "my buf.bin");
+        assert_eq!(decode_percent("100%zz%4"), "100%zz%4");
+    }
+
+    #[test]
+    fn strips_and_fans_become_lists() {
+        assert_eq!(triangle_strip(&[0, 1, 2, 3, 4]), vec!(0, 1, 2, 1, 3, 2, 2, 3, 4));
+        assert_eq!(triangle_fan(&[0, 1, 2, 3]), vec!(1, 2, 0, 2, 3, 0));
+        assert!(triangle_fan(&[0, 1]).is_empty() && triangle_strip(&[0, 1]).is_empty());
+        assert_eq!(line_strip(&[0, 1, 2], false), vec!(0, 1, 1, 2));
+        assert_eq!(line_strip(&[0, 1, 2], true), vec!(0, 1, 1, 2, 2, 0));
+        assert_eq!(line_strip(&[0, 1], true), vec!(0, 1));
+
+        // Every triangle of a counter-clockwise strip and fan keeps facing +Z.
+        let mut builder = Builder::default();
+        builder.floats("VEC3", &[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0]);
+        builder.indices(&[0, 1, 3, 2]);
+        let gltf = builder.load(r#""meshes": [{ "primitives": [
+            { "attributes": { "POSITION": 0 }, "mode": 5 },
+            { "attributes": { "POSITION": 0 }, "indices": 1, "mode": 6 },
+            { "attributes": { "POSITION": 0 }, "mode": 2 }] }]"#).unwrap();
+        let [strip, fan, lines] = [0, 1, 2].map(|i| &gltf.meshes[0].primitives[i]);
+        for primitive in [strip, fan] {
+            assert_eq!((primitive.mode, primitive.mesh.triangle_count()), (DrawMode::Triangles, 2));
+            assert!(primitive.mesh.normals.iter().all(|&n| n == glm::vec3(0.0, 0.0, 1.0)));
+        }
+        assert_eq!((lines.mode, lines.mesh.indices.clone()), (DrawMode::Lines, vec!(0, 1, 1, 2, 2, 3, 3, 0)));
+        assert_eq!(strip.mesh.submeshes, vec!(Submesh::of(0, 6, 0)));
+    }
+
+    #[test]
+    fn uvs_are_flipped_with_their_tangents() {
+        let mut builder = Builder::default();
+        builder.floats("VEC3", &TRIANGLE);
+        builder.floats("VEC3", &[0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+        builder.floats("VEC2", &[0.0, 0.25, 1.0, 0.25, 0.0, 1.0]);
+        builder.floats("VEC4", &[1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, -1.0]);
+        let gltf = builder.load(r#""meshes": [{ "primitives": [
+            { "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2, "TANGENT": 3 } },
+            { "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2 } }] }]"#).unwrap();
+        let given = &gltf.meshes[0].primitives[0].mesh;
+        assert_eq!(given.uvs, vec!(glm::vec2(0.0, 0.75), glm::vec2(1.0, 0.75), glm::vec2(0.0, 0.0)));
+        assert_eq!(given.tangents.iter().map(|t| t.w).collect::<Vec<_>>(), vec!(-1.0, -1.0, 1.0));
+        // Computed tangents agree with the flipped given ones.
+        let computed = &gltf.meshes[0].primitives[1].mesh;
+        assert!(computed.tangents.iter().all(|&t| t == glm::vec4(1.0, 0.0, 0.0, -1.0)), "{:?}", computed.tangents);
+    }
+
+    #[test]
+    fn flat_normals_unweld_shared_vertices() {
+        let mut builder = Builder::default();
+        builder.floats("VEC3", &[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        builder.floats("VEC4", &[0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0]);
+        builder.indices(&[0, 1, 2, 0, 2, 3]);
+        let gltf = builder.load(r#""meshes": [{ "primitives": [{ "attributes": { "POSITION": 0, "COLOR_0": 1 }, "indices": 2 }] }]"#).unwrap();
+        let primitive = &gltf.meshes[0].primitives[0];
+        let mesh = &primitive.mesh;
+        assert_eq!((mesh.vertex_count(), mesh.indices.clone()), (6, vec!(0, 1, 2, 3, 4, 5)));
+        assert_eq!(mesh.positions[3], mesh.positions[0]);
+        assert_eq!(primitive.colors.len(), 6);
+        assert_eq!(primitive.colors[4], glm::vec4(0.0, 1.0, 0.0, 1.0));
+        assert!(mesh.normals[..3].iter().all(|&n| n == glm::vec3(0.0, 0.0, 1.0)));
+        let diagonal = glm::normalize(glm::vec3(1.0, -1.0, 0.0));
+        assert!(mesh.normals[3..].iter().all(|&n| glm::length(n - diagonal) < 1e-6));
+    }
+
+    #[test]
+    fn materials_default_and_parse() {
+        let builder = Builder::default();
+        let gltf = builder.load(r#""textures": [{}, {}], "materials": [{}, {
+            "name": "painted",
+            "pbrMetallicRoughness": { "baseColorFactor": [1, 0, 0, 0.5], "baseColorTexture": { "index": 1, "texCoord": 1 },
+                                      "metallicFactor": 0, "roughnessFactor": 0.25 },
+            "normalTexture": { "index": 0, "scale": 2 },
+            "occlusionTexture": { "index": 0, "strength": 0.5 },
+            "emissiveFactor": [1, 1, 0],
+            "alphaMode": "MASK", "alphaCutoff": 0.25, "doubleSided": true }]"#).unwrap();
+        assert_eq!(gltf.materials[0], GltfMaterial::default());
+        let m = &gltf.materials[1];
+        assert_eq!(m.name.as_deref(), Some("painted"));
+        assert_eq!((m.base_color, m.metallic, m.roughness), (glm::vec4(1.0, 0.0, 0.0, 0.5), 0.0, 0.25));
+        assert_eq!(m.base_color_texture, Some(TextureRef { texture: 1, tex_coord: 1 }));
+        assert_eq!((m.normal_texture.map(|t| t.texture), m.normal_scale, m.occlusion_strength), (Some(0), 2.0, 0.5));
+        assert_eq!((m.emissive, m.alpha_mode, m.alpha_cutoff, m.double_sided), (glm::vec3(1.0, 1.0, 0.0), AlphaMode::Mask, 0.25, true));
+
+        assert_eq!(invalid_at(builder.load(r#""materials": [{ "alphaMode": "GLASS" }]"#)), "/materials/0/alphaMode");
+        assert_eq!(invalid_at(builder.load(r#""materials": [{ "emissiveTexture": { "index": 0 } }]"#)), "/materials/0/emissiveTexture/index");
+        assert_eq!(invalid_at(builder.load(r#""materials": [{ "emissiveFactor": [1, 1] }]"#)), "/materials/0/emissiveFactor");
+    }
+
+    #[test]
+    fn cameras_and_lights() {
+        let builder = Builder::default();
+        let gltf = builder.load(r#""cameras": [
+                { "type": "perspective", "perspective": { "yfov": 1, "znear": 0.1 } },
+                { "type": "orthographic", "orthographic": { "xmag": 2, "ymag": 1, "znear": 0, "zfar": 10 } }],
+            "extensions": { "KHR_lights_punctual": { "lights": [
+                { "type": "spot", "spot": {} },
+                { "type": "point", "color": [1, 0, 0], "intensity": 20, "range": 5 }] } },
+            "nodes": [{ "camera": 1, "extensions": { "KHR_lights_punctual": { "light": 1 } } }]"#).unwrap();
+        assert_eq!(gltf.cameras[0].projection, Projection::Perspective { yfov: 1.0, aspect_ratio: None, znear: 0.1, zfar: None });
+        assert_eq!(gltf.cameras[1].projection, Projection::Orthographic { xmag: 2.0, ymag: 1.0, znear: 0.0, zfar: 10.0 });
+        assert_eq!(gltf.lights[0].kind, LightKind::Spot { inner_cone_angle: 0.0, outer_cone_angle: std::f32::consts::FRAC_PI_4 });
+        assert_eq!((gltf.lights[0].intensity, gltf.lights[0].range), (1.0, None));
+        assert_eq!((gltf.lights[1].kind, gltf.lights[1].color, gltf.lights[1].range), (LightKind::Point, glm::vec3(1.0, 0.0, 0.0), Some(5.0)));
+        assert_eq!((gltf.nodes[0].camera, gltf.nodes[0].light), (Some(1), Some(1)));
+
+        let camera = |json: &str| invalid_at(builder.load(&format!(r#""cameras": [{}]"#, json)));
+        assert_eq!(camera(r#"{ "type": "perspective", "perspective": { "yfov": 1, "znear": 1, "zfar": 0.5 } }"#), "/cameras/0/perspective");
+        assert_eq!(camera(r#"{ "type": "perspective", "perspective": { "yfov": 0, "znear": 1 } }"#), "/cameras/0/perspective/yfov");
+        assert_eq!(camera(r#"{ "type": "orthographic", "orthographic": { "xmag": 1, "ymag": 1, "znear": -1, "zfar": 1 } }"#), "/cameras/0/orthographic");
+        assert_eq!(camera(r#"{ "type": "fisheye" }"#), "/cameras/0/type");
+        let light = |json: &str| invalid_at(builder.load(&format!(r#""extensions": {{ "KHR_lights_punctual": {{ "lights": [{}] }} }}"#, json)));
+        assert_eq!(light(r#"{ "type": "spot", "spot": { "innerConeAngle": 1, "outerConeAngle": 0.5 } }"#), "/extensions/KHR_lights_punctual/lights/0/spot");
+        assert_eq!(light(r#"{ "type": "area" }"#), "/extensions/KHR_lights_punctual/lights/0/type");
+        assert_eq!(invalid_at(builder.load(r#""nodes": [{ "extensions": { "KHR_lights_punctual": { "light": 0 } } }]"#)),
+                   "/nodes/0/extensions/KHR_lights_punctual/light");
+    }
+
+    #[test]
+    fn skins_read_inverse_bind_matrices() {
+        let mut builder = Builder::default();
+        let mut matrices = [0.0; 32];
+        for i in 0..4 {
+            matrices[i * 5] = 1.0;
+            matrices[16 + i * 5] = 2.0;
+        }
+        matrices[28] = 3.0;
+        builder.floats("MAT4", &matrices);
+        let gltf = builder.load(r#""nodes": [{}, {}], "skins": [
+            { "joints": [0, 1], "inverseBindMatrices": 0, "skeleton": 0 }, { "joints": [1] }]"#).unwrap();
+        let skin = &gltf.skins[0];
+        assert_eq!((skin.joints.clone(), skin.skeleton), (vec!(0, 1), Some(0)));
+        assert_eq!(skin.inverse_bind_matrices[0], identity());
+        assert_eq!(skin.inverse_bind_matrices[1].c0, glm::vec4(2.0, 0.0, 0.0, 0.0));
+        assert_eq!(skin.inverse_bind_matrices[1].c3, glm::vec4(3.0, 0.0, 0.0, 2.0));
+        assert_eq!(gltf.skins[1].inverse_bind_matrices, vec!(identity()));
+
+        assert_eq!(invalid_at(builder.load(r#""nodes": [{}, {}, {}], "skins": [{ "joints": [0, 1, 2], "inverseBindMatrices": 0 }]"#)),
+                   "/skins/0/inverseBindMatrices");
+        assert_eq!(invalid_at(builder.load(r#""nodes": [{}], "skins": [{ "joints": [] }]"#)), "/skins/0");
+        assert_eq!(invalid_at(builder.load(r#""nodes": [{ "skin": 0 }], "skins": [{ "joints": [0] }]"#)), "/nodes/0");
+    }
+
+    #[test]
+    fn animations_are_validated() {
+        let mut builder = Builder::default();
+        builder.floats("SCALAR", &[0.0, 1.0, 2.0]);
+        builder.floats("SCALAR", &[0.0, 1.0, 1.0]);
+        builder.floats("VEC3", &[0.0; 9]);
+        builder.floats("VEC3", &[0.0; 27]);
+        builder.floats("SCALAR", &[0.0; 6]);
+        let animation = |samplers: &str, path: &str| builder.load(&format!(r#""nodes": [{{}}], "animations": [{{
+            "samplers": [{}], "channels": [{{ "sampler": 0, "target": {{ "node": 0, "path": "{}" }} }}] }}]"#, samplers, path));
+
+        let gltf = animation(r#"{ "input": 0, "output": 2 }"#, "translation").unwrap();
+        let sampler = &gltf.animations[0].samplers[0];
+        assert_eq!((sampler.interpolation, sampler.times.clone(), sampler.values.len()), (Interpolation::Linear, vec!(0.0, 1.0, 2.0), 9));
+        assert_eq!(gltf.animations[0].channels[0], GltfChannel { sampler: 0, node: Some(0), path: AnimationPath::Translation });
+        assert!(animation(r#"{ "input": 0, "output": 3, "interpolation": "CUBICSPLINE" }"#, "scale").is_ok());
+        // Two morph target weights per keyframe.
+        assert!(animation(r#"{ "input": 0, "output": 4 }"#, "weights").is_ok());
+
+        assert_eq!(invalid_at(animation(r#"{ "input": 1, "output": 2 }"#, "translation")), "/animations/0/samplers/0/input");
+        assert_eq!(invalid_at(animation(r#"{ "input": 0, "output": 2, "interpolation": "CUBICSPLINE" }"#, "translation")),
+                   "/animations/0/samplers/0/output");
+        assert_eq!(invalid_at(animation(r#"{ "input": 0, "output": 2, "interpolation": "SMOOTH" }"#, "translation")),
+                   "/animations/0/samplers/0/interpolation");
+        assert_eq!(invalid_at(animation(r#"{ "input": 0, "output": 2 }"#, "rotation")), "/animations/0/channels/0");
+        assert_eq!(invalid_at(animation(r#"{ "input": 0, "output": 4 }"#, "scale")), "/animations/0/channels/0");
+        assert_eq!(invalid_at(animation(r#"{ "input": 0, "output": 2 }"#, "color")), "/animations/0/channels/0/target/path");
+    }
+
+    #[test]
+    fn nodes_must_form_trees() {
+        let builder = Builder::default();
+        assert_eq!(invalid_at(builder.load(r#""nodes": [{ "children": [2] }, { "children": [2] }, {}]"#)), "/nodes/1");
+        assert_eq!(invalid_at(builder.load(r#""nodes": [{ "children": [1] }, { "children": [0] }]"#)), "/nodes/0");
+        assert_eq!(invalid_at(builder.load(r#""nodes": [{ "children": [0] }]"#)), "/nodes/0");
+        assert_eq!(invalid_at(builder.load(r#""nodes": [{ "children": [1] }, {}], "scenes": [{ "nodes": [1] }]"#)), "/scenes/0");
+        let gltf = builder.load(r#""nodes": [{ "children": [1] }, {}, {}], "scenes": [{ "nodes": [0, 2] }], "scene": 0"#).unwrap();
+        assert_eq!((gltf.roots(), gltf.scene), (vec!(0, 2), Some(0)));
+    }
+
+    #[test]
+    fn world_transforms_compose_down_the_tree() {
+        let builder = Builder::default();
+        let gltf = builder.load(r#""nodes": [
+            { "children": [1], "translation": [1, 0, 0], "scale": [2, 2, 2] },
+            { "children": [2], "translation": [0, 1, 0], "rotation": [0, 0, 0.70710677, 0.70710677] },
+            { "translation": [1, 0, 0] },
+            { "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 5, 6, 7, 1] }]"#).unwrap();
+        let world = gltf.world_transforms();
+        assert_eq!(world[0].c3, glm::vec4(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(world[1].c3, glm::vec4(1.0, 2.0, 0.0, 1.0));
+        // The child's +X is turned to +Y by its parent, then scaled by the root.
+        let p = world[2].c3;
+        assert!(glm::length(glm::vec3(p.x, p.y, p.z) - glm::vec3(1.0, 4.0, 0.0)) < 1e-5, "{:?}", p);
+        assert_eq!(world[3].c3, glm::vec4(5.0, 6.0, 7.0, 1.0));
+    }
+}
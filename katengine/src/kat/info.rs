@@ -0,0 +1,246 @@
+//! What the current context supports: version, extensions, limits and engine features.
+
+use std::collections::HashSet;
+use std::ffi::CStr;
+use std::fmt;
+use crate::gl;
+use crate::gl::types::*;
+
+#[derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug,Default)]
+pub struct GlVersion {
+    pub major: u32,
+    pub minor: u32
+}
+
+impl GlVersion {
+    pub const fn of(major: u32, minor: u32) -> GlVersion {
+        GlVersion { major, minor }
+    }
+}
+
+impl fmt::Display for GlVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Implementation limits relevant to the engine. Compute limits are zero when the
+/// context has no compute shaders.
+#[derive(Clone,Debug,Default,PartialEq,Eq)]
+pub struct GlLimits {
+    pub max_texture_size: i32,
+    pub max_texture_image_units: i32,
+    pub max_combined_texture_image_units: i32,
+    pub max_uniform_block_size: i32,
+    pub max_uniform_buffer_bindings: i32,
+    pub max_shader_storage_buffer_bindings: i32,
+    pub max_vertex_attribs: i32,
+    pub max_compute_work_group_count: [i32; 3],
+    pub max_compute_work_group_size: [i32; 3],
+    pub max_compute_work_group_invocations: i32
+}
+
+/// Optional functionality the engine relies on for some of its features.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum GlFeature {
+    DirectStateAccess,
+    ComputeShaders,
+    ShaderStorageBuffers,
+    DebugOutput,
+    QueryBufferObjects,
+    TimerQueries,
+    TransformFeedbackObjects
+}
+
+impl fmt::Display for GlFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GlFeature::DirectStateAccess => "direct state access (GL 4.5 or ARB_direct_state_access)",
+            GlFeature::ComputeShaders => "compute shaders (GL 4.3 or ARB_compute_shader)",
+            GlFeature::ShaderStorageBuffers => "shader storage buffers (GL 4.3 or ARB_shader_storage_buffer_object)",
+            GlFeature::DebugOutput => "debug output (GL 4.3 or KHR_debug)",
+            GlFeature::QueryBufferObjects => "query buffer objects (GL 4.4 or ARB_query_buffer_object)",
+            GlFeature::TimerQueries => "timer queries (GL 3.3 or ARB_timer_query)",
+            GlFeature::TransformFeedbackObjects => "transform feedback objects (GL 4.0 or ARB_transform_feedback2)"
+        })
+    }
+}
+
+/// Returned by `GlInfo::require` when the context lacks features.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct UnsupportedContext {
+    pub version: GlVersion,
+    pub renderer: String,
+    /// Set when the context is older than the version that was required.
+    pub required_version: Option<GlVersion>,
+    pub missing: Vec<GlFeature>
+}
+
+impl fmt::Display for UnsupportedContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(required) = self.required_version {
+            return write!(f, "The OpenGL {} context on {} is older than the required version {}.", self.version, self.renderer, required);
+        }
+
+        write!(f, "The OpenGL {} context on {} is missing required features:", self.version, self.renderer)?;
+        for feature in &self.missing {
+            write!(f, "\n  - {}", feature)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnsupportedContext {}
+
+/// What the current context supports, queried once after the function pointers are loaded.
+#[derive(Clone,Debug,Default)]
+pub struct GlInfo {
+    pub version: GlVersion,
+    /// Whether this is an OpenGL ES context; `version` is then the ES version.
+    pub es: bool,
+    pub vendor: String,
+    pub renderer: String,
+    pub version_string: String,
+    pub glsl_version: String,
+    pub extensions: HashSet<String>,
+    pub limits: GlLimits
+}
+
+fn get_string(name: GLenum) -> String {
+    unsafe {
+        let ptr = gl::GetString(name);
+        if ptr.is_null() {
+            return String::new();
+        }
+        CStr::from_ptr(ptr as *const GLchar).to_string_lossy().into_owned()
+    }
+}
+
+fn get_integer(name: GLenum) -> i32 {
+    let mut value: GLint = 0;
+    unsafe { gl::GetIntegerv(name, &mut value); }
+    value
+}
+
+fn get_indexed_integers(name: GLenum) -> [i32; 3] {
+    let mut values: [GLint; 3] = [0; 3];
+    for (i, v) in values.iter_mut().enumerate() {
+        unsafe { gl::GetIntegeri_v(name, i as GLuint, v); }
+    }
+    values
+}
+
+impl GlInfo {
+    /// Queries the context that is current on this thread.
+    pub fn query() -> GlInfo {
+        let version = GlVersion::of(get_integer(gl::MAJOR_VERSION) as u32, get_integer(gl::MINOR_VERSION) as u32);
+
+        let extensions = (0..get_integer(gl::NUM_EXTENSIONS).max(0) as GLuint)
+            .filter_map(|i| unsafe {
+                let ptr = gl::GetStringi(gl::EXTENSIONS, i);
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(CStr::from_ptr(ptr as *const GLchar).to_string_lossy().into_owned())
+                }
+            })
+            .collect();
+
+        let version_string = get_string(gl::VERSION);
+
+        let mut info = GlInfo {
+            version,
+            es: version_string.starts_with("OpenGL ES"),
+            vendor: get_string(gl::VENDOR),
+            renderer: get_string(gl::RENDERER),
+            version_string,
+            glsl_version: get_string(gl::SHADING_LANGUAGE_VERSION),
+            extensions,
+            limits: GlLimits::default()
+        };
+
+        info.limits = GlLimits {
+            max_texture_size: get_integer(gl::MAX_TEXTURE_SIZE),
+            max_texture_image_units: get_integer(gl::MAX_TEXTURE_IMAGE_UNITS),
+            max_combined_texture_image_units: get_integer(gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS),
+            max_uniform_block_size: get_integer(gl::MAX_UNIFORM_BLOCK_SIZE),
+            max_uniform_buffer_bindings: get_integer(gl::MAX_UNIFORM_BUFFER_BINDINGS),
+            max_vertex_attribs: get_integer(gl::MAX_VERTEX_ATTRIBS),
+            ..GlLimits::default()
+        };
+
+        if info.supports(GlFeature::ShaderStorageBuffers) {
+            info.limits.max_shader_storage_buffer_bindings = get_integer(gl::MAX_SHADER_STORAGE_BUFFER_BINDINGS);
+        }
+
+        if info.supports(GlFeature::ComputeShaders) {
+            info.limits.max_compute_work_group_count = get_indexed_integers(gl::MAX_COMPUTE_WORK_GROUP_COUNT);
+            info.limits.max_compute_work_group_size = get_indexed_integers(gl::MAX_COMPUTE_WORK_GROUP_SIZE);
+            info.limits.max_compute_work_group_invocations = get_integer(gl::MAX_COMPUTE_WORK_GROUP_INVOCATIONS);
+        }
+
+        info
+    }
+
+    pub fn is_version_at_least(&self, major: u32, minor: u32) -> bool {
+        self.version >= GlVersion::of(major, minor)
+    }
+
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.contains(name)
+    }
+
+    pub fn supports(&self, feature: GlFeature) -> bool {
+        if self.es {
+            return match feature {
+                GlFeature::DirectStateAccess => false,
+                GlFeature::ComputeShaders => self.is_version_at_least(3, 1),
+                GlFeature::ShaderStorageBuffers => self.is_version_at_least(3, 1),
+                GlFeature::DebugOutput => self.is_version_at_least(3, 2) || self.has_extension("GL_KHR_debug"),
+                GlFeature::QueryBufferObjects => false,
+                GlFeature::TimerQueries => self.has_extension("GL_EXT_disjoint_timer_query"),
+                // Core in ES 3.0, though drawing from them is not.
+                GlFeature::TransformFeedbackObjects => self.is_version_at_least(3, 0)
+            };
+        }
+
+        match feature {
+            GlFeature::DirectStateAccess => self.is_version_at_least(4, 5) || self.has_extension("GL_ARB_direct_state_access"),
+            GlFeature::ComputeShaders => self.is_version_at_least(4, 3) || self.has_extension("GL_ARB_compute_shader"),
+            GlFeature::ShaderStorageBuffers => self.is_version_at_least(4, 3) || self.has_extension("GL_ARB_shader_storage_buffer_object"),
+            GlFeature::DebugOutput => self.is_version_at_least(4, 3) || self.has_extension("GL_KHR_debug"),
+            GlFeature::QueryBufferObjects => self.is_version_at_least(4, 4) || self.has_extension("GL_ARB_query_buffer_object"),
+            GlFeature::TimerQueries => self.is_version_at_least(3, 3) || self.has_extension("GL_ARB_timer_query"),
+            GlFeature::TransformFeedbackObjects => self.is_version_at_least(4, 0) || self.has_extension("GL_ARB_transform_feedback2")
+        }
+    }
+
+    /// Checks that every feature in `features` is available.
+    pub fn require(&self, features: &[GlFeature]) -> Result<(), UnsupportedContext> {
+        let missing: Vec<GlFeature> = features.iter().copied().filter(|f| !self.supports(*f)).collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        Err(UnsupportedContext {
+            version: self.version,
+            renderer: self.renderer.clone(),
+            required_version: None,
+            missing
+        })
+    }
+
+    /// Checks that the context is at least `version`.
+    pub fn require_version(&self, version: GlVersion) -> Result<(), UnsupportedContext> {
+        if self.version >= version {
+            return Ok(());
+        }
+
+        Err(UnsupportedContext {
+            version: self.version,
+            renderer: self.renderer.clone(),
+            required_version: Some(version),
+            missing: Vec::new()
+        })
+    }
+}
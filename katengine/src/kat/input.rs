@@ -0,0 +1,307 @@
+//! Polled keyboard and mouse state, built from the event stream.
+
+use std::collections::HashSet;
+use crate::kat::{Action, Event, GamepadAxis, GamepadButton, GamepadState, Key, Modifiers, MouseButton};
+
+/// How the cursor behaves while over the window.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum CursorMode {
+    Normal,
+    /// Invisible over the content area but otherwise free to leave the window.
+    Hidden,
+    /// Hidden and locked to the window, reporting unbounded motion. For FPS-style cameras.
+    Captured
+}
+
+/// Keyboard, mouse and gamepad state for one window.
+///
+/// The window feeds every event through `handle_event` and calls `begin_frame` before
+/// each batch, so "this frame" means since the last time events were polled.
+#[derive(Clone,Debug,Default)]
+pub struct Input {
+    keys_down: HashSet<Key>,
+    keys_pressed: HashSet<Key>,
+    keys_released: HashSet<Key>,
+    buttons_down: HashSet<MouseButton>,
+    buttons_pressed: HashSet<MouseButton>,
+    buttons_released: HashSet<MouseButton>,
+    mouse_position: (f64, f64),
+    mouse_delta: (f64, f64),
+    /// `None` until the first cursor event, and after the cursor mode changes, so a
+    /// jump in reported position doesn't show up as motion.
+    last_position: Option<(f64, f64)>,
+    scroll: (f64, f64),
+    modifiers: Modifiers,
+    text: String,
+    /// Neutral while no gamepad is connected, so bindings read it without checking.
+    gamepad: GamepadState,
+    previous_gamepad: GamepadState,
+    has_gamepad: bool
+}
+
+impl Input {
+    pub fn new() -> Input {
+        Input::default()
+    }
+
+    /// Forgets everything that only lasts one frame.
+    pub fn begin_frame(&mut self) {
+        self.keys_pressed.clear();
+        self.keys_released.clear();
+        self.buttons_pressed.clear();
+        self.buttons_released.clear();
+        self.mouse_delta = (0.0, 0.0);
+        self.scroll = (0.0, 0.0);
+        self.text.clear();
+    }
+
+    pub fn handle_event(&mut self, event: &Event) {
+        match event {
+            Event::Key { key, action, modifiers, .. } => {
+                self.modifiers = *modifiers;
+                match action {
+                    Action::Press => {
+                        self.keys_down.insert(*key);
+                        self.keys_pressed.insert(*key);
+                    }
+                    Action::Release => {
+                        self.keys_down.remove(key);
+                        self.keys_released.insert(*key);
+                    }
+                    Action::Repeat => {}
+                }
+            }
+            Event::MouseButton { button, action, modifiers } => {
+                self.modifiers = *modifiers;
+                match action {
+                    Action::Press => {
+                        self.buttons_down.insert(*button);
+                        self.buttons_pressed.insert(*button);
+                    }
+                    Action::Release => {
+                        self.buttons_down.remove(button);
+                        self.buttons_released.insert(*button);
+                    }
+                    Action::Repeat => {}
+                }
+            }
+            Event::CursorMoved { x, y } => {
+                if let Some((lx, ly)) = self.last_position {
+                    self.mouse_delta.0 += x - lx;
+                    self.mouse_delta.1 += y - ly;
+                }
+                self.last_position = Some((*x, *y));
+                self.mouse_position = (*x, *y);
+            }
+            Event::Scroll { x, y } => {
+                self.scroll.0 += x;
+                self.scroll.1 += y;
+            }
+            Event::Text(c) => self.text.push(*c),
+            Event::Focused(false) => self.release_all(),
+            _ => {}
+        }
+    }
+
+    /// Releases every held key and button, e.g. when focus is lost and release events
+    /// would never arrive.
+    pub fn release_all(&mut self) {
+        self.keys_released.extend(self.keys_down.drain());
+        self.buttons_released.extend(self.buttons_down.drain());
+        self.modifiers = Modifiers::default();
+    }
+
+    /// Makes the next cursor event a new starting point rather than motion.
+    pub fn reset_mouse_delta(&mut self) {
+        self.last_position = None;
+    }
+
+    pub fn is_key_down(&self, key: Key) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    pub fn was_key_pressed_this_frame(&self, key: Key) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    pub fn was_key_released_this_frame(&self, key: Key) -> bool {
+        self.keys_released.contains(&key)
+    }
+
+    pub fn is_button_down(&self, button: MouseButton) -> bool {
+        self.buttons_down.contains(&button)
+    }
+
+    pub fn was_button_pressed_this_frame(&self, button: MouseButton) -> bool {
+        self.buttons_pressed.contains(&button)
+    }
+
+    pub fn was_button_released_this_frame(&self, button: MouseButton) -> bool {
+        self.buttons_released.contains(&button)
+    }
+
+    /// Cursor position in screen coordinates relative to the top-left of the content area.
+    pub fn mouse_position(&self) -> (f64, f64) {
+        self.mouse_position
+    }
+
+    /// Cursor motion this frame.
+    pub fn mouse_delta(&self) -> (f64, f64) {
+        self.mouse_delta
+    }
+
+    /// Scroll offset accumulated this frame.
+    pub fn scroll_delta(&self) -> (f64, f64) {
+        self.scroll
+    }
+
+    /// Modifiers held at the most recent key or mouse button event.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Text typed this frame.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// State of the gamepad driving this window's input; all released if there is none.
+    pub fn gamepad(&self) -> &GamepadState {
+        &self.gamepad
+    }
+
+    pub fn has_gamepad(&self) -> bool {
+        self.has_gamepad
+    }
+
+    /// Records the gamepad's state for this frame. The window does this on every poll.
+    pub fn set_gamepad(&mut self, state: Option<GamepadState>) {
+        self.previous_gamepad = self.gamepad;
+        self.gamepad = state.unwrap_or_default();
+        self.has_gamepad = state.is_some();
+    }
+
+    pub fn is_gamepad_button_down(&self, button: GamepadButton) -> bool {
+        self.gamepad.button(button)
+    }
+
+    pub fn was_gamepad_button_pressed_this_frame(&self, button: GamepadButton) -> bool {
+        self.gamepad.button(button) && !self.previous_gamepad.button(button)
+    }
+
+    pub fn was_gamepad_button_released_this_frame(&self, button: GamepadButton) -> bool {
+        !self.gamepad.button(button) && self.previous_gamepad.button(button)
+    }
+
+    pub fn gamepad_axis(&self, axis: GamepadAxis) -> f32 {
+        self.gamepad.axis(axis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(key: Key, action: Action) -> Event {
+        Event::Key { key, scancode: 0, action, modifiers: Modifiers::default() }
+    }
+
+    fn button(button: MouseButton, action: Action) -> Event {
+        Event::MouseButton { button, action, modifiers: Modifiers { shift: true, ..Modifiers::default() } }
+    }
+
+    #[test]
+    fn presses_and_releases_last_one_frame() {
+        let mut input = Input::new();
+        input.begin_frame();
+        input.handle_event(&key(Key::W, Action::Press));
+        input.handle_event(&key(Key::W, Action::Repeat));
+        input.handle_event(&button(MouseButton::Left, Action::Press));
+        assert!(input.is_key_down(Key::W) && input.was_key_pressed_this_frame(Key::W));
+        assert!(input.is_button_down(MouseButton::Left) && input.was_button_pressed_this_frame(MouseButton::Left));
+        assert!(input.modifiers().shift);
+
+        input.begin_frame();
+        assert!(input.is_key_down(Key::W) && !input.was_key_pressed_this_frame(Key::W));
+        assert!(input.is_button_down(MouseButton::Left) && !input.was_button_pressed_this_frame(MouseButton::Left));
+        input.handle_event(&key(Key::W, Action::Release));
+        input.handle_event(&button(MouseButton::Left, Action::Release));
+        assert!(!input.is_key_down(Key::W) && input.was_key_released_this_frame(Key::W));
+        assert!(!input.is_button_down(MouseButton::Left) && input.was_button_released_this_frame(MouseButton::Left));
+
+        input.begin_frame();
+        assert!(!input.was_key_released_this_frame(Key::W) && !input.was_button_released_this_frame(MouseButton::Left));
+    }
+
+    #[test]
+    fn tap_within_a_frame_is_both_pressed_and_released() {
+        let mut input = Input::new();
+        input.handle_event(&key(Key::Space, Action::Press));
+        input.handle_event(&key(Key::Space, Action::Release));
+        assert!(!input.is_key_down(Key::Space));
+        assert!(input.was_key_pressed_this_frame(Key::Space) && input.was_key_released_this_frame(Key::Space));
+    }
+
+    #[test]
+    fn scroll_and_text_accumulate_until_the_next_frame() {
+        let mut input = Input::new();
+        input.handle_event(&Event::Scroll { x: 0.0, y: 1.0 });
+        input.handle_event(&Event::Scroll { x: 0.5, y: 2.0 });
+        input.handle_event(&Event::Text('h'));
+        input.handle_event(&Event::Text('é'));
+        assert_eq!((input.scroll_delta(), input.text()), ((0.5, 3.0), "hé"));
+        input.begin_frame();
+        assert_eq!((input.scroll_delta(), input.text()), ((0.0, 0.0), ""));
+    }
+
+    #[test]
+    fn mouse_delta_starts_at_the_first_position() {
+        let mut input = Input::new();
+        input.handle_event(&Event::CursorMoved { x: 100.0, y: 100.0 });
+        assert_eq!(input.mouse_delta(), (0.0, 0.0));
+        input.handle_event(&Event::CursorMoved { x: 103.0, y: 98.0 });
+        input.handle_event(&Event::CursorMoved { x: 104.0, y: 99.0 });
+        assert_eq!((input.mouse_position(), input.mouse_delta()), ((104.0, 99.0), (4.0, -1.0)));
+        input.begin_frame();
+        assert_eq!(input.mouse_delta(), (0.0, 0.0));
+
+        // A cursor mode change jumps the reported position; that jump isn't motion.
+        input.reset_mouse_delta();
+        input.handle_event(&Event::CursorMoved { x: 5000.0, y: -3000.0 });
+        assert_eq!((input.mouse_position(), input.mouse_delta()), ((5000.0, -3000.0), (0.0, 0.0)));
+        input.handle_event(&Event::CursorMoved { x: 5001.0, y: -3000.0 });
+        assert_eq!(input.mouse_delta(), (1.0, 0.0));
+    }
+
+    #[test]
+    fn losing_focus_releases_everything() {
+        let mut input = Input::new();
+        input.handle_event(&key(Key::A, Action::Press));
+        input.handle_event(&key(Key::LeftShift, Action::Press));
+        input.handle_event(&button(MouseButton::Right, Action::Press));
+        input.begin_frame();
+        input.handle_event(&Event::Focused(true));
+        assert!(input.is_key_down(Key::A));
+        input.handle_event(&Event::Focused(false));
+        assert!(!input.is_key_down(Key::A) && !input.is_key_down(Key::LeftShift) && !input.is_button_down(MouseButton::Right));
+        assert!(input.was_key_released_this_frame(Key::A) && input.was_key_released_this_frame(Key::LeftShift));
+        assert!(input.was_button_released_this_frame(MouseButton::Right));
+        assert_eq!(input.modifiers(), Modifiers::default());
+    }
+
+    #[test]
+    fn gamepad_edges_compare_with_the_previous_poll() {
+        let mut input = Input::new();
+        assert!(!input.has_gamepad() && input.gamepad_axis(GamepadAxis::LeftX) == 0.0);
+        let mut state = GamepadState::default();
+        state.buttons[GamepadButton::A as usize] = true;
+        state.axes[GamepadAxis::LeftX as usize] = 0.5;
+        input.set_gamepad(Some(state));
+        assert!(input.has_gamepad() && input.was_gamepad_button_pressed_this_frame(GamepadButton::A));
+        assert_eq!(input.gamepad_axis(GamepadAxis::LeftX), 0.5);
+        input.set_gamepad(Some(state));
+        assert!(input.is_gamepad_button_down(GamepadButton::A) && !input.was_gamepad_button_pressed_this_frame(GamepadButton::A));
+        input.set_gamepad(None);
+        assert!(!input.has_gamepad() && input.was_gamepad_button_released_this_frame(GamepadButton::A));
+    }
+}
@@ -0,0 +1,451 @@
+//! Meshes: vertex and index buffers with their vertex array, split into submeshes that each
+//! use one material slot.
+
+pub mod obj;
+pub mod shapes;
+
+use crate::gl;
+use crate::kat::{Bindable, Buffer, BufferTarget, DrawMode, Labeled, Shader, VertexArray};
+
+/// A range of a mesh's indices drawn with one material.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct Submesh {
+    /// First index of the range.
+    pub start: usize,
+    pub count: usize,
+    /// Which of the materials the mesh is drawn with applies to this range.
+    pub material: usize
+}
+
+impl Submesh {
+    pub fn of(start: usize, count: usize, material: usize) -> Submesh {
+        Submesh { start, count, material }
+    }
+}
+
+/// An axis-aligned bounding box.
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct Aabb {
+    pub min: glm::Vec3,
+    pub max: glm::Vec3
+}
+
+impl Aabb {
+    /// The smallest box around `points`, or `None` if there are none.
+    pub fn from_points<I>(points: I) -> Option<Aabb> where I: IntoIterator<Item = glm::Vec3> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Aabb { min: first, max: first }, |b, p| Aabb { min: glm::min(b.min, p), max: glm::max(b.max, p) }))
+    }
+
+    pub fn center(&self) -> glm::Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Half the size along each axis.
+    pub fn extents(&self) -> glm::Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    pub fn contains(&self, point: glm::Vec3) -> bool {
+        point.x >= self.min.x && point.y >= self.min.y && point.z >= self.min.z &&
+            point.x <= self.max.x && point.y <= self.max.y && point.z <= self.max.z
+    }
+
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb { min: glm::min(self.min, other.min), max: glm::max(self.max, other.max) }
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct BoundingSphere {
+    pub center: glm::Vec3,
+    pub radius: f32
+}
+
+impl BoundingSphere {
+    /// A sphere around `points` centered on their bounding box: cheap, but not the smallest.
+    pub fn from_points<I>(points: I) -> Option<BoundingSphere> where I: IntoIterator<Item = glm::Vec3> + Clone {
+        let center = Aabb::from_points(points.clone())?.center();
+        let radius = points.into_iter().map(|p| glm::distance(p, center)).fold(0.0, f32::max);
+        Some(BoundingSphere { center, radius })
+    }
+}
+
+/// Mesh data on the CPU, e.g. from a generator in `shapes`, before it is uploaded.
+///
+/// Attributes are either empty or have one entry per position. Without submeshes the
+/// data is a single submesh with material 0.
+#[derive(Clone,PartialEq,Debug,Default)]
+pub struct MeshData {
+    pub positions: Vec<glm::Vec3>,
+    pub normals: Vec<glm::Vec3>,
+    pub uvs: Vec<glm::Vec2>,
+    /// Direction of increasing u, with the handedness of the bitangent in w (1 or -1).
+    pub tangents: Vec<glm::Vec4>,
+    pub indices: Vec<u32>,
+    pub submeshes: Vec<Submesh>
+}
+
+impl MeshData {
+    /// The layout `upload` produces: position, normal, uv and tangent at locations 0 to 3.
+    pub const ATTRIBS: [usize; 4] = [3, 3, 2, 4];
+
+    pub fn new() -> MeshData {
+        MeshData::default()
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    pub fn aabb(&self) -> Option<Aabb> {
+        Aabb::from_points(self.positions.iter().copied())
+    }
+
+    pub fn bounding_sphere(&self) -> Option<BoundingSphere> {
+        BoundingSphere::from_points(self.positions.iter().copied())
+    }
+
+    /// The submeshes, or a single one over every index if none were given.
+    pub fn submeshes_or_whole(&self) -> Vec<Submesh> {
+        if self.submeshes.is_empty() {
+            vec!(Submesh::of(0, self.indices.len(), 0))
+        } else {
+            self.submeshes.clone()
+        }
+    }
+
+    /// The submeshes, or a single one over every index if none were given and there are any.
+    fn submeshes_or_whole_if_any(&self) -> Vec<Submesh> {
+        if self.submeshes.is_empty() && self.indices.is_empty() { Vec::new() } else { self.submeshes_or_whole() }
+    }
+
+    /// Appends `other`, keeping its submeshes and material slots as they are. An attribute
+    /// only one side has is filled with zeros on the other.
+    pub fn append(&mut self, other: &MeshData) {
+        let (vertex_offset, index_offset) = (self.positions.len() as u32, self.indices.len());
+        let (count, other_count) = (self.positions.len(), other.positions.len());
+        let mut submeshes = self.submeshes_or_whole_if_any();
+        submeshes.extend(other.submeshes_or_whole_if_any().iter().map(|s| Submesh::of(s.start + index_offset, s.count, s.material)));
+        self.submeshes = submeshes;
+        self.positions.extend_from_slice(&other.positions);
+        append_attribute(&mut self.normals, count, &other.normals, other_count, glm::vec3(0.0, 0.0, 0.0));
+        append_attribute(&mut self.uvs, count, &other.uvs, other_count, glm::vec2(0.0, 0.0));
+        append_attribute(&mut self.tangents, count, &other.tangents, other_count, glm::vec4(0.0, 0.0, 0.0, 0.0));
+        self.indices.extend(other.indices.iter().map(|i| i + vertex_offset));
+    }
+
+    /// Computes tangents from the positions, normals and uvs of every triangle. Vertices
+    /// whose triangles have no usable uv gradient get an arbitrary tangent.
+    pub fn compute_tangents(&mut self) {
+        let count = self.positions.len();
+        if self.normals.len() != count || self.uvs.len() != count {
+            panic!("Tangents need a normal and uv for each of the {} positions", count);
+        }
+        let zero = glm::vec3(0.0, 0.0, 0.0);
+        let mut tangents = vec!(zero; count);
+        let mut bitangents = vec!(zero; count);
+        for triangle in self.indices.chunks_exact(3) {
+            let [a, b, c] = [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize];
+            let (e1, e2) = (self.positions[b] - self.positions[a], self.positions[c] - self.positions[a]);
+            let (d1, d2) = (self.uvs[b] - self.uvs[a], self.uvs[c] - self.uvs[a]);
+            let det = d1.x * d2.y - d2.x * d1.y;
+            if det.abs() < 1e-12 {
+                continue;
+            }
+            let tangent = (e1 * d2.y - e2 * d1.y) / det;
+            let bitangent = (e2 * d1.x - e1 * d2.x) / det;
+            for &i in &[a, b, c] {
+                tangents[i] = tangents[i] + tangent;
+                bitangents[i] = bitangents[i] + bitangent;
+            }
+        }
+
+        self.tangents = (0..count).map(|i| {
+            let n = self.normals[i];
+            // Gram-Schmidt against the normal.
+            let mut t = tangents[i] - n * glm::dot(n, tangents[i]);
+            if glm::length(t) < 1e-6 {
+                let axis = if n.x.abs() < 0.9 { glm::vec3(1.0, 0.0, 0.0) } else { glm::vec3(0.0, 1.0, 0.0) };
+                t = glm::cross(axis, n);
+            }
+            let t = glm::normalize(t);
+            let w = if glm::dot(glm::cross(n, t), bitangents[i]) < 0.0 { -1.0 } else { 1.0 };
+            glm::vec4(t.x, t.y, t.z, w)
+        }).collect();
+    }
+
+    /// The vertices interleaved as `ATTRIBS`, with zeros for missing attributes.
+    pub fn interleaved(&self) -> Vec<f32> {
+        let count = self.positions.len();
+        for (name, len) in [("normals", self.normals.len()), ("uvs", self.uvs.len()), ("tangents", self.tangents.len())] {
+            if len != 0 && len != count {
+                panic!("{} {} for {} positions", len, name, count);
+            }
+        }
+        let stride: usize = MeshData::ATTRIBS.iter().sum();
+        let mut vertices = Vec::with_capacity(count * stride);
+        for i in 0..count {
+            let p = self.positions[i];
+            let n = self.normals.get(i).copied().unwrap_or(glm::vec3(0.0, 0.0, 0.0));
+            let uv = self.uvs.get(i).copied().unwrap_or(glm::vec2(0.0, 0.0));
+            let t = self.tangents.get(i).copied().unwrap_or(glm::vec4(0.0, 0.0, 0.0, 0.0));
+            vertices.extend_from_slice(&[p.x, p.y, p.z, n.x, n.y, n.z, uv.x, uv.y, t.x, t.y, t.z, t.w]);
+        }
+        return vertices;
+    }
+
+    pub fn upload(&self) -> Mesh {
+        Mesh::with_submeshes(self.interleaved(), MeshData::ATTRIBS.to_vec(), self.indices.clone(), self.submeshes_or_whole())
+    }
+}
+
+/// Appends one attribute of `MeshData::append`, keeping it empty only if both sides are.
+fn append_attribute<T: Copy>(values: &mut Vec<T>, count: usize, other: &[T], other_count: usize, zero: T) {
+    if values.is_empty() && other.is_empty() {
+        return;
+    }
+    values.resize(count, zero);
+    if other.is_empty() {
+        values.resize(count + other_count, zero);
+    } else {
+        values.extend_from_slice(other);
+    }
+}
+
+/// Vertex data, indices and the vertex array that ties them together.
+///
+/// Vertices are interleaved floats laid out by `attribs`, the component count of each
+/// attribute in order, as in `VertexArray::vertex_buffer`. The first attribute is the
+/// position and is used for the bounds; two-component positions get a z of zero.
+pub struct Mesh {
+    vertex_array: VertexArray,
+    vertices: Buffer<f32>,
+    indices: Buffer<u32>,
+    attribs: Vec<usize>,
+    mode: DrawMode,
+    vertex_count: usize,
+    submeshes: Vec<Submesh>,
+    aabb: Aabb,
+    sphere: BoundingSphere
+}
+
+impl Mesh {
+    /// A triangle mesh with a single submesh using material 0.
+    pub fn create(vertices: Vec<f32>, attribs: Vec<usize>, indices: Vec<u32>) -> Mesh {
+        let submesh = Submesh::of(0, indices.len(), 0);
+        Mesh::with_submeshes(vertices, attribs, indices, vec!(submesh))
+    }
+
+    pub fn with_submeshes(vertices: Vec<f32>, attribs: Vec<usize>, indices: Vec<u32>, submeshes: Vec<Submesh>) -> Mesh {
+        let stride: usize = attribs.iter().sum();
+        if attribs.is_empty() || attribs[0] < 2 || attribs[0] > 4 {
+            panic!("The first vertex attribute must be a position of 2 to 4 components, got layout {:?}", attribs);
+        }
+        if !vertices.len().is_multiple_of(stride) {
+            panic!("{} floats don't make whole vertices of {} floats", vertices.len(), stride);
+        }
+        let vertex_count = vertices.len() / stride;
+        if let Some(&index) = indices.iter().find(|&&i| i as usize >= vertex_count) {
+            panic!("Index {} is out of range for {} vertices", index, vertex_count);
+        }
+        for submesh in &submeshes {
+            if submesh.start + submesh.count > indices.len() {
+                panic!("{:?} is out of range for {} indices", submesh, indices.len());
+            }
+        }
+
+        let positions = vertices.chunks_exact(stride).map(|v| glm::vec3(v[0], v[1], if attribs[0] > 2 { v[2] } else { 0.0 }));
+        let aabb = Aabb::from_points(positions.clone()).unwrap_or(Aabb { min: glm::vec3(0.0, 0.0, 0.0), max: glm::vec3(0.0, 0.0, 0.0) });
+        let sphere = BoundingSphere::from_points(positions).unwrap_or(BoundingSphere { center: glm::vec3(0.0, 0.0, 0.0), radius: 0.0 });
+
+        let mut vertex_buffer = Buffer::create(BufferTarget::Array, vertices);
+        let mut index_buffer = Buffer::create(BufferTarget::ElementArray, indices);
+        let mut vertex_array = VertexArray::new();
+        vertex_array.vertex_buffer(&mut vertex_buffer, attribs.clone());
+        vertex_array.element_buffer(&mut index_buffer);
+
+        return Mesh {
+            vertex_array,
+            vertices: vertex_buffer,
+            indices: index_buffer,
+            attribs,
+            mode: DrawMode::Triangles,
+            vertex_count,
+            submeshes,
+            aabb,
+            sphere
+        };
+    }
+
+    /// How the indices are assembled into primitives. Triangles by default.
+    pub fn set_mode(&mut self, mode: DrawMode) {
+        self.mode = mode;
+    }
+
+    pub fn mode(&self) -> DrawMode {
+        self.mode
+    }
+
+    pub fn attribs(&self) -> &[usize] {
+        &self.attribs
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    pub fn index_count(&self) -> usize {
+        self.indices.size
+    }
+
+    pub fn submeshes(&self) -> &[Submesh] {
+        &self.submeshes
+    }
+
+    /// The number of material slots, one more than the highest slot any submesh uses.
+    pub fn material_count(&self) -> usize {
+        self.submeshes.iter().map(|s| s.material + 1).max().unwrap_or(0)
+    }
+
+    pub fn aabb(&self) -> Aabb {
+        self.aabb
+    }
+
+    pub fn bounding_sphere(&self) -> BoundingSphere {
+        self.sphere
+    }
+
+    pub fn vertex_array(&mut self) -> &mut VertexArray {
+        &mut self.vertex_array
+    }
+
+    pub fn vertex_buffer(&mut self) -> &mut Buffer<f32> {
+        &mut self.vertices
+    }
+
+    pub fn index_buffer(&mut self) -> &mut Buffer<u32> {
+        &mut self.indices
+    }
+
+    /// Draws every submesh with `shader`.
+    pub fn draw(&mut self, shader: &Shader) {
+        self.draw_with_materials(shader, |_| {});
+    }
+
+    /// Draws every submesh with `shader`, calling `f` with the submesh's material slot
+    /// before each one so it can set that material's uniforms and textures.
+    pub fn draw_with_materials<F>(&mut self, shader: &Shader, mut f: F) where F: FnMut(usize) {
+        use_program(shader);
+        for i in 0..self.submeshes.len() {
+            let submesh = self.submeshes[i];
+            f(submesh.material);
+            self.vertex_array.draw_elements(self.mode, submesh.count, submesh.start as i32);
+        }
+    }
+
+    pub fn draw_submesh(&mut self, index: usize, shader: &Shader) {
+        use_program(shader);
+        let submesh = self.submeshes[index];
+        self.vertex_array.draw_elements(self.mode, submesh.count, submesh.start as i32);
+    }
+}
+
+fn use_program(shader: &Shader) {
+    shader.group.check("Shader");
+    unsafe { gl::UseProgram(shader.handle); }
+}
+
+impl Bindable for Mesh {
+    fn bind(&mut self) {
+        self.vertex_array.bind();
+    }
+}
+
+impl Labeled for Mesh {
+    fn set_label(&mut self, label: &str) {
+        self.vertex_array.set_label(label);
+        self.vertices.set_label(&format!("{} vertices", label));
+        self.indices.set_label(&format!("{} indices", label));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle(uvs: bool) -> MeshData {
+        let mut data = MeshData::new();
+        data.positions = vec!(glm::vec3(0.0, 0.0, 0.0), glm::vec3(1.0, 0.0, 0.0), glm::vec3(0.0, 1.0, 0.0));
+        data.normals = vec!(glm::vec3(0.0, 0.0, 1.0); 3);
+        data.indices = vec!(0, 1, 2);
+        if uvs {
+            data.uvs = vec!(glm::vec2(0.0, 0.0), glm::vec2(1.0, 0.0), glm::vec2(0.0, 1.0));
+            data.compute_tangents();
+        }
+        data
+    }
+
+    #[test]
+    fn append_offsets_indices_and_submeshes() {
+        let mut data = triangle(true);
+        let mut other = triangle(true);
+        other.indices = vec!(0, 1, 2, 2, 1, 0);
+        other.submeshes = vec!(Submesh::of(0, 3, 1), Submesh::of(3, 3, 2));
+        data.append(&other);
+        assert_eq!(data.indices, vec!(0, 1, 2, 3, 4, 5, 5, 4, 3));
+        assert_eq!(data.submeshes, vec!(Submesh::of(0, 3, 0), Submesh::of(3, 3, 1), Submesh::of(6, 3, 2)));
+    }
+
+    #[test]
+    fn append_fills_missing_attributes() {
+        let mut data = triangle(false);
+        data.append(&triangle(true));
+        assert_eq!((data.positions.len(), data.normals.len(), data.uvs.len(), data.tangents.len()), (6, 6, 6, 6));
+        assert_eq!(data.uvs[0], glm::vec2(0.0, 0.0));
+        assert_eq!(data.uvs[4], glm::vec2(1.0, 0.0));
+        assert_eq!(data.interleaved().len(), 6 * 12);
+
+        let mut data = triangle(true);
+        data.append(&triangle(false));
+        assert_eq!((data.uvs.len(), data.tangents.len()), (6, 6));
+        assert_eq!(data.tangents[3], glm::vec4(0.0, 0.0, 0.0, 0.0));
+
+        let mut data = triangle(false);
+        data.append(&triangle(false));
+        assert!(data.uvs.is_empty() && data.tangents.is_empty());
+    }
+
+    #[test]
+    fn append_to_empty_adds_no_empty_submesh() {
+        let mut data = MeshData::new();
+        data.append(&triangle(true));
+        assert_eq!(data.submeshes_or_whole(), vec!(Submesh::of(0, 3, 0)));
+        data.append(&MeshData::new());
+        assert_eq!(data.submeshes_or_whole(), vec!(Submesh::of(0, 3, 0)));
+        assert_eq!(data.positions.len(), data.normals.len());
+    }
+
+    #[test]
+    fn tangents_follow_u() {
+        let data = triangle(true);
+        for t in &data.tangents {
+            assert_eq!(*t, glm::vec4(1.0, 0.0, 0.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        let data = triangle(false);
+        let aabb = data.aabb().unwrap();
+        assert_eq!((aabb.min, aabb.max), (glm::vec3(0.0, 0.0, 0.0), glm::vec3(1.0, 1.0, 0.0)));
+        assert!(aabb.contains(glm::vec3(0.5, 0.5, 0.0)) && !aabb.contains(glm::vec3(0.5, 0.5, 0.1)));
+        assert_eq!(data.bounding_sphere().unwrap().center, glm::vec3(0.5, 0.5, 0.0));
+        assert!(MeshData::new().aabb().is_none());
+    }
+}
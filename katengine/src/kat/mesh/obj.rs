@@ -0,0 +1,866 @@
+//! Wavefront OBJ models and their MTL materials.
+//!
+//! Faces become triangles in one `MeshData` per object, with a submesh per material. Faces
+//! without normals get flat normals, or smooth ones shared within their smoothing group.
+//! Vertices are shared wherever a face corner repeats the same position, uv and normal.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::kat::mesh::{MeshData, Submesh};
+
+#[derive(Debug)]
+pub enum ObjError {
+    Io { path: PathBuf, error: io::Error },
+    /// `line` and `column` count from 1; `file` is `None` when parsing a string.
+    Parse { file: Option<PathBuf>, line: usize, column: usize, message: String }
+}
+
+impl ObjError {
+    fn in_file(self, path: &Path) -> ObjError {
+        match self {
+            ObjError::Parse { file: None, line, column, message } => ObjError::Parse { file: Some(path.to_path_buf()), line, column, message },
+            e => e
+        }
+    }
+}
+
+impl Display for ObjError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjError::Io { path, error } => write!(f, "Failed to read {}: {}", path.display(), error),
+            ObjError::Parse { file: Some(file), line, column, message } => write!(f, "{}:{}:{}: {}", file.display(), line, column, message),
+            ObjError::Parse { file: None, line, column, message } => write!(f, "Line {}, column {}: {}", line, column, message)
+        }
+    }
+}
+
+impl std::error::Error for ObjError {}
+
+/// A material from an MTL file. Texture paths are relative to the MTL file unless it was
+/// loaded through `load`, which resolves them.
+#[derive(Clone,PartialEq,Debug)]
+pub struct ObjMaterial {
+    pub name: String,
+    pub ambient: glm::Vec3,
+    pub diffuse: glm::Vec3,
+    pub specular: glm::Vec3,
+    pub emissive: glm::Vec3,
+    /// Specular exponent, 0 to 1000.
+    pub shininess: f32,
+    /// Index of refraction.
+    pub optical_density: f32,
+    /// Opacity, 1 for opaque.
+    pub dissolve: f32,
+    pub illumination: u32,
+    /// From the PBR extension to MTL, if given.
+    pub roughness: Option<f32>,
+    pub metallic: Option<f32>,
+    pub ambient_map: Option<PathBuf>,
+    pub diffuse_map: Option<PathBuf>,
+    pub specular_map: Option<PathBuf>,
+    pub shininess_map: Option<PathBuf>,
+    pub emissive_map: Option<PathBuf>,
+    pub dissolve_map: Option<PathBuf>,
+    pub bump_map: Option<PathBuf>,
+    pub bump_multiplier: f32,
+    pub normal_map: Option<PathBuf>,
+    pub displacement_map: Option<PathBuf>,
+    pub roughness_map: Option<PathBuf>,
+    pub metallic_map: Option<PathBuf>
+}
+
+impl ObjMaterial {
+    /// A plain light grey material, used for names no library defines.
+    pub fn named(name: &str) -> ObjMaterial {
+        let zero = glm::vec3(0.0, 0.0, 0.0);
+        ObjMaterial {
+            name: name.to_string(),
+            ambient: zero,
+            diffuse: glm::vec3(0.8, 0.8, 0.8),
+            specular: zero,
+            emissive: zero,
+            shininess: 0.0,
+            optical_density: 1.0,
+            dissolve: 1.0,
+            illumination: 1,
+            roughness: None,
+            metallic: None,
+            ambient_map: None,
+            diffuse_map: None,
+            specular_map: None,
+            shininess_map: None,
+            emissive_map: None,
+            dissolve_map: None,
+            bump_map: None,
+            bump_multiplier: 1.0,
+            normal_map: None,
+            displacement_map: None,
+            roughness_map: None,
+            metallic_map: None
+        }
+    }
+
+    fn maps_mut(&mut self) -> [&mut Option<PathBuf>; 11] {
+        [&mut self.ambient_map, &mut self.diffuse_map, &mut self.specular_map, &mut self.shininess_map,
+            &mut self.emissive_map, &mut self.dissolve_map, &mut self.bump_map, &mut self.normal_map,
+            &mut self.displacement_map, &mut self.roughness_map, &mut self.metallic_map]
+    }
+}
+
+/// One `o` statement's worth of faces.
+#[derive(Clone,PartialEq,Debug)]
+pub struct ObjObject {
+    pub name: String,
+    /// Submesh materials index `ObjModel::materials`.
+    pub mesh: MeshData
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct ObjModel {
+    pub objects: Vec<ObjObject>,
+    /// Every material a face uses, in order of first use.
+    pub materials: Vec<ObjMaterial>,
+    /// The `mtllib` file names, as written.
+    pub material_libraries: Vec<String>
+}
+
+impl ObjModel {
+    /// Every object in one mesh, e.g. to upload a static model in a single `Mesh`. Objects
+    /// without uvs get zero uvs and tangents if others have them.
+    pub fn merged(&self) -> MeshData {
+        let mut merged = MeshData::new();
+        for object in &self.objects {
+            merged.append(&object.mesh);
+        }
+        return merged;
+    }
+
+    /// Replaces the materials with those of the same name in `library`.
+    pub fn apply_materials(&mut self, library: &[ObjMaterial]) {
+        for material in &mut self.materials {
+            if let Some(found) = library.iter().find(|m| m.name == material.name) {
+                *material = found.clone();
+            }
+        }
+    }
+}
+
+/// Loads a model and the materials of its `mtllib` files, which are looked up next to it.
+/// Missing material libraries are logged and leave their materials at the defaults.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<ObjModel, ObjError> {
+    let path = path.as_ref();
+    let source = read(path)?;
+    let mut model = parse(&source).map_err(|e| e.in_file(path))?;
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let mut defined = Vec::new();
+    for library in model.material_libraries.clone() {
+        let mtl_path = dir.join(&library);
+        let mtl_source = match read(&mtl_path) {
+            Ok(source) => source,
+            Err(e) => {
+                log::warn!(target: "katengine::obj", "{}", e);
+                continue;
+            }
+        };
+        let mut materials = parse_mtl(&mtl_source).map_err(|e| e.in_file(&mtl_path))?;
+        let mtl_dir = mtl_path.parent().unwrap_or(Path::new(""));
+        for material in &mut materials {
+            for map in material.maps_mut().into_iter().flatten() {
+                *map = mtl_dir.join(&*map);
+            }
+        }
+        model.apply_materials(&materials);
+        defined.extend(materials.into_iter().map(|m| m.name));
+    }
+    for material in &model.materials {
+        if !material.name.is_empty() && !defined.contains(&material.name) {
+            log::warn!(target: "katengine::obj", "{}: no material library defines '{}'", path.display(), material.name);
+        }
+    }
+    return Ok(model);
+}
+
+pub fn load_mtl<P: AsRef<Path>>(path: P) -> Result<Vec<ObjMaterial>, ObjError> {
+    let path = path.as_ref();
+    parse_mtl(&read(path)?).map_err(|e| e.in_file(path))
+}
+
+fn read(path: &Path) -> Result<String, ObjError> {
+    std::fs::read_to_string(path).map_err(|error| ObjError::Io { path: path.to_path_buf(), error })
+}
+
+/// A whitespace-separated token and where it starts.
+#[derive(Clone,Copy,Debug)]
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize
+}
+
+/// The tokens of one statement, which may continue over several lines ending in `\`.
+struct Statement<'a> {
+    tokens: Vec<Token<'a>>,
+    next: usize,
+    /// Just past the last token, for errors about missing values.
+    end: (usize, usize)
+}
+
+fn tokenize<'a>(text: &'a str, line: usize, tokens: &mut Vec<Token<'a>>) {
+    let mut start = None;
+    let mut column = 0;
+    for (chars, (offset, c)) in text.char_indices().enumerate() {
+        if c.is_whitespace() {
+            if let Some(s) = start.take() {
+                tokens.push(Token { text: &text[s..offset], line, column });
+            }
+        } else if start.is_none() {
+            start = Some(offset);
+            column = chars + 1;
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(Token { text: &text[s..], line, column });
+    }
+}
+
+impl<'a> Statement<'a> {
+    fn new(tokens: Vec<Token<'a>>) -> Statement<'a> {
+        let last = tokens[tokens.len() - 1];
+        let end = (last.line, last.column + last.text.chars().count());
+        Statement { tokens, next: 1, end }
+    }
+}
+
+fn statements(source: &str) -> Vec<Statement<'_>> {
+    let mut statements = Vec::new();
+    let mut tokens = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        let text = line.split('#').next().unwrap_or("");
+        let (text, continues) = match text.trim_end().strip_suffix('\\') {
+            Some(text) => (text, true),
+            None => (text, false)
+        };
+        tokenize(text, i + 1, &mut tokens);
+        if !continues && !tokens.is_empty() {
+            statements.push(Statement::new(std::mem::take(&mut tokens)));
+        }
+    }
+    if !tokens.is_empty() {
+        statements.push(Statement::new(tokens));
+    }
+    return statements;
+}
+
+fn error_at(token: &Token<'_>, message: String) -> ObjError {
+    ObjError::Parse { file: None, line: token.line, column: token.column, message }
+}
+
+impl<'a> Statement<'a> {
+    fn keyword(&self) -> &'a str {
+        self.tokens[0].text
+    }
+
+    fn error_at_end(&self, message: String) -> ObjError {
+        ObjError::Parse { file: None, line: self.end.0, column: self.end.1, message }
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.tokens.get(self.next).copied();
+        self.next += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.next).copied()
+    }
+
+    fn float(&mut self, what: &str) -> Result<f32, ObjError> {
+        match self.next() {
+            Some(token) => token.text.parse().map_err(|_| error_at(&token, format!("Expected {}, found '{}'", what, token.text))),
+            None => Err(self.error_at_end(format!("Expected {}", what)))
+        }
+    }
+
+    fn optional_float(&mut self, what: &str) -> Result<Option<f32>, ObjError> {
+        if self.peek().is_none() { Ok(None) } else { self.float(what).map(Some) }
+    }
+
+    fn vec3(&mut self, what: &str) -> Result<glm::Vec3, ObjError> {
+        Ok(glm::vec3(self.float(what)?, self.float(what)?, self.float(what)?))
+    }
+
+    /// An `r [g b]` color; a single value is grey.
+    fn color(&mut self) -> Result<glm::Vec3, ObjError> {
+        if let Some(token) = self.peek() {
+            if token.text == "spectral" || token.text == "xyz" {
+                return Err(error_at(&token, format!("'{}' colors are not supported", token.text)));
+            }
+        }
+        let r = self.float("a color component")?;
+        match self.optional_float("a color component")? {
+            Some(g) => Ok(glm::vec3(r, g, self.float("a color component")?)),
+            None => Ok(glm::vec3(r, r, r))
+        }
+    }
+
+    /// The rest of the statement as one string, for names that may contain spaces.
+    fn rest(&mut self, what: &str) -> Result<String, ObjError> {
+        if self.peek().is_none() {
+            return Err(self.error_at_end(format!("Expected {}", what)));
+        }
+        let rest: Vec<&str> = self.tokens[self.next..].iter().map(|t| t.text).collect();
+        self.next = self.tokens.len();
+        Ok(rest.join(" "))
+    }
+
+    fn finish(&self) -> Result<(), ObjError> {
+        match self.peek() {
+            Some(token) => Err(error_at(&token, format!("Unexpected '{}' after {} statement", token.text, self.keyword()))),
+            None => Ok(())
+        }
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+enum NormalKey {
+    Given(u32),
+    /// Averaged over the faces of a smoothing group sharing the position.
+    Smooth(u32),
+    /// The face's own normal, for faces outside smoothing groups.
+    Flat(u32)
+}
+
+struct ObjectBuilder {
+    name: String,
+    data: MeshData,
+    has_uvs: bool,
+    vertices: HashMap<(u32, Option<u32>, NormalKey), u32>,
+    /// Indices per material slot of the model.
+    indices: Vec<Vec<u32>>,
+    smooth_normals: HashMap<(u32, u32), glm::Vec3>,
+    smooth_vertices: Vec<(u32, (u32, u32))>
+}
+
+impl ObjectBuilder {
+    fn new(name: String) -> ObjectBuilder {
+        ObjectBuilder {
+            name,
+            data: MeshData::new(),
+            has_uvs: false,
+            vertices: HashMap::new(),
+            indices: Vec::new(),
+            smooth_normals: HashMap::new(),
+            smooth_vertices: Vec::new()
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.positions.is_empty()
+    }
+
+    fn finish(mut self) -> ObjObject {
+        for &(vertex, key) in &self.smooth_vertices {
+            let normal = self.smooth_normals[&key];
+            self.data.normals[vertex as usize] = normalize_or_up(normal);
+        }
+        for (material, indices) in self.indices.into_iter().enumerate() {
+            if !indices.is_empty() {
+                self.data.submeshes.push(Submesh::of(self.data.indices.len(), indices.len(), material));
+                self.data.indices.extend(indices);
+            }
+        }
+        if self.has_uvs {
+            self.data.compute_tangents();
+        } else {
+            self.data.uvs.clear();
+        }
+        ObjObject { name: self.name, mesh: self.data }
+    }
+}
+
+fn normalize_or_up(v: glm::Vec3) -> glm::Vec3 {
+    if glm::length(v) > 1e-12 { glm::normalize(v) } else { glm::vec3(0.0, 1.0, 0.0) }
+}
+
+/// The normal of a polygon by Newell's method, as long as twice its area.
+fn polygon_normal(points: &[glm::Vec3]) -> glm::Vec3 {
+    let mut n = glm::vec3(0.0, 0.0, 0.0);
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        n = n + glm::vec3((a.y - b.y) * (a.z + b.z), (a.z - b.z) * (a.x + b.x), (a.x - b.x) * (a.y + b.y));
+    }
+    return n;
+}
+
+/// Splits a polygon into triangles of its corners by ear clipping in the plane facing
+/// `normal`, which keeps concave polygons intact. Self-intersecting ones fall back to a fan.
+fn triangulate(points: &[glm::Vec3], normal: glm::Vec3) -> Vec<[usize; 3]> {
+    if points.len() == 3 {
+        return vec!([0, 1, 2]);
+    }
+    let (ax, ay, az) = (normal.x.abs(), normal.y.abs(), normal.z.abs());
+    // Drop the dominant axis, keeping the other two in the order that preserves winding.
+    let (projected, sign): (Vec<(f32, f32)>, f32) = if ax >= ay && ax >= az {
+        (points.iter().map(|p| (p.y, p.z)).collect(), normal.x.signum())
+    } else if ay >= az {
+        (points.iter().map(|p| (p.z, p.x)).collect(), normal.y.signum())
+    } else {
+        (points.iter().map(|p| (p.x, p.y)).collect(), normal.z.signum())
+    };
+    let turn = |o: usize, a: usize, b: usize| {
+        let (o, a, b) = (projected[o], projected[a], projected[b]);
+        ((a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)) * sign
+    };
+
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut triangles = Vec::new();
+    while remaining.len() > 3 {
+        let m = remaining.len();
+        let ear = (0..m).find(|&i| {
+            let (prev, cur, next) = (remaining[(i + m - 1) % m], remaining[i], remaining[(i + 1) % m]);
+            turn(prev, cur, next) > 0.0 && !remaining.iter().any(|&j| {
+                j != prev && j != cur && j != next &&
+                    turn(prev, cur, j) >= 0.0 && turn(cur, next, j) >= 0.0 && turn(next, prev, j) >= 0.0
+            })
+        });
+        match ear {
+            Some(i) => {
+                triangles.push([remaining[(i + m - 1) % m], remaining[i], remaining[(i + 1) % m]]);
+                remaining.remove(i);
+            }
+            None => {
+                for i in 1..m - 1 {
+                    triangles.push([remaining[0], remaining[i], remaining[i + 1]]);
+                }
+                return triangles;
+            }
+        }
+    }
+    triangles.push([remaining[0], remaining[1], remaining[2]]);
+    return triangles;
+}
+
+/// Resolves a 1-based or negative (relative to the end) OBJ index into `count` elements.
+fn resolve(token: &Token<'_>, text: &str, count: usize, what: &str) -> Result<u32, ObjError> {
+    let index: i64 = text.parse().map_err(|_| error_at(token, format!("Expected a {} index, found '{}'", what, text)))?;
+    let resolved = if index > 0 { index - 1 } else { count as i64 + index };
+    if index == 0 {
+        Err(error_at(token, format!("{} indices start at 1", what)))
+    } else if resolved < 0 || resolved >= count as i64 {
+        Err(error_at(token, format!("{} {} doesn't exist; only {} are defined before this face", what, index, count)))
+    } else {
+        Ok(resolved as u32)
+    }
+}
+
+struct Parser {
+    positions: Vec<glm::Vec3>,
+    uvs: Vec<glm::Vec2>,
+    normals: Vec<glm::Vec3>,
+    objects: Vec<ObjObject>,
+    current: ObjectBuilder,
+    /// Groups split objects only in files without `o` statements.
+    seen_object: bool,
+    materials: Vec<String>,
+    material: usize,
+    smoothing_group: u32,
+    faces: u32,
+    libraries: Vec<String>
+}
+
+impl Parser {
+    fn start_object(&mut self, name: String) {
+        let previous = std::mem::replace(&mut self.current, ObjectBuilder::new(name));
+        if !previous.is_empty() {
+            self.objects.push(previous.finish());
+        }
+    }
+
+    fn use_material(&mut self, name: String) {
+        self.material = match self.materials.iter().position(|m| *m == name) {
+            Some(i) => i,
+            None => {
+                self.materials.push(name);
+                self.materials.len() - 1
+            }
+        };
+    }
+
+    fn face(&mut self, statement: &mut Statement<'_>) -> Result<(), ObjError> {
+        let mut corners: Vec<(u32, Option<u32>, Option<u32>)> = Vec::new();
+        while let Some(token) = statement.next() {
+            let mut parts = token.text.split('/');
+            let position = resolve(&token, parts.next().unwrap_or(""), self.positions.len(), "Position")?;
+            let uv = match parts.next() {
+                Some("") | None => None,
+                Some(text) => Some(resolve(&token, text, self.uvs.len(), "Texture coordinate")?)
+            };
+            let normal = match parts.next() {
+                Some("") | None => None,
+                Some(text) => Some(resolve(&token, text, self.normals.len(), "Normal")?)
+            };
+            if parts.next().is_some() {
+                return Err(error_at(&token, format!("Expected v, v/vt, v//vn or v/vt/vn, found '{}'", token.text)));
+            }
+            corners.push((position, uv, normal));
+        }
+        if corners.len() < 3 {
+            return Err(statement.error_at_end(format!("A face needs at least 3 vertices, found {}", corners.len())));
+        }
+
+        let points: Vec<glm::Vec3> = corners.iter().map(|c| self.positions[c.0 as usize]).collect();
+        let face_normal = polygon_normal(&points);
+        let face = self.faces;
+        self.faces += 1;
+
+        let object = &mut self.current;
+        let mut vertices = Vec::with_capacity(corners.len());
+        for &(position, uv, normal) in &corners {
+            let key = match normal {
+                Some(n) => NormalKey::Given(n),
+                None if self.smoothing_group > 0 => {
+                    let sum = object.smooth_normals.entry((position, self.smoothing_group)).or_insert(glm::vec3(0.0, 0.0, 0.0));
+                    *sum = *sum + face_normal;
+                    NormalKey::Smooth(self.smoothing_group)
+                }
+                None => NormalKey::Flat(face)
+            };
+            let data = &mut object.data;
+            let vertex = *object.vertices.entry((position, uv, key)).or_insert_with(|| {
+                data.positions.push(self.positions[position as usize]);
+                data.uvs.push(uv.map_or(glm::vec2(0.0, 0.0), |i| self.uvs[i as usize]));
+                data.normals.push(match key {
+                    NormalKey::Given(n) => self.normals[n as usize],
+                    NormalKey::Flat(_) => normalize_or_up(face_normal),
+                    // Filled in once every face of the group is known.
+                    NormalKey::Smooth(group) => {
+                        object.smooth_vertices.push(((data.positions.len() - 1) as u32, (position, group)));
+                        glm::vec3(0.0, 0.0, 0.0)
+                    }
+                });
+                (data.positions.len() - 1) as u32
+            });
+            object.has_uvs |= uv.is_some();
+            vertices.push(vertex);
+        }
+
+        if object.indices.len() <= self.material {
+            object.indices.resize(self.material + 1, Vec::new());
+        }
+        let indices = &mut object.indices[self.material];
+        for [a, b, c] in triangulate(&points, face_normal) {
+            indices.extend_from_slice(&[vertices[a], vertices[b], vertices[c]]);
+        }
+        Ok(())
+    }
+
+    fn statement(&mut self, statement: &mut Statement<'_>) -> Result<(), ObjError> {
+        match statement.keyword() {
+            "v" => {
+                let position = statement.vec3("a coordinate")?;
+                self.positions.push(position);
+                // An optional w, or the r g b of the vertex color extension; both are ignored.
+                statement.next = statement.tokens.len();
+            }
+            "vt" => {
+                let u = statement.float("a texture coordinate")?;
+                let v = statement.optional_float("a texture coordinate")?.unwrap_or(0.0);
+                statement.optional_float("a texture coordinate")?;
+                self.uvs.push(glm::vec2(u, v));
+            }
+            "vn" => {
+                let normal = statement.vec3("a normal component")?;
+                self.normals.push(normal);
+            }
+            "f" => self.face(statement)?,
+            "o" => {
+                let name = statement.rest("an object name")?;
+                self.seen_object = true;
+                self.start_object(name);
+            }
+            "g" => {
+                let name = if statement.peek().is_some() { statement.rest("a group name")? } else { String::new() };
+                if !self.seen_object {
+                    self.start_object(name);
+                }
+            }
+            "s" => {
+                let token = statement.next().ok_or_else(|| statement.error_at_end("Expected a smoothing group or 'off'".to_string()))?;
+                self.smoothing_group = match token.text {
+                    "off" => 0,
+                    text => text.parse().map_err(|_| error_at(&token, format!("Expected a smoothing group or 'off', found '{}'", text)))?
+                };
+            }
+            "usemtl" => {
+                let name = statement.rest("a material name")?;
+                self.use_material(name);
+            }
+            "mtllib" => {
+                while let Some(token) = statement.next() {
+                    self.libraries.push(token.text.to_string());
+                }
+            }
+            // Lines, points, free-form geometry and render attributes aren't meshes.
+            _ => statement.next = statement.tokens.len()
+        }
+        statement.finish()
+    }
+}
+
+/// Parses a model without loading its material libraries; see `load` and
+/// `ObjModel::apply_materials`.
+pub fn parse(source: &str) -> Result<ObjModel, ObjError> {
+    let mut parser = Parser {
+        positions: Vec::new(),
+        uvs: Vec::new(),
+        normals: Vec::new(),
+        objects: Vec::new(),
+        current: ObjectBuilder::new(String::new()),
+        seen_object: false,
+        materials: Vec::new(),
+        material: 0,
+        smoothing_group: 0,
+        faces: 0,
+        libraries: Vec::new()
+    };
+    // Faces before the first `usemtl` use a material without a name.
+    parser.use_material(String::new());
+    for mut statement in statements(source) {
+        parser.statement(&mut statement)?;
+    }
+    parser.start_object(String::new());
+
+    // Drop materials no face ended up using, like the unnamed one in most files.
+    let mut used = vec!(false; parser.materials.len());
+    for submesh in parser.objects.iter().flat_map(|o| &o.mesh.submeshes) {
+        used[submesh.material] = true;
+    }
+    let slots: Vec<usize> = used.iter().scan(0, |next, &u| {
+        let slot = *next;
+        *next += u as usize;
+        Some(slot)
+    }).collect();
+    for submesh in parser.objects.iter_mut().flat_map(|o| &mut o.mesh.submeshes) {
+        submesh.material = slots[submesh.material];
+    }
+
+    return Ok(ObjModel {
+        objects: parser.objects,
+        materials: parser.materials.iter().zip(&used).filter(|(_, &u)| u).map(|(name, _)| ObjMaterial::named(name)).collect(),
+        material_libraries: parser.libraries
+    });
+}
+
+/// Reads a texture statement's file name, skipping the options before it except for the
+/// bump multiplier.
+fn texture_map(statement: &mut Statement<'_>) -> Result<(PathBuf, Option<f32>), ObjError> {
+    let mut bump_multiplier = None;
+    while let Some(token) = statement.peek() {
+        let arguments = match token.text {
+            "-bm" => {
+                statement.next();
+                bump_multiplier = Some(statement.float("a bump multiplier")?);
+                continue;
+            }
+            "-blendu" | "-blendv" | "-boost" | "-texres" | "-clamp" | "-imfchan" | "-type" | "-cc" => 1,
+            "-mm" => 2,
+            // Up to three numbers.
+            "-o" | "-s" | "-t" => 3,
+            text if text.starts_with('-') && text.parse::<f32>().is_err() => {
+                return Err(error_at(&token, format!("Unknown texture option '{}'", text)));
+            }
+            _ => break
+        };
+        statement.next();
+        for i in 0..arguments {
+            let optional = i > 0 && matches!(token.text, "-o" | "-s" | "-t");
+            match statement.peek() {
+                Some(argument) if optional && argument.text.parse::<f32>().is_err() => break,
+                Some(_) => { statement.next(); }
+                None => return Err(statement.error_at_end(format!("Expected a value for {}", token.text)))
+            }
+        }
+    }
+    Ok((PathBuf::from(statement.rest("a texture file name")?), bump_multiplier))
+}
+
+/// Parses the materials of an MTL file.
+pub fn parse_mtl(source: &str) -> Result<Vec<ObjMaterial>, ObjError> {
+    let mut materials: Vec<ObjMaterial> = Vec::new();
+    for mut statement in statements(source) {
+        let keyword = statement.tokens[0];
+        if keyword.text == "newmtl" {
+            let name = statement.rest("a material name")?;
+            materials.push(ObjMaterial::named(&name));
+            continue;
+        }
+        let material = match materials.last_mut() {
+            Some(material) => material,
+            None => return Err(error_at(&keyword, format!("'{}' before the first newmtl", keyword.text)))
+        };
+        match keyword.text {
+            "Ka" => material.ambient = statement.color()?,
+            "Kd" => material.diffuse = statement.color()?,
+            "Ks" => material.specular = statement.color()?,
+            "Ke" => material.emissive = statement.color()?,
+            "Ns" => material.shininess = statement.float("a specular exponent")?,
+            "Ni" => material.optical_density = statement.float("an index of refraction")?,
+            "d" => {
+                if statement.peek().is_some_and(|t| t.text == "-halo") {
+                    statement.next();
+                }
+                material.dissolve = statement.float("a dissolve factor")?;
+            }
+            "Tr" => material.dissolve = 1.0 - statement.float("a transparency")?,
+            "illum" => {
+                let token = statement.next().ok_or_else(|| statement.error_at_end("Expected an illumination model".to_string()))?;
+                material.illumination = token.text.parse().map_err(|_| error_at(&token, format!("Expected an illumination model, found '{}'", token.text)))?;
+            }
+            "Pr" => material.roughness = Some(statement.float("a roughness")?),
+            "Pm" => material.metallic = Some(statement.float("a metallic factor")?),
+            "map_Ka" => material.ambient_map = Some(texture_map(&mut statement)?.0),
+            "map_Kd" => material.diffuse_map = Some(texture_map(&mut statement)?.0),
+            "map_Ks" => material.specular_map = Some(texture_map(&mut statement)?.0),
+            "map_Ns" => material.shininess_map = Some(texture_map(&mut statement)?.0),
+            "map_Ke" => material.emissive_map = Some(texture_map(&mut statement)?.0),
+            "map_d" => material.dissolve_map = Some(texture_map(&mut statement)?.0),
+            "map_bump" | "bump" | "map_Bump" => {
+                let (path, multiplier) = texture_map(&mut statement)?;
+                material.bump_map = Some(path);
+                material.bump_multiplier = multiplier.unwrap_or(1.0);
+            }
+            "norm" | "map_Kn" => material.normal_map = Some(texture_map(&mut statement)?.0),
+            "disp" => material.displacement_map = Some(texture_map(&mut statement)?.0),
+            "map_Pr" => material.roughness_map = Some(texture_map(&mut statement)?.0),
+            "map_Pm" => material.metallic_map = Some(texture_map(&mut statement)?.0),
+            // Transmission filters, reflection maps and the like have no use here.
+            _ => statement.next = statement.tokens.len()
+        }
+        statement.finish()?;
+    }
+    return Ok(materials);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(source: &str) -> (usize, usize, String) {
+        match parse(source) {
+            Err(ObjError::Parse { file: None, line, column, message }) => (line, column, message),
+            other => panic!("expected a parse error, got {:?}", other)
+        }
+    }
+
+    fn mesh(source: &str) -> MeshData {
+        let mut model = parse(source).unwrap();
+        assert_eq!(model.objects.len(), 1);
+        model.objects.remove(0).mesh
+    }
+
+    fn triangle_area(data: &MeshData) -> f32 {
+        data.indices.chunks_exact(3).map(|t| {
+            let [a, b, c] = [t[0] as usize, t[1] as usize, t[2] as usize];
+            glm::cross(data.positions[b] - data.positions[a], data.positions[c] - data.positions[a]).z * 0.5
+        }).sum()
+    }
+
+    #[test]
+    fn errors_point_at_the_token() {
+        assert_eq!(parse_error("v 0 0 0\nv 1  x 0\n"), (2, 6, "Expected a coordinate, found 'x'".to_string()));
+        assert_eq!(parse_error("v 1 2"), (1, 6, "Expected a coordinate".to_string()));
+        assert_eq!(parse_error("v 0 0 0\nf 1 1 4"), (2, 7, "Position 4 doesn't exist; only 1 are defined before this face".to_string()));
+        assert_eq!(parse_error("v 0 0 0\n\tf 0 1 1").1, 4);
+        assert_eq!(parse_error("v 0 0 0\nf 1 \\\n  1").0, 3);
+        assert_eq!(parse_error("s maybe").2, "Expected a smoothing group or 'off', found 'maybe'");
+        let error = parse("vn 0 1").unwrap_err().in_file(Path::new("model.obj"));
+        assert_eq!(error.to_string(), "model.obj:1:7: Expected a normal component");
+    }
+
+    #[test]
+    fn polygons_are_triangulated() {
+        let quad = mesh("v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3 4");
+        assert_eq!((quad.vertex_count(), quad.triangle_count()), (4, 2));
+        assert_eq!(triangle_area(&quad), 1.0);
+
+        // An L: fanning from the first corner would cover the notch.
+        let l = mesh("v 0 0 0\nv 2 0 0\nv 2 1 0\nv 1 1 0\nv 1 2 0\nv 0 2 0\nf 1 2 3 4 5 6");
+        assert_eq!(l.triangle_count(), 4);
+        assert_eq!(triangle_area(&l), 3.0);
+        assert!(l.normals.iter().all(|&n| n == glm::vec3(0.0, 0.0, 1.0)));
+
+        assert!(parse_error("v 0 0 0\nf 1 1").2.starts_with("A face needs at least 3 vertices"));
+    }
+
+    #[test]
+    fn negative_indices_count_back_from_the_last_element() {
+        let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvt 1 0\nvt 0 1\nvn 0 0 1\n";
+        let absolute = mesh(&format!("{}f 1/1/1 2/2/1 3/3/1", source));
+        let relative = mesh(&format!("{}f -3/-3/-1 -2/-2/-1 -1/-1/-1", source));
+        assert_eq!(absolute, relative);
+        assert_eq!(parse_error(&format!("{}f -4 -2 -1", source)).1, 3);
+    }
+
+    #[test]
+    fn smoothing_groups_share_normals() {
+        // Two faces folded along the edge from 1 to 3.
+        let source = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 0 1\n";
+        let faces = "f 1 2 3\nf 1 3 4\n";
+        let flat = mesh(&format!("{}s off\n{}", source, faces));
+        assert_eq!(flat.vertex_count(), 6);
+        assert_eq!(flat.normals[0], glm::vec3(0.0, 0.0, 1.0));
+
+        let smooth = mesh(&format!("{}s 1\n{}", source, faces));
+        assert_eq!(smooth.vertex_count(), 4);
+        let shared = smooth.normals[0];
+        assert!((glm::length(shared) - 1.0).abs() < 1e-5 && shared.z > 0.0 && shared.y < 0.0);
+        assert_eq!(smooth.normals[1], glm::vec3(0.0, 0.0, 1.0));
+
+        // Different groups don't share.
+        assert_eq!(mesh(&format!("{}s 1\nf 1 2 3\ns 2\nf 1 3 4", source)).vertex_count(), 6);
+    }
+
+    #[test]
+    fn repeated_corners_share_vertices() {
+        let source = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nvt 1 0\nvn 0 0 1\n";
+        let shared = mesh(&format!("{}f 1//1 2//1 3//1\nf 1//1 3//1 4//1", source));
+        assert_eq!((shared.vertex_count(), shared.indices.len()), (4, 6));
+        assert!(shared.uvs.is_empty() && shared.tangents.is_empty());
+        // A different uv at the same position is a different vertex.
+        let split = mesh(&format!("{}f 1/1/1 2/2/1 3/2/1\nf 1/2/1 3/2/1 4/1/1", source));
+        assert_eq!(split.vertex_count(), 5);
+        assert_eq!(split.tangents.len(), 5);
+    }
+
+    #[test]
+    fn objects_and_materials() {
+        let model = parse("mtllib a.mtl b.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvt 1 0\nvt 0 1\n\
+            o first\nusemtl red\nf 1/1 2/2 3/3\nusemtl blue\nf 3/3 2/2 1/1\no second\nusemtl red\nf 1 2 3").unwrap();
+        assert_eq!(model.material_libraries, vec!("a.mtl", "b.mtl"));
+        assert_eq!(model.materials.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(), vec!("red", "blue"));
+        let names: Vec<&str> = model.objects.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(names, vec!("first", "second"));
+        assert_eq!(model.objects[0].mesh.submeshes, vec!(Submesh::of(0, 3, 0), Submesh::of(3, 3, 1)));
+        assert!(model.objects[1].mesh.uvs.is_empty());
+
+        let merged = model.merged();
+        assert_eq!((merged.positions.len(), merged.uvs.len(), merged.tangents.len()), (9, 9, 9));
+        assert_eq!(merged.submeshes, vec!(Submesh::of(0, 3, 0), Submesh::of(3, 3, 1), Submesh::of(6, 3, 0)));
+        assert_eq!(merged.interleaved().len(), 9 * 12);
+    }
+
+    #[test]
+    fn mtl_materials() {
+        let materials = parse_mtl("newmtl metal\nKd 0.5\nKs 1 0 0\nd -halo 0.5\nmap_bump -bm 2 -o 0 0 bump map.png\nnewmtl glass\nTr 0.25").unwrap();
+        assert_eq!(materials.len(), 2);
+        assert_eq!(materials[0].diffuse, glm::vec3(0.5, 0.5, 0.5));
+        assert_eq!(materials[0].specular, glm::vec3(1.0, 0.0, 0.0));
+        assert_eq!(materials[0].dissolve, 0.5);
+        assert_eq!(materials[0].bump_map, Some(PathBuf::from("bump map.png")));
+        assert_eq!(materials[0].bump_multiplier, 2.0);
+        assert_eq!(materials[1].dissolve, 0.75);
+        assert!(parse_mtl("Kd 1 1 1").is_err());
+    }
+}
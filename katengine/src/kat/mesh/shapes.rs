@@ -0,0 +1,346 @@
+//! Procedural meshes with normals, uvs and tangents.
+//!
+//! Every shape is centered on the origin with +Y up and counter-clockwise front faces. Uvs
+//! start at the bottom left; wrapped surfaces run u counter-clockwise seen from above,
+//! starting on +X.
+
+use std::collections::HashMap;
+use std::f32::consts::{PI, TAU};
+use crate::kat::mesh::MeshData;
+
+fn vertex(data: &mut MeshData, position: glm::Vec3, normal: glm::Vec3, uv: glm::Vec2) -> u32 {
+    data.positions.push(position);
+    data.normals.push(normal);
+    data.uvs.push(uv);
+    (data.positions.len() - 1) as u32
+}
+
+fn triangle(data: &mut MeshData, a: u32, b: u32, c: u32) {
+    let (pa, pb, pc) = (data.positions[a as usize], data.positions[b as usize], data.positions[c as usize]);
+    // Rows that collapse to a point, like sphere poles and cone tips, give empty triangles.
+    // Relative to the edges, since sin(PI) isn't quite zero.
+    let (e1, e2) = (pb - pa, pc - pa);
+    if glm::length(glm::cross(e1, e2)) > 1e-5 * glm::dot(e1, e1).max(glm::dot(e2, e2)) {
+        data.indices.extend_from_slice(&[a, b, c]);
+    }
+}
+
+/// A grid of `columns` x `rows` quads from a function of the vertex column and row. The
+/// surface faces the way `dp/du x dp/dv` points.
+fn grid<F>(data: &mut MeshData, columns: usize, rows: usize, f: F)
+    where F: Fn(usize, usize) -> (glm::Vec3, glm::Vec3, glm::Vec2) {
+    let first = data.positions.len() as u32;
+    for row in 0..=rows {
+        for column in 0..=columns {
+            let (position, normal, uv) = f(column, row);
+            vertex(data, position, normal, uv);
+        }
+    }
+    let width = (columns + 1) as u32;
+    for row in 0..rows as u32 {
+        for column in 0..columns as u32 {
+            let a = first + row * width + column;
+            let (b, c, d) = (a + 1, a + width + 1, a + width);
+            triangle(data, a, b, c);
+            triangle(data, a, c, d);
+        }
+    }
+}
+
+/// A flat rectangle centered on `center`, spanning `u_axis` and `v_axis` (half extents).
+fn face(data: &mut MeshData, center: glm::Vec3, u_axis: glm::Vec3, v_axis: glm::Vec3, columns: usize, rows: usize) {
+    let normal = glm::normalize(glm::cross(u_axis, v_axis));
+    grid(data, columns, rows, |column, row| {
+        let (u, v) = (column as f32 / columns as f32, row as f32 / rows as f32);
+        (center + u_axis * (u * 2.0 - 1.0) + v_axis * (v * 2.0 - 1.0), normal, glm::vec2(u, v))
+    });
+}
+
+/// A disk in the XZ plane at height `y`, facing +Y or, if `up` is false, -Y.
+fn disk(data: &mut MeshData, y: f32, radius: f32, segments: usize, up: bool) {
+    let normal = glm::vec3(0.0, if up { 1.0 } else { -1.0 }, 0.0);
+    let center = vertex(data, glm::vec3(0.0, y, 0.0), normal, glm::vec2(0.5, 0.5));
+    let first = data.positions.len() as u32;
+    for i in 0..=segments {
+        let theta = i as f32 / segments as f32 * TAU;
+        let (x, z) = (theta.cos(), -theta.sin());
+        // Seen from the side the disk faces, u runs along +X and v along -Z on top, +Z below.
+        let uv = glm::vec2(0.5 + x * 0.5, 0.5 + if up { -z } else { z } * 0.5);
+        vertex(data, glm::vec3(x * radius, y, z * radius), normal, uv);
+    }
+    for i in 0..segments as u32 {
+        if up {
+            triangle(data, center, first + i, first + i + 1);
+        } else {
+            triangle(data, center, first + i + 1, first + i);
+        }
+    }
+}
+
+/// The unit direction at `theta` around +Y from +X and `phi` from the -Y pole.
+fn direction(theta: f32, phi: f32) -> glm::Vec3 {
+    glm::vec3(phi.sin() * theta.cos(), -phi.cos(), -phi.sin() * theta.sin())
+}
+
+fn check(what: &str, count: usize, min: usize) {
+    if count < min {
+        panic!("{} must be at least {}, got {}", what, min, count);
+    }
+}
+
+/// A `width` x `height` rectangle in the XY plane, facing +Z.
+pub fn quad(width: f32, height: f32) -> MeshData {
+    let mut data = MeshData::new();
+    face(&mut data, glm::vec3(0.0, 0.0, 0.0), glm::vec3(width * 0.5, 0.0, 0.0), glm::vec3(0.0, height * 0.5, 0.0), 1, 1);
+    data.compute_tangents();
+    return data;
+}
+
+/// A `width` x `depth` rectangle in the XZ plane facing +Y, split into a grid of
+/// `columns` x `rows` quads, e.g. for terrain.
+pub fn plane(width: f32, depth: f32, columns: usize, rows: usize) -> MeshData {
+    check("Plane columns", columns, 1);
+    check("Plane rows", rows, 1);
+    let mut data = MeshData::new();
+    face(&mut data, glm::vec3(0.0, 0.0, 0.0), glm::vec3(width * 0.5, 0.0, 0.0), glm::vec3(0.0, 0.0, -depth * 0.5), columns, rows);
+    data.compute_tangents();
+    return data;
+}
+
+/// A cube with edges of `size`, each face with its own vertices and the full uv square.
+pub fn cube(size: f32) -> MeshData {
+    let h = size * 0.5;
+    let x = glm::vec3(h, 0.0, 0.0);
+    let y = glm::vec3(0.0, h, 0.0);
+    let z = glm::vec3(0.0, 0.0, h);
+    let mut data = MeshData::new();
+    for (center, u, v) in [(x, -z, y), (-x, z, y), (y, x, -z), (-y, x, z), (z, x, y), (-z, -x, y)] {
+        face(&mut data, center, u, v, 1, 1);
+    }
+    data.compute_tangents();
+    return data;
+}
+
+/// A sphere of `segments` around and `rings` from pole to pole, with the uv square
+/// wrapped around it.
+pub fn uv_sphere(radius: f32, segments: usize, rings: usize) -> MeshData {
+    check("Sphere segments", segments, 3);
+    check("Sphere rings", rings, 2);
+    let mut data = MeshData::new();
+    grid(&mut data, segments, rings, |column, row| {
+        let (u, v) = (column as f32 / segments as f32, row as f32 / rings as f32);
+        let d = direction(u * TAU, v * PI);
+        (d * radius, d, glm::vec2(u, v))
+    });
+    data.compute_tangents();
+    return data;
+}
+
+/// A sphere from an icosahedron whose faces are split in four `subdivisions` times. The
+/// triangles are evenly sized, unlike a uv sphere's.
+pub fn ico_sphere(radius: f32, subdivisions: usize) -> MeshData {
+    let t = (1.0 + 5f32.sqrt()) / 2.0;
+    let mut directions: Vec<glm::Vec3> = [
+        (-1.0, t, 0.0), (1.0, t, 0.0), (-1.0, -t, 0.0), (1.0, -t, 0.0),
+        (0.0, -1.0, t), (0.0, 1.0, t), (0.0, -1.0, -t), (0.0, 1.0, -t),
+        (t, 0.0, -1.0), (t, 0.0, 1.0), (-t, 0.0, -1.0), (-t, 0.0, 1.0)
+    ].iter().map(|&(x, y, z)| glm::normalize(glm::vec3(x, y, z))).collect();
+    let mut faces: Vec<[u32; 3]> = vec!(
+        [0, 11, 5], [0, 5, 1], [0, 1, 7], [0, 7, 10], [0, 10, 11],
+        [1, 5, 9], [5, 11, 4], [11, 10, 2], [10, 7, 6], [7, 1, 8],
+        [3, 9, 4], [3, 4, 2], [3, 2, 6], [3, 6, 8], [3, 8, 9],
+        [4, 9, 5], [2, 4, 11], [6, 2, 10], [8, 6, 7], [9, 8, 1]);
+
+    for _ in 0..subdivisions {
+        let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+        let mut midpoint = |a: u32, b: u32| -> u32 {
+            *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                directions.push(glm::normalize(directions[a as usize] + directions[b as usize]));
+                (directions.len() - 1) as u32
+            })
+        };
+        faces = faces.iter().flat_map(|&[a, b, c]| {
+            let (ab, bc, ca) = (midpoint(a, b), midpoint(b, c), midpoint(c, a));
+            [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+        }).collect();
+    }
+
+    // Vertices are split where a triangle crosses the uv seam, keyed by direction and u.
+    let mut data = MeshData::new();
+    let mut vertices: HashMap<(u32, u32), u32> = HashMap::new();
+    for face in faces {
+        let d = face.map(|i| directions[i as usize]);
+        let mut u = d.map(|d| (-d.z).atan2(d.x).rem_euclid(TAU) / TAU);
+        let max = u.iter().copied().fold(0.0, f32::max);
+        for u in u.iter_mut() {
+            if max - *u > 0.5 {
+                *u += 1.0;
+            }
+        }
+        // At a pole u is arbitrary; take the middle of the other two corners.
+        for i in 0..3 {
+            if d[i].x.abs() < 1e-6 && d[i].z.abs() < 1e-6 {
+                u[i] = (u[(i + 1) % 3] + u[(i + 2) % 3]) * 0.5;
+            }
+        }
+        let corners: Vec<u32> = (0..3).map(|i| {
+            *vertices.entry((face[i], u[i].to_bits())).or_insert_with(|| {
+                let v = (-d[i].y).clamp(-1.0, 1.0).acos() / PI;
+                vertex(&mut data, d[i] * radius, d[i], glm::vec2(u[i], v))
+            })
+        }).collect();
+        triangle(&mut data, corners[0], corners[1], corners[2]);
+    }
+    data.compute_tangents();
+    return data;
+}
+
+/// A closed cylinder along Y. The side wraps the uv square around; the caps are disks
+/// inscribed in it.
+pub fn cylinder(radius: f32, height: f32, segments: usize) -> MeshData {
+    check("Cylinder segments", segments, 3);
+    let mut data = MeshData::new();
+    grid(&mut data, segments, 1, |column, row| {
+        let u = column as f32 / segments as f32;
+        let n = direction(u * TAU, PI / 2.0);
+        (n * radius + glm::vec3(0.0, (row as f32 - 0.5) * height, 0.0), n, glm::vec2(u, row as f32))
+    });
+    disk(&mut data, height * 0.5, radius, segments, true);
+    disk(&mut data, -height * 0.5, radius, segments, false);
+    data.compute_tangents();
+    return data;
+}
+
+/// A closed cone along Y with its tip at the top.
+pub fn cone(radius: f32, height: f32, segments: usize) -> MeshData {
+    check("Cone segments", segments, 3);
+    let mut data = MeshData::new();
+    let slope = (radius * radius + height * height).sqrt();
+    grid(&mut data, segments, 1, |column, row| {
+        let u = column as f32 / segments as f32;
+        // The tip gets one vertex per segment, each with the normal of the middle of its triangle.
+        let theta = if row == 1 { (u - 0.5 / segments as f32) * TAU } else { u * TAU };
+        let out = direction(u * TAU, PI / 2.0);
+        let n = direction(theta, PI / 2.0) * (height / slope) + glm::vec3(0.0, radius / slope, 0.0);
+        let position = out * radius * (1.0 - row as f32) + glm::vec3(0.0, (row as f32 - 0.5) * height, 0.0);
+        (position, n, glm::vec2(u, row as f32))
+    });
+    disk(&mut data, -height * 0.5, radius, segments, false);
+    data.compute_tangents();
+    return data;
+}
+
+/// A torus around Y: a tube of `minor_radius` circling at `major_radius` from the center.
+/// U runs around Y, v around the tube starting on its outside.
+pub fn torus(major_radius: f32, minor_radius: f32, major_segments: usize, minor_segments: usize) -> MeshData {
+    check("Torus major segments", major_segments, 3);
+    check("Torus minor segments", minor_segments, 3);
+    let mut data = MeshData::new();
+    grid(&mut data, major_segments, minor_segments, |column, row| {
+        let (u, v) = (column as f32 / major_segments as f32, row as f32 / minor_segments as f32);
+        let out = direction(u * TAU, PI / 2.0);
+        let phi = v * TAU;
+        let n = out * phi.cos() + glm::vec3(0.0, phi.sin(), 0.0);
+        (out * major_radius + n * minor_radius, n, glm::vec2(u, v))
+    });
+    data.compute_tangents();
+    return data;
+}
+
+/// A cylinder along Y capped with hemispheres, `height` tall including the caps. Each cap
+/// has `rings` rings; v follows the distance along the surface from the bottom.
+pub fn capsule(radius: f32, height: f32, segments: usize, rings: usize) -> MeshData {
+    check("Capsule segments", segments, 3);
+    check("Capsule rings", rings, 1);
+    let half = (height * 0.5 - radius).max(0.0);
+    let length = PI * radius + 2.0 * half;
+    let mut data = MeshData::new();
+    // Rows 0..=rings are the bottom cap, the rest the top cap; the gap between the caps'
+    // equators is the cylinder.
+    grid(&mut data, segments, 2 * rings + 1, |column, row| {
+        let u = column as f32 / segments as f32;
+        let top = row > rings;
+        let step = if top { row - 1 } else { row };
+        let phi = step as f32 / (2 * rings) as f32 * PI;
+        let d = direction(u * TAU, phi);
+        let offset = if top { half } else { -half };
+        let distance = phi * radius + if top { 2.0 * half } else { 0.0 };
+        (d * radius + glm::vec3(0.0, offset, 0.0), d, glm::vec2(u, distance / length))
+    });
+    data.compute_tangents();
+    return data;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    /// Checks the invariants every shape shares and returns its vertex and triangle count.
+    fn check_shape(data: &MeshData) -> (usize, usize) {
+        let count = data.vertex_count();
+        assert_eq!((data.normals.len(), data.uvs.len(), data.tangents.len()), (count, count, count));
+        assert_eq!(data.indices.len() % 3, 0);
+        assert!(data.indices.iter().all(|&i| (i as usize) < count));
+        for (n, t) in data.normals.iter().zip(&data.tangents) {
+            assert!(close(glm::length(*n), 1.0), "normal {:?}", n);
+            let t3 = glm::vec3(t.x, t.y, t.z);
+            assert!(close(glm::length(t3), 1.0) && close(glm::dot(*n, t3), 0.0), "tangent {:?} for normal {:?}", t, n);
+            assert!(t.w == 1.0 || t.w == -1.0);
+        }
+        // Counter-clockwise front faces: each triangle turns the way its normals point.
+        for triangle in data.indices.chunks_exact(3) {
+            let [a, b, c] = [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize];
+            let face = glm::cross(data.positions[b] - data.positions[a], data.positions[c] - data.positions[a]);
+            let normal = data.normals[a] + data.normals[b] + data.normals[c];
+            assert!(glm::dot(face, normal) > 0.0, "triangle {:?} faces away from its normals", triangle);
+        }
+        (count, data.triangle_count())
+    }
+
+    #[test]
+    fn flat_shapes() {
+        let quad = quad(2.0, 1.0);
+        assert_eq!(check_shape(&quad), (4, 2));
+        assert!(quad.normals.iter().all(|&n| n == glm::vec3(0.0, 0.0, 1.0)));
+        assert_eq!(check_shape(&plane(4.0, 4.0, 2, 3)), (12, 12));
+        let cube = cube(2.0);
+        assert_eq!(check_shape(&cube), (24, 12));
+        assert!(cube.positions.iter().zip(&cube.normals).all(|(&p, &n)| close(glm::dot(p, n), 1.0)));
+    }
+
+    #[test]
+    fn spheres() {
+        // The triangles that collapse at the poles are dropped.
+        let sphere = uv_sphere(2.0, 8, 4);
+        assert_eq!(check_shape(&sphere), (45, 48));
+        assert_eq!(check_shape(&ico_sphere(2.0, 0)).1, 20);
+        let ico = ico_sphere(2.0, 2);
+        assert_eq!(check_shape(&ico).1, 320);
+        for data in [sphere, ico] {
+            for (&p, &n) in data.positions.iter().zip(&data.normals) {
+                assert!(close(glm::length(p), 2.0) && close(glm::dot(p * 0.5, n), 1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn round_shapes() {
+        assert_eq!(check_shape(&cylinder(1.0, 2.0, 8)), (38, 32));
+        assert_eq!(check_shape(&cone(1.0, 2.0, 8)), (28, 16));
+        assert_eq!(check_shape(&torus(2.0, 0.5, 8, 6)), (63, 96));
+        let capsule = capsule(1.0, 4.0, 8, 2);
+        assert_eq!(check_shape(&capsule).0, 9 * 6);
+        let aabb = capsule.aabb().unwrap();
+        assert!(close(aabb.min.y, -2.0) && close(aabb.max.y, 2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_few_segments_panics() {
+        uv_sphere(1.0, 2, 4);
+    }
+}
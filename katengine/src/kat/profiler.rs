@@ -0,0 +1,348 @@
+//! GPU timing of named scopes from timestamp queries, with Chrome trace export.
+
+use std::io::Write;
+use std::ops::{Deref, DerefMut};
+use crate::gl;
+use crate::kat::{backend, DebugGroup, ObjectKind};
+use crate::kat::context::{self, ContextToken};
+
+/// Timing of one profiled scope, in nanoseconds of GPU time.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct ScopeTiming {
+    pub name: String,
+    pub depth: usize,
+    pub frame: u64,
+    /// Start of the scope relative to the first frame the profiler resolved.
+    pub start_ns: u64,
+    pub duration_ns: u64
+}
+
+struct PendingScope {
+    name: String,
+    depth: usize,
+    begin_query: usize,
+    end_query: Option<usize>
+}
+
+struct FrameSlot {
+    context: ContextToken,
+    timestamps: Vec<u32>,
+    used: usize,
+    scopes: Vec<PendingScope>,
+    frame: u64,
+    pending: bool
+}
+
+impl FrameSlot {
+    fn new() -> FrameSlot {
+        FrameSlot {
+            context: context::require_current(),
+            timestamps: Vec::new(),
+            used: 0,
+            scopes: Vec::new(),
+            frame: 0,
+            pending: false
+        }
+    }
+
+    fn next_timestamp(&mut self) -> usize {
+        if self.used == self.timestamps.len() {
+            let query = backend::create_query(gl::TIMESTAMP);
+            self.timestamps.push(query);
+            self.context.adopt(ObjectKind::Query, query);
+        }
+
+        unsafe { gl::QueryCounter(self.timestamps[self.used], gl::TIMESTAMP); }
+        self.used += 1;
+        self.used - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        match self.used {
+            0 => true,
+            n => backend::query_available(self.timestamps[n - 1])
+        }
+    }
+
+    fn read(query: u32) -> u64 {
+        backend::query_result(query)
+    }
+}
+
+impl Drop for FrameSlot {
+    fn drop(&mut self) {
+        for query in &self.timestamps {
+            self.context.release(ObjectKind::Query, *query);
+        }
+    }
+}
+
+/// GPU profiler built on timestamp queries. Creating one panics without `GlFeature::TimerQueries`.
+///
+/// Scopes and the frame itself are measured with `TIMESTAMP` queries, because
+/// `TIME_ELAPSED` queries cannot nest; this leaves `QueryTarget::TimeElapsed` queries free
+/// for the application, even inside a profiled frame. Results are read back
+/// several frames later from a ring of query sets, so the CPU never waits on the GPU.
+/// If a frame's results are still not available when its query set comes around again,
+/// the frame is dropped and counted in `dropped_frames`.
+pub struct GpuProfiler {
+    slots: Vec<FrameSlot>,
+    current: usize,
+    frame: u64,
+    in_frame: bool,
+    open: Vec<usize>,
+    groups: Vec<DebugGroup>,
+    epoch: Option<u64>,
+    last_frame: Vec<ScopeTiming>,
+    last_frame_gpu_ns: Option<u64>,
+    capture: Option<Vec<ScopeTiming>>,
+    dropped_frames: u64
+}
+
+impl GpuProfiler {
+    /// A profiler keeping two frames of queries in flight.
+    pub fn new() -> GpuProfiler {
+        Self::with_latency(2)
+    }
+
+    /// A profiler keeping `frames_in_flight` frames of queries before it needs their results.
+    pub fn with_latency(frames_in_flight: usize) -> GpuProfiler {
+        assert!(frames_in_flight > 0, "A profiler needs at least one frame of queries.");
+        assert!(backend::has_timer_queries(), "The GPU profiler needs GlFeature::TimerQueries.");
+
+        GpuProfiler {
+            slots: (0..frames_in_flight).map(|_| FrameSlot::new()).collect(),
+            current: 0,
+            frame: 0,
+            in_frame: false,
+            open: Vec::new(),
+            groups: Vec::new(),
+            epoch: None,
+            last_frame: Vec::new(),
+            last_frame_gpu_ns: None,
+            capture: None,
+            dropped_frames: 0
+        }
+    }
+
+    pub fn begin_frame(&mut self) {
+        assert!(!self.in_frame, "begin_frame called twice without end_frame.");
+
+        if self.slots[self.current].pending {
+            if self.slots[self.current].is_ready() {
+                self.resolve(self.current);
+            } else {
+                self.slots[self.current].pending = false;
+                self.dropped_frames += 1;
+            }
+        }
+
+        let slot = &mut self.slots[self.current];
+        slot.used = 0;
+        slot.scopes.clear();
+        slot.frame = self.frame;
+        slot.next_timestamp();
+
+        self.in_frame = true;
+    }
+
+    pub fn end_frame(&mut self) {
+        assert!(self.in_frame, "end_frame called without begin_frame.");
+
+        while !self.open.is_empty() {
+            self.end_scope();
+        }
+
+        self.slots[self.current].next_timestamp();
+        self.slots[self.current].pending = true;
+        self.in_frame = false;
+        self.current = (self.current + 1) % self.slots.len();
+        self.frame += 1;
+
+        // Pick up anything that finished early, oldest frame first.
+        for i in 0..self.slots.len() {
+            let idx = (self.current + i) % self.slots.len();
+            if self.slots[idx].pending && self.slots[idx].is_ready() {
+                self.resolve(idx);
+            }
+        }
+    }
+
+    /// Starts a named scope; scopes may nest. Also pushes a debug group of the same name.
+    pub fn begin_scope(&mut self, name: &str) {
+        assert!(self.in_frame, "Profiler scopes must be inside begin_frame/end_frame.");
+
+        self.groups.push(DebugGroup::push(name));
+        let slot = &mut self.slots[self.current];
+        let begin_query = slot.next_timestamp();
+        slot.scopes.push(PendingScope {
+            name: name.to_string(),
+            depth: self.open.len(),
+            begin_query,
+            end_query: None
+        });
+        self.open.push(slot.scopes.len() - 1);
+    }
+
+    pub fn end_scope(&mut self) {
+        let scope = self.open.pop().expect("end_scope called without an open scope.");
+        let slot = &mut self.slots[self.current];
+        let end_query = slot.next_timestamp();
+        slot.scopes[scope].end_query = Some(end_query);
+        self.groups.pop();
+    }
+
+    /// Starts a scope that ends when the returned guard is dropped. The guard dereferences
+    /// to the profiler, so nested scopes are opened through it.
+    pub fn scope(&mut self, name: &str) -> ProfileScope<'_> {
+        self.begin_scope(name);
+        ProfileScope { profiler: self }
+    }
+
+    /// Scope timings of the most recently resolved frame.
+    pub fn last_frame(&self) -> &[ScopeTiming] {
+        &self.last_frame
+    }
+
+    /// GPU time between the start and end of the most recently resolved frame.
+    pub fn last_frame_gpu_ns(&self) -> Option<u64> {
+        self.last_frame_gpu_ns
+    }
+
+    pub fn dropped_frames(&self) -> u64 {
+        self.dropped_frames
+    }
+
+    /// Starts keeping every resolved scope so it can be written out as a trace.
+    pub fn start_capture(&mut self) {
+        self.capture = Some(Vec::new());
+    }
+
+    /// Stops capturing and returns everything resolved since `start_capture`.
+    pub fn stop_capture(&mut self) -> Vec<ScopeTiming> {
+        self.capture.take().unwrap_or_default()
+    }
+
+    fn resolve(&mut self, idx: usize) {
+        let slot = &mut self.slots[idx];
+        slot.pending = false;
+
+        let timestamps: Vec<u64> = slot.timestamps[..slot.used].iter().map(|q| FrameSlot::read(*q)).collect();
+        // The first and last timestamps bracket the frame.
+        self.last_frame_gpu_ns = Some(timestamps[timestamps.len() - 1].saturating_sub(timestamps[0]));
+
+        if self.epoch.is_none() {
+            self.epoch = timestamps.first().copied();
+        }
+        let epoch = self.epoch.unwrap_or(0);
+
+        self.last_frame = slot.scopes.iter().filter_map(|s| {
+            let begin = timestamps[s.begin_query];
+            let end = timestamps[s.end_query?];
+            Some(ScopeTiming {
+                name: s.name.clone(),
+                depth: s.depth,
+                frame: slot.frame,
+                start_ns: begin.saturating_sub(epoch),
+                duration_ns: end.saturating_sub(begin)
+            })
+        }).collect();
+
+        if let Some(capture) = &mut self.capture {
+            capture.extend(self.last_frame.iter().cloned());
+        }
+    }
+}
+
+impl Default for GpuProfiler {
+    fn default() -> Self {
+        GpuProfiler::new()
+    }
+}
+
+pub struct ProfileScope<'a> {
+    profiler: &'a mut GpuProfiler
+}
+
+impl Deref for ProfileScope<'_> {
+    type Target = GpuProfiler;
+
+    fn deref(&self) -> &GpuProfiler {
+        self.profiler
+    }
+}
+
+impl DerefMut for ProfileScope<'_> {
+    fn deref_mut(&mut self) -> &mut GpuProfiler {
+        self.profiler
+    }
+}
+
+impl Drop for ProfileScope<'_> {
+    fn drop(&mut self) {
+        self.profiler.end_scope();
+    }
+}
+
+fn write_json_string<W: Write>(out: &mut W, s: &str) -> std::io::Result<()> {
+    write!(out, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(out, "\\\"")?,
+            '\\' => write!(out, "\\\\")?,
+            '\n' => write!(out, "\\n")?,
+            '\r' => write!(out, "\\r")?,
+            '\t' => write!(out, "\\t")?,
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => write!(out, "{}", c)?
+        }
+    }
+    write!(out, "\"")
+}
+
+/// Writes scope timings in the Chrome trace event format (`chrome://tracing`, Perfetto).
+pub fn write_chrome_trace<W: Write>(out: &mut W, timings: &[ScopeTiming]) -> std::io::Result<()> {
+    write!(out, "{{\"traceEvents\":[")?;
+    for (i, t) in timings.iter().enumerate() {
+        if i > 0 {
+            write!(out, ",")?;
+        }
+        write!(out, "{{\"name\":")?;
+        write_json_string(out, &t.name)?;
+        write!(out, ",\"cat\":\"gpu\",\"ph\":\"X\",\"ts\":{:.3},\"dur\":{:.3},\"pid\":0,\"tid\":0,\"args\":{{\"frame\":{}}}}}",
+               t.start_ns as f64 / 1000.0, t.duration_ns as f64 / 1000.0, t.frame)?;
+    }
+    write!(out, "],\"displayTimeUnit\":\"ms\"}}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chrome_trace_escapes_names() {
+        let timings = vec!(
+            ScopeTiming { name: "shadow \"pass\"\\1\n\t\u{1}é".to_string(), depth: 0, frame: 3, start_ns: 1500, duration_ns: 2000 },
+            ScopeTiming { name: "post".to_string(), depth: 1, frame: 3, start_ns: 4000, duration_ns: 250 }
+        );
+        let mut out = Vec::new();
+        write_chrome_trace(&mut out, &timings).unwrap();
+
+        let trace: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["name"], timings[0].name.as_str());
+        assert_eq!(events[0]["ts"], 1.5);
+        assert_eq!(events[0]["dur"], 2.0);
+        assert_eq!(events[0]["args"]["frame"], 3);
+        assert_eq!(events[1]["name"], "post");
+    }
+
+    #[test]
+    fn chrome_trace_without_timings() {
+        let mut out = Vec::new();
+        write_chrome_trace(&mut out, &[]).unwrap();
+        let trace: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(trace["traceEvents"].as_array().unwrap().len(), 0);
+    }
+}
@@ -0,0 +1,122 @@
+//! Offscreen framebuffers whose attachments follow a window's size.
+
+use crate::kat::{Attachment, Framebuffer, Texture, TextureFormat};
+
+/// The attachments of a render target and its size relative to the window's framebuffer.
+#[derive(Clone,PartialEq,Debug)]
+pub struct RenderTargetDesc {
+    colors: Vec<TextureFormat>,
+    depth: Option<TextureFormat>,
+    scale: f32
+}
+
+impl RenderTargetDesc {
+    pub fn new() -> RenderTargetDesc {
+        RenderTargetDesc { colors: Vec::new(), depth: None, scale: 1.0 }
+    }
+
+    /// Adds a color attachment; the n-th call is written by fragment output n.
+    pub fn color(mut self, format: TextureFormat) -> RenderTargetDesc {
+        if format.is_depth() {
+            panic!("{:?} is a depth format and can't be a color attachment", format);
+        }
+        self.colors.push(format);
+        self
+    }
+
+    pub fn depth(mut self, format: TextureFormat) -> RenderTargetDesc {
+        if !format.is_depth() {
+            panic!("{:?} is not a depth format", format);
+        }
+        self.depth = Some(format);
+        self
+    }
+
+    /// Size as a fraction of the window's framebuffer, e.g. 0.5 for half-resolution effects.
+    pub fn scale(mut self, scale: f32) -> RenderTargetDesc {
+        self.scale = scale;
+        self
+    }
+
+    /// The size of the attachments for a window framebuffer of `width` x `height`.
+    pub fn size_for(&self, width: i32, height: i32) -> (i32, i32) {
+        (((width as f32 * self.scale) as i32).max(1), ((height as f32 * self.scale) as i32).max(1))
+    }
+}
+
+impl Default for RenderTargetDesc {
+    fn default() -> Self {
+        RenderTargetDesc::new()
+    }
+}
+
+/// A framebuffer together with the textures attached to it.
+pub struct RenderTarget {
+    desc: RenderTargetDesc,
+    // Declared before the textures so it is deleted first.
+    framebuffer: Framebuffer,
+    colors: Vec<Texture>,
+    depth: Option<Texture>,
+    width: i32,
+    height: i32
+}
+
+impl RenderTarget {
+    /// Creates the target with attachments of exactly `width` x `height`; the scale only
+    /// applies when a window resizes it.
+    pub fn create(desc: RenderTargetDesc, width: i32, height: i32) -> RenderTarget {
+        let mut target = RenderTarget {
+            desc,
+            framebuffer: Framebuffer::create(),
+            colors: Vec::new(),
+            depth: None,
+            width: 0,
+            height: 0
+        };
+        target.framebuffer.set_draw_buffers(target.desc.colors.len() as u32);
+        target.resize(width, height);
+        return target;
+    }
+
+    /// Reallocates every attachment at the new size. Their previous contents are lost.
+    pub fn resize(&mut self, width: i32, height: i32) {
+        if (width, height) == (self.width, self.height) {
+            return;
+        }
+        self.colors = self.desc.colors.iter().map(|&f| Texture::create_2d(f, width, height)).collect();
+        self.depth = self.desc.depth.map(|f| Texture::create_2d(f, width, height));
+        for (i, texture) in self.colors.iter().enumerate() {
+            self.framebuffer.attach_texture(Attachment::Color(i as u32), texture);
+        }
+        if let Some(depth) = &self.depth {
+            let attachment = if depth.format().has_stencil() { Attachment::DepthStencil } else { Attachment::Depth };
+            self.framebuffer.attach_texture(attachment, depth);
+        }
+        let status = self.framebuffer.status();
+        if status != crate::gl::FRAMEBUFFER_COMPLETE {
+            panic!("Render target {:?} is incomplete at {}x{} (status 0x{:x})", self.desc, width, height, status);
+        }
+        self.width = width;
+        self.height = height;
+    }
+
+    pub fn desc(&self) -> &RenderTargetDesc {
+        &self.desc
+    }
+
+    pub fn size(&self) -> (i32, i32) {
+        (self.width, self.height)
+    }
+
+    pub fn framebuffer(&mut self) -> &mut Framebuffer {
+        &mut self.framebuffer
+    }
+
+    pub fn color(&self, index: usize) -> &Texture {
+        &self.colors[index]
+    }
+
+    pub fn depth(&self) -> Option<&Texture> {
+        self.depth.as_ref()
+    }
+}
@@ -0,0 +1,132 @@
+//! Window size tracking: the viewport, resize callbacks and window-sized render targets.
+
+use crate::gl;
+use crate::kat::context;
+use crate::kat::{Event, RenderTarget, RenderTargetDesc, Window};
+
+/// A window's size in its two coordinate systems. On HiDPI displays the framebuffer has
+/// more pixels than the window has screen coordinates, by the content scale.
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct WindowMetrics {
+    /// Size in screen coordinates, as used for window placement and cursor positions.
+    pub size: (i32, i32),
+    /// Size in pixels, as used for the viewport.
+    pub framebuffer_size: (i32, i32),
+    pub content_scale: (f32, f32)
+}
+
+impl WindowMetrics {
+    pub(crate) fn query(win: &glfw::Window) -> WindowMetrics {
+        WindowMetrics {
+            size: win.get_size(),
+            framebuffer_size: win.get_framebuffer_size(),
+            content_scale: win.get_content_scale()
+        }
+    }
+
+    /// Whether the window is minimized, in which case the framebuffer has no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.framebuffer_size.0 <= 0 || self.framebuffer_size.1 <= 0
+    }
+}
+
+/// Identifies a render target registered with a window.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct RenderTargetId(usize);
+
+pub(crate) type ResizeCallback = Box<dyn FnMut(&WindowMetrics)>;
+
+impl Window {
+    pub fn metrics(&self) -> WindowMetrics {
+        self.metrics
+    }
+
+    pub fn size(&self) -> (i32, i32) {
+        self.metrics.size
+    }
+
+    pub fn framebuffer_size(&self) -> (i32, i32) {
+        self.metrics.framebuffer_size
+    }
+
+    pub fn content_scale(&self) -> (f32, f32) {
+        self.metrics.content_scale
+    }
+
+    /// Whether the viewport follows the framebuffer size. On by default.
+    pub fn set_auto_viewport(&mut self, auto: bool) {
+        self.auto_viewport = auto;
+    }
+
+    /// Calls `f` after the framebuffer size or content scale changes, with this window's
+    /// context current and registered render targets already resized.
+    pub fn on_resize<F>(&mut self, f: F) where F: FnMut(&WindowMetrics) + 'static {
+        self.resize_callbacks.push(Box::new(f));
+    }
+
+    /// Creates a render target sized to the framebuffer and keeps it that size.
+    pub fn add_render_target(&mut self, desc: RenderTargetDesc) -> RenderTargetId {
+        self.context.check("Window render target");
+        let (width, height) = desc.size_for(self.metrics.framebuffer_size.0, self.metrics.framebuffer_size.1);
+        let target = RenderTarget::create(desc, width, height);
+        match self.render_targets.iter().position(|t| t.is_none()) {
+            Some(i) => {
+                self.render_targets[i] = Some(target);
+                RenderTargetId(i)
+            }
+            None => {
+                self.render_targets.push(Some(target));
+                RenderTargetId(self.render_targets.len() - 1)
+            }
+        }
+    }
+
+    pub fn render_target(&mut self, id: RenderTargetId) -> &mut RenderTarget {
+        self.render_targets.get_mut(id.0).and_then(|t| t.as_mut())
+            .unwrap_or_else(|| panic!("{:?} was removed or belongs to another window", id))
+    }
+
+    /// Stops tracking the target and hands it back.
+    pub fn remove_render_target(&mut self, id: RenderTargetId) -> RenderTarget {
+        self.render_targets.get_mut(id.0).and_then(|t| t.take())
+            .unwrap_or_else(|| panic!("{:?} was removed or belongs to another window", id))
+    }
+
+    /// Updates the tracked sizes from a window event.
+    pub(crate) fn track_size(&mut self, event: &Event) {
+        match event {
+            Event::Resized { width, height } => self.metrics.size = (*width, *height),
+            Event::FramebufferResized { width, height } => {
+                self.metrics.framebuffer_size = (*width, *height);
+                self.resize_pending = true;
+            }
+            Event::ContentScale { x, y } => {
+                self.metrics.content_scale = (*x, *y);
+                self.resize_pending = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Applies a pending resize if this window's context is current; otherwise it waits
+    /// for `make_current`. Nothing is resized while the window is minimized.
+    pub(crate) fn apply_resize(&mut self) {
+        if !self.resize_pending || context::current_context() != Some(self.context.id()) || self.metrics.is_empty() {
+            return;
+        }
+        self.resize_pending = false;
+
+        let (width, height) = self.metrics.framebuffer_size;
+        if self.auto_viewport {
+            unsafe { gl::Viewport(0, 0, width, height); }
+        }
+        for target in self.render_targets.iter_mut().flatten() {
+            let (w, h) = target.desc().size_for(width, height);
+            target.resize(w, h);
+        }
+        let metrics = self.metrics;
+        for callback in &mut self.resize_callbacks {
+            callback(&metrics);
+        }
+    }
+}
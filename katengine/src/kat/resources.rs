@@ -0,0 +1,439 @@
+//! Engine-owned GL resources addressed by generational handles.
+//!
+//! A `Handle<T>` is a slot index plus the generation of the slot when the resource was
+//! inserted. Removing a resource bumps the generation, so old handles stop resolving
+//! instead of silently reaching whatever reuses the slot.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use crate::kat::{Buffer, Engine, Mesh, Shader, Texture};
+
+/// A `Copy` reference to a resource in a `Pool`.
+pub struct Handle<T> {
+    index: u32,
+    generation: u32,
+    _phantom: PhantomData<fn() -> T>
+}
+
+impl<T> Handle<T> {
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+// Derives would require `T` itself to be `Copy`, `Eq` and so on.
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.generation == other.generation
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> Hash for Handle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = std::any::type_name::<T>();
+        let name = name.rsplit("::").next().unwrap_or(name);
+        write!(f, "Handle<{}>({}v{})", name, self.index, self.generation)
+    }
+}
+
+struct Slot<T> {
+    generation: u32,
+    refs: u32,
+    value: Option<T>
+}
+
+/// Resources of one type. Each has a reference count that starts at one: `retain` and
+/// `release` share it between owners, `remove` frees it regardless.
+pub struct Pool<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+    len: usize
+}
+
+impl<T> Pool<T> {
+    pub fn new() -> Pool<T> {
+        Pool { slots: Vec::new(), free: Vec::new(), len: 0 }
+    }
+
+    pub fn insert(&mut self, value: T) -> Handle<T> {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot { generation: 0, refs: 0, value: None });
+                (self.slots.len() - 1) as u32
+            }
+        };
+        let slot = &mut self.slots[index as usize];
+        slot.refs = 1;
+        slot.value = Some(value);
+        self.len += 1;
+        Handle { index, generation: slot.generation, _phantom: PhantomData }
+    }
+
+    fn slot(&self, handle: Handle<T>) -> Option<&Slot<T>> {
+        self.slots.get(handle.index as usize)
+            .filter(|s| s.generation == handle.generation && s.value.is_some())
+    }
+
+    fn slot_mut(&mut self, handle: Handle<T>) -> Option<&mut Slot<T>> {
+        self.slots.get_mut(handle.index as usize)
+            .filter(|s| s.generation == handle.generation && s.value.is_some())
+    }
+
+    /// `None` once the resource was removed.
+    pub fn get(&self, handle: Handle<T>) -> Option<&T> {
+        self.slot(handle).and_then(|s| s.value.as_ref())
+    }
+
+    pub fn get_mut(&mut self, handle: Handle<T>) -> Option<&mut T> {
+        self.slot_mut(handle).and_then(|s| s.value.as_mut())
+    }
+
+    pub fn contains(&self, handle: Handle<T>) -> bool {
+        self.slot(handle).is_some()
+    }
+
+    /// Zero once the resource was removed.
+    pub fn ref_count(&self, handle: Handle<T>) -> u32 {
+        self.slot(handle).map_or(0, |s| s.refs)
+    }
+
+    /// Adds an owner, which must later `release` the handle.
+    pub fn retain(&mut self, handle: Handle<T>) {
+        match self.slot_mut(handle) {
+            Some(slot) => slot.refs += 1,
+            None => panic!("Retained {:?} after it was removed", handle)
+        }
+    }
+
+    /// Drops one reference and hands the resource back when it was the last.
+    pub fn release(&mut self, handle: Handle<T>) -> Option<T> {
+        let slot = match self.slot_mut(handle) {
+            Some(slot) => slot,
+            None => panic!("Released {:?} after it was removed", handle)
+        };
+        slot.refs -= 1;
+        if slot.refs > 0 {
+            return None;
+        }
+        self.take(handle.index)
+    }
+
+    /// Removes the resource no matter how many references are left. Returns `None` if it
+    /// was already removed.
+    pub fn remove(&mut self, handle: Handle<T>) -> Option<T> {
+        self.slot(handle)?;
+        self.take(handle.index)
+    }
+
+    fn take(&mut self, index: u32) -> Option<T> {
+        let slot = &mut self.slots[index as usize];
+        let value = slot.value.take();
+        slot.refs = 0;
+        // A slot whose generation would wrap is retired, so no old handle can match it again.
+        if let Some(generation) = slot.generation.checked_add(1) {
+            slot.generation = generation;
+            self.free.push(index);
+        }
+        self.len -= 1;
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Handle<T>, &T)> {
+        self.slots.iter().enumerate().filter_map(|(i, s)| {
+            s.value.as_ref().map(|v| (Handle { index: i as u32, generation: s.generation, _phantom: PhantomData }, v))
+        })
+    }
+
+    /// Removes every resource; all existing handles become stale.
+    pub fn clear(&mut self) {
+        for index in 0..self.slots.len() as u32 {
+            if self.slots[index as usize].value.is_some() {
+                self.take(index);
+            }
+        }
+    }
+}
+
+impl<T> Default for Pool<T> {
+    fn default() -> Self {
+        Pool::new()
+    }
+}
+
+impl<T> Index<Handle<T>> for Pool<T> {
+    type Output = T;
+
+    fn index(&self, handle: Handle<T>) -> &T {
+        self.get(handle).unwrap_or_else(|| panic!("Used {:?} after it was removed", handle))
+    }
+}
+
+impl<T> IndexMut<Handle<T>> for Pool<T> {
+    fn index_mut(&mut self, handle: Handle<T>) -> &mut T {
+        self.get_mut(handle).unwrap_or_else(|| panic!("Used {:?} after it was removed", handle))
+    }
+}
+
+/// A type `Resources` keeps a pool of.
+pub trait Resource: Sized {
+    fn pool(resources: &Resources) -> &Pool<Self>;
+    fn pool_mut(resources: &mut Resources) -> &mut Pool<Self>;
+}
+
+/// The engine's resource pools, one per resource type.
+#[derive(Default)]
+pub struct Resources {
+    vertex_buffers: Pool<Buffer<f32>>,
+    index_buffers: Pool<Buffer<u32>>,
+    shaders: Pool<Shader>,
+    textures: Pool<Texture>,
+    meshes: Pool<Mesh>
+}
+
+macro_rules! resource {
+    ($t:ty, $field:ident) => {
+        impl Resource for $t {
+            fn pool(resources: &Resources) -> &Pool<Self> {
+                &resources.$field
+            }
+
+            fn pool_mut(resources: &mut Resources) -> &mut Pool<Self> {
+                &mut resources.$field
+            }
+        }
+    };
+}
+
+resource!(Buffer<f32>, vertex_buffers);
+resource!(Buffer<u32>, index_buffers);
+resource!(Shader, shaders);
+resource!(Texture, textures);
+resource!(Mesh, meshes);
+
+impl Resources {
+    pub fn new() -> Resources {
+        Resources::default()
+    }
+
+    pub fn pool<T: Resource>(&self) -> &Pool<T> {
+        T::pool(self)
+    }
+
+    pub fn pool_mut<T: Resource>(&mut self) -> &mut Pool<T> {
+        T::pool_mut(self)
+    }
+
+    pub fn insert<T: Resource>(&mut self, value: T) -> Handle<T> {
+        T::pool_mut(self).insert(value)
+    }
+
+    pub fn get<T: Resource>(&self, handle: Handle<T>) -> Option<&T> {
+        T::pool(self).get(handle)
+    }
+
+    pub fn get_mut<T: Resource>(&mut self, handle: Handle<T>) -> Option<&mut T> {
+        T::pool_mut(self).get_mut(handle)
+    }
+
+    pub fn contains<T: Resource>(&self, handle: Handle<T>) -> bool {
+        T::pool(self).contains(handle)
+    }
+
+    pub fn retain<T: Resource>(&mut self, handle: Handle<T>) {
+        T::pool_mut(self).retain(handle)
+    }
+
+    /// Drops one reference; the resource is dropped (and its GL object deleted) with the last.
+    pub fn release<T: Resource>(&mut self, handle: Handle<T>) {
+        T::pool_mut(self).release(handle);
+    }
+
+    pub fn remove<T: Resource>(&mut self, handle: Handle<T>) -> Option<T> {
+        T::pool_mut(self).remove(handle)
+    }
+
+    /// Drops every resource. Must happen while a context of their share group is current,
+    /// so before the last window goes away.
+    pub fn clear(&mut self) {
+        self.vertex_buffers.clear();
+        self.index_buffers.clear();
+        self.shaders.clear();
+        self.textures.clear();
+        self.meshes.clear();
+    }
+}
+
+impl<T: Resource> Index<Handle<T>> for Resources {
+    type Output = T;
+
+    fn index(&self, handle: Handle<T>) -> &T {
+        &T::pool(self)[handle]
+    }
+}
+
+impl<T: Resource> IndexMut<Handle<T>> for Resources {
+    fn index_mut(&mut self, handle: Handle<T>) -> &mut T {
+        &mut T::pool_mut(self)[handle]
+    }
+}
+
+impl Engine {
+    pub fn resources(&self) -> &Resources {
+        &self.resources
+    }
+
+    pub fn resources_mut(&mut self) -> &mut Resources {
+        &mut self.resources
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removed_handles_are_stale() {
+        let mut pool = Pool::new();
+        let handle = pool.insert(1u32);
+        assert_eq!((pool.get(handle), pool.contains(handle), pool.len()), (Some(&1), true, 1));
+        assert_eq!(pool.remove(handle), Some(1));
+        assert!(pool.get(handle).is_none() && pool.get_mut(handle).is_none() && !pool.contains(handle));
+        assert_eq!((pool.ref_count(handle), pool.len()), (0, 0));
+        assert_eq!(pool.remove(handle), None);
+    }
+
+    #[test]
+    fn reused_slots_get_a_new_generation() {
+        let mut pool = Pool::new();
+        let old = pool.insert(1u32);
+        pool.remove(old);
+        let new = pool.insert(2);
+        assert_eq!((new.index(), new.generation()), (old.index(), old.generation() + 1));
+        assert_ne!(old, new);
+        assert_eq!((pool.get(old), pool[new]), (None, 2));
+        assert_eq!(pool.remove(old), None);
+        assert!(pool.contains(new));
+    }
+
+    #[test]
+    fn slots_are_retired_before_their_generation_wraps() {
+        let mut pool = Pool::new();
+        let handle = pool.insert(1u32);
+        pool.slots[0].generation = u32::MAX;
+        let last = Handle { index: 0, generation: u32::MAX, _phantom: PhantomData };
+        assert_eq!(pool.remove(last), Some(1));
+        assert_eq!(pool.insert(2).index(), 1);
+        assert!(!pool.contains(handle) && !pool.contains(last));
+    }
+
+    #[test]
+    fn release_returns_the_value_on_the_last_reference() {
+        let mut pool = Pool::new();
+        let handle = pool.insert(1u32);
+        pool.retain(handle);
+        pool.retain(handle);
+        assert_eq!(pool.ref_count(handle), 3);
+        assert_eq!(pool.release(handle), None);
+        assert_eq!(pool.release(handle), None);
+        assert_eq!(pool.ref_count(handle), 1);
+        assert_eq!(pool.release(handle), Some(1));
+        assert!(!pool.contains(handle));
+    }
+
+    #[test]
+    fn remove_ignores_references() {
+        let mut pool = Pool::new();
+        let handle = pool.insert(1u32);
+        pool.retain(handle);
+        assert_eq!(pool.remove(handle), Some(1));
+        assert_eq!(pool.ref_count(handle), 0);
+    }
+
+    #[test]
+    fn clear_makes_every_handle_stale() {
+        let mut pool = Pool::new();
+        let handles: Vec<Handle<u32>> = (0..4).map(|i| pool.insert(i)).collect();
+        pool.remove(handles[1]);
+        pool.clear();
+        assert!(pool.is_empty() && pool.iter().next().is_none());
+        assert!(handles.iter().all(|&h| !pool.contains(h)));
+        let reused = pool.insert(9);
+        assert!(handles.iter().all(|&h| h != reused && !pool.contains(h)));
+    }
+
+    #[test]
+    fn iter_yields_live_handles() {
+        let mut pool = Pool::new();
+        let (a, b, c) = (pool.insert(1u32), pool.insert(2), pool.insert(3));
+        pool.remove(b);
+        assert_eq!(pool.iter().collect::<Vec<_>>(), vec!((a, &1), (c, &3)));
+    }
+
+    fn stale() -> (Pool<u32>, Handle<u32>) {
+        let mut pool = Pool::new();
+        let handle = pool.insert(1);
+        pool.remove(handle);
+        pool.insert(2);
+        (pool, handle)
+    }
+
+    #[test]
+    #[should_panic(expected = "Retained")]
+    fn retaining_a_stale_handle_panics() {
+        let (mut pool, handle) = stale();
+        pool.retain(handle);
+    }
+
+    #[test]
+    #[should_panic(expected = "Released")]
+    fn releasing_a_stale_handle_panics() {
+        let (mut pool, handle) = stale();
+        pool.release(handle);
+    }
+
+    #[test]
+    #[should_panic(expected = "after it was removed")]
+    fn indexing_with_a_stale_handle_panics() {
+        let (pool, handle) = stale();
+        let _ = pool[handle];
+    }
+
+    #[test]
+    #[should_panic(expected = "after it was removed")]
+    fn mutably_indexing_with_a_stale_handle_panics() {
+        let (mut pool, handle) = stale();
+        pool[handle] = 3;
+    }
+}
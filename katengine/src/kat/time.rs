@@ -0,0 +1,349 @@
+//! Frame timing: delta time, elapsed time, frame counts and frame time statistics.
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::time::Instant;
+use glfw::Glfw;
+
+/// A source of time in seconds. Only differences between readings matter.
+pub trait Clock: Debug {
+    fn now(&self) -> f64;
+}
+
+/// GLFW's timer, the default for `Engine`.
+#[derive(Debug)]
+pub struct GlfwClock {
+    glfw: Glfw
+}
+
+impl GlfwClock {
+    pub fn new(glfw: &Glfw) -> GlfwClock {
+        GlfwClock { glfw: glfw.clone() }
+    }
+}
+
+impl Clock for GlfwClock {
+    fn now(&self) -> f64 {
+        self.glfw.get_time()
+    }
+}
+
+/// `std::time::Instant`, for timing without GLFW.
+#[derive(Clone,Copy,Debug)]
+pub struct MonotonicClock {
+    start: Instant
+}
+
+impl MonotonicClock {
+    pub fn new() -> MonotonicClock {
+        MonotonicClock { start: Instant::now() }
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        MonotonicClock::new()
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+}
+
+/// A clock that only moves when told to. Clones share the same time, so keep one to
+/// drive a `Time` that owns another.
+#[derive(Clone,Debug,Default)]
+pub struct ManualClock {
+    now: Rc<Cell<f64>>
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, seconds: f64) {
+        self.now.set(self.now.get() + seconds);
+    }
+
+    pub fn set(&self, seconds: f64) {
+        self.now.set(seconds);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> f64 {
+        self.now.get()
+    }
+}
+
+/// Weight of the newest frame in the smoothed frame time.
+const FPS_SMOOTHING: f64 = 0.1;
+
+/// Frame timing, advanced once per frame by `tick`.
+///
+/// Scaled values (`delta`, `elapsed`) follow the time scale and stop while paused; the
+/// `real_` variants and the statistics always follow the clock.
+#[derive(Debug)]
+pub struct Time {
+    clock: Box<dyn Clock>,
+    last_tick: Option<f64>,
+    frame: u64,
+    real_delta: f64,
+    real_elapsed: f64,
+    delta: f64,
+    elapsed: f64,
+    time_scale: f64,
+    paused: bool,
+    smoothed_frame_time: f64,
+    frame_times: VecDeque<f64>,
+    window: usize
+}
+
+impl Time {
+    pub fn new(clock: Box<dyn Clock>) -> Time {
+        Time {
+            clock,
+            last_tick: None,
+            frame: 0,
+            real_delta: 0.0,
+            real_elapsed: 0.0,
+            delta: 0.0,
+            elapsed: 0.0,
+            time_scale: 1.0,
+            paused: false,
+            smoothed_frame_time: 0.0,
+            frame_times: VecDeque::new(),
+            window: 240
+        }
+    }
+
+    /// Sets how many recent frames the percentiles are taken over. Defaults to 240.
+    pub fn with_window(mut self, frames: usize) -> Time {
+        self.set_window(frames);
+        self
+    }
+
+    pub fn set_window(&mut self, frames: usize) {
+        self.window = frames.max(1);
+        while self.frame_times.len() > self.window {
+            self.frame_times.pop_front();
+        }
+    }
+
+    /// Swaps the clock, e.g. for a `ManualClock` in tests. The next tick measures from the
+    /// new clock's current reading, so the switch doesn't show up as a long frame.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+        self.last_tick = None;
+    }
+
+    /// Starts a new frame, measuring the time since the previous tick. The first tick has a
+    /// delta of zero.
+    pub fn tick(&mut self) {
+        let now = self.clock.now();
+        let real_delta = self.last_tick.map_or(0.0, |last| (now - last).max(0.0));
+        self.last_tick = Some(now);
+        if self.frame > 0 {
+            self.record(real_delta);
+        }
+        self.frame += 1;
+
+        self.real_delta = real_delta;
+        self.real_elapsed += real_delta;
+        self.delta = if self.paused { 0.0 } else { real_delta * self.time_scale };
+        self.elapsed += self.delta;
+    }
+
+    fn record(&mut self, frame_time: f64) {
+        self.smoothed_frame_time = if self.frame_times.is_empty() {
+            frame_time
+        } else {
+            self.smoothed_frame_time + (frame_time - self.smoothed_frame_time) * FPS_SMOOTHING
+        };
+        if self.frame_times.len() == self.window {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(frame_time);
+    }
+
+    /// Scaled seconds since the previous frame; zero while paused.
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    pub fn real_delta(&self) -> f64 {
+        self.real_delta
+    }
+
+    /// Scaled seconds since the first tick.
+    pub fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    pub fn real_elapsed(&self) -> f64 {
+        self.real_elapsed
+    }
+
+    /// How many ticks have happened, counting the current frame.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    pub fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Speeds up (> 1) or slows down (< 1) scaled time.
+    pub fn set_time_scale(&mut self, scale: f64) {
+        if !scale.is_finite() || scale < 0.0 {
+            panic!("Time scale must be finite and not negative, got {}", scale);
+        }
+        self.time_scale = scale;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Frames per second over an exponential moving average of recent frame times.
+    pub fn fps(&self) -> f64 {
+        if self.smoothed_frame_time > 0.0 { 1.0 / self.smoothed_frame_time } else { 0.0 }
+    }
+
+    /// The `percentile`th (0 to 100) frame time in seconds over the recent window, e.g. 99
+    /// for the 1% lows. Zero before the second frame.
+    pub fn frame_time_percentile(&self, percentile: f64) -> f64 {
+        if self.frame_times.is_empty() {
+            return 0.0;
+        }
+        let mut sorted: Vec<f64> = self.frame_times.iter().copied().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64).round() as usize;
+        return sorted[rank];
+    }
+
+    /// Mean frame time in seconds over the recent window.
+    pub fn average_frame_time(&self) -> f64 {
+        if self.frame_times.is_empty() {
+            return 0.0;
+        }
+        return self.frame_times.iter().sum::<f64>() / self.frame_times.len() as f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manual() -> (ManualClock, Time) {
+        let clock = ManualClock::new();
+        let time = Time::new(Box::new(clock.clone()));
+        (clock, time)
+    }
+
+    #[test]
+    fn tick_measures_from_the_previous_tick() {
+        let (clock, mut time) = manual();
+        clock.set(10.0);
+        time.tick();
+        assert_eq!((time.frame(), time.delta(), time.elapsed()), (1, 0.0, 0.0));
+        clock.advance(0.25);
+        time.tick();
+        clock.advance(0.5);
+        time.tick();
+        assert_eq!((time.frame(), time.delta(), time.elapsed()), (3, 0.5, 0.75));
+        assert_eq!((time.real_delta(), time.real_elapsed()), (0.5, 0.75));
+    }
+
+    #[test]
+    fn clock_going_backwards_is_a_zero_delta() {
+        let (clock, mut time) = manual();
+        clock.set(1.0);
+        time.tick();
+        clock.set(0.5);
+        time.tick();
+        assert_eq!(time.real_delta(), 0.0);
+    }
+
+    #[test]
+    fn scale_and_pause_only_affect_scaled_time() {
+        let (clock, mut time) = manual();
+        time.tick();
+        time.set_time_scale(2.0);
+        clock.advance(0.25);
+        time.tick();
+        assert_eq!((time.delta(), time.real_delta()), (0.5, 0.25));
+
+        time.set_paused(true);
+        clock.advance(0.25);
+        time.tick();
+        assert_eq!((time.delta(), time.real_delta()), (0.0, 0.25));
+        assert_eq!((time.elapsed(), time.real_elapsed()), (0.5, 0.5));
+
+        time.set_paused(false);
+        time.set_time_scale(0.0);
+        clock.advance(0.25);
+        time.tick();
+        assert_eq!((time.delta(), time.elapsed()), (0.0, 0.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_time_scale_panics() {
+        manual().1.set_time_scale(-1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_time_scale_panics() {
+        manual().1.set_time_scale(f64::NAN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn infinite_time_scale_panics() {
+        manual().1.set_time_scale(f64::INFINITY);
+    }
+
+    #[test]
+    fn statistics_over_the_window() {
+        let (clock, mut time) = manual();
+        time = time.with_window(4);
+        assert_eq!((time.fps(), time.frame_time_percentile(50.0), time.average_frame_time()), (0.0, 0.0, 0.0));
+        time.tick();
+        for frame_time in [8.0, 1.0, 2.0, 4.0, 3.0] {
+            clock.advance(frame_time);
+            time.tick();
+        }
+        // The 8 second frame has fallen out of the window.
+        assert_eq!(time.frame_time_percentile(0.0), 1.0);
+        assert_eq!(time.frame_time_percentile(100.0), 4.0);
+        assert_eq!(time.frame_time_percentile(50.0), 3.0);
+        assert_eq!(time.frame_time_percentile(250.0), 4.0);
+        assert_eq!(time.average_frame_time(), 2.5);
+
+        time.set_window(2);
+        assert_eq!(time.average_frame_time(), 3.5);
+    }
+
+    #[test]
+    fn fps_follows_the_first_frame_then_smooths() {
+        let (clock, mut time) = manual();
+        time.tick();
+        clock.advance(0.5);
+        time.tick();
+        assert_eq!(time.fps(), 2.0);
+        clock.advance(1.5);
+        time.tick();
+        assert!((time.fps() - 1.0 / 0.6).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,731 @@
+//! Window creation: monitors and video modes, context requests, and switching between
+//! windowed, fullscreen and borderless display modes.
+
+use std::fmt;
+use glfw::WindowMode;
+use crate::gl;
+use crate::gl::types::*;
+use crate::kat::context::{self, GroupToken};
+use crate::kat::{install_debug_output, load_gl, DebugOutput, Engine, GlFeature, GlInfo, GlVersion, Input, Rect,
+                 UnsupportedContext, Window, WindowMetrics, MINIMUM_ES_VERSION, MINIMUM_VERSION};
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct VideoMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_rate: u32,
+    pub red_bits: u32,
+    pub green_bits: u32,
+    pub blue_bits: u32
+}
+
+impl VideoMode {
+    fn from_glfw(mode: &glfw::VidMode) -> VideoMode {
+        VideoMode {
+            width: mode.width,
+            height: mode.height,
+            refresh_rate: mode.refresh_rate,
+            red_bits: mode.red_bits,
+            green_bits: mode.green_bits,
+            blue_bits: mode.blue_bits
+        }
+    }
+}
+
+/// Picks the mode from `modes` closest to `requested`: matching resolution first, then
+/// the nearest refresh rate, then the deepest color.
+pub fn closest_video_mode(modes: &[VideoMode], requested: &VideoMode) -> Option<VideoMode> {
+    modes.iter().copied().min_by_key(|m| {
+        let size = (m.width as i64 - requested.width as i64).abs() + (m.height as i64 - requested.height as i64).abs();
+        let refresh = (m.refresh_rate as i64 - requested.refresh_rate as i64).abs();
+        let depth = -((m.red_bits + m.green_bits + m.blue_bits) as i64);
+        (size, refresh, depth)
+    })
+}
+
+/// A connected monitor, as reported by `Engine::monitors`.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct MonitorInfo {
+    /// Position in the connected monitor list, for `MonitorSelection::Index`.
+    pub index: usize,
+    pub name: String,
+    pub primary: bool,
+    pub position: (i32, i32),
+    pub current_mode: Option<VideoMode>,
+    pub video_modes: Vec<VideoMode>
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum MonitorSelection {
+    Primary,
+    Index(usize)
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum DisplayMode {
+    Windowed,
+    /// Exclusive fullscreen. Without a video mode the monitor's current mode is kept.
+    Fullscreen(MonitorSelection, Option<VideoMode>),
+    /// An undecorated window covering the monitor, without a video mode change.
+    Borderless(MonitorSelection)
+}
+
+fn with_monitor<T, F>(glfw: &mut glfw::Glfw, selection: MonitorSelection, f: F) -> Option<T>
+    where F: FnOnce(&mut glfw::Glfw, &glfw::Monitor) -> T {
+    match selection {
+        MonitorSelection::Primary => glfw.with_primary_monitor(|g, m| m.map(|m| f(g, m))),
+        MonitorSelection::Index(i) => glfw.with_connected_monitors(|g, ms| ms.get(i).map(|m| f(g, m)))
+    }
+}
+
+/// Resolves the mode to switch `monitor` to for `requested`.
+fn resolve_video_mode(monitor: &glfw::Monitor, requested: Option<VideoMode>) -> Option<VideoMode> {
+    let current = monitor.get_video_mode().map(|m| VideoMode::from_glfw(&m));
+    match requested {
+        None => current,
+        Some(r) => {
+            let modes: Vec<VideoMode> = monitor.get_video_modes().iter().map(VideoMode::from_glfw).collect();
+            closest_video_mode(&modes, &r).or(current)
+        }
+    }
+}
+
+impl Engine {
+    pub fn monitors(&mut self) -> Vec<MonitorInfo> {
+        let primary = self.glfw_ctx.with_primary_monitor(|_, m| m.map(|m| m.get_pos()));
+
+        self.glfw_ctx.with_connected_monitors(|_, monitors| {
+            monitors.iter().enumerate().map(|(index, m)| MonitorInfo {
+                index,
+                name: m.get_name().unwrap_or_default(),
+                // Monitors cannot overlap, so the position identifies the primary one.
+                primary: Some(m.get_pos()) == primary,
+                position: m.get_pos(),
+                current_mode: m.get_video_mode().map(|v| VideoMode::from_glfw(&v)),
+                video_modes: m.get_video_modes().iter().map(VideoMode::from_glfw).collect()
+            }).collect()
+        })
+    }
+}
+
+/// Which flavour of OpenGL a window's context provides.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum ClientApi {
+    OpenGl,
+    /// OpenGL ES 3.0 or newer, the WebGL 2 feature level.
+    OpenGlEs
+}
+
+/// The library that creates the context.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum ContextCreation {
+    Native,
+    Egl
+}
+
+/// Desktop OpenGL profile. Core and compatibility profiles need version 3.2 or newer.
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum GlProfile {
+    Any,
+    Core,
+    Compatibility
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum SwapInterval {
+    /// Swap as soon as the frame is done, without waiting for vertical blank.
+    Immediate,
+    /// Wait for this many vertical blanks per swap; 1 is regular vsync.
+    Sync(u32),
+    /// Vsync that tears instead of waiting when a frame is late, where supported.
+    Adaptive
+}
+
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub enum WindowError {
+    /// The builder settings contradict each other.
+    InvalidRequest(String),
+    /// The selected monitor is not connected.
+    NoSuchMonitor(MonitorSelection),
+    /// GLFW could not create a window with the requested context.
+    CreationFailed,
+    Unsupported(UnsupportedContext),
+    /// The context was created but does not provide what was asked for.
+    ContextMismatch(Vec<String>)
+}
+
+impl fmt::Display for WindowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowError::InvalidRequest(message) => write!(f, "Invalid window request: {}", message),
+            WindowError::NoSuchMonitor(m) => write!(f, "Monitor {:?} is not connected.", m),
+            WindowError::CreationFailed => write!(f, "Failed to create window."),
+            WindowError::Unsupported(e) => write!(f, "{}", e),
+            WindowError::ContextMismatch(problems) => {
+                write!(f, "The created context does not match the request:")?;
+                for p in problems {
+                    write!(f, "\n  - {}", p)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for WindowError {}
+
+/// Describes a window and its context. Unset options are left to GLFW's defaults.
+#[derive(Clone,Debug)]
+pub struct WindowBuilder {
+    width: u32,
+    height: u32,
+    title: String,
+    client_api: ClientApi,
+    context_creation: ContextCreation,
+    version: Option<GlVersion>,
+    profile: GlProfile,
+    forward_compat: bool,
+    debug_context: bool,
+    debug_output: Option<DebugOutput>,
+    samples: Option<u32>,
+    srgb: bool,
+    depth_bits: Option<u32>,
+    stencil_bits: Option<u32>,
+    swap_interval: SwapInterval,
+    display_mode: DisplayMode,
+    resizable: bool,
+    decorated: bool,
+    floating: bool,
+    transparent: bool,
+    position: Option<(i32, i32)>,
+    min_size: Option<(u32, u32)>,
+    max_size: Option<(u32, u32)>,
+    aspect_ratio: Option<(u32, u32)>
+}
+
+impl WindowBuilder {
+    pub fn new(width: u32, height: u32, title: &str) -> WindowBuilder {
+        WindowBuilder {
+            width,
+            height,
+            title: title.to_string(),
+            client_api: ClientApi::OpenGl,
+            context_creation: ContextCreation::Native,
+            version: None,
+            profile: GlProfile::Any,
+            forward_compat: false,
+            debug_context: false,
+            debug_output: None,
+            samples: None,
+            srgb: false,
+            depth_bits: None,
+            stencil_bits: None,
+            swap_interval: SwapInterval::Sync(1),
+            display_mode: DisplayMode::Windowed,
+            resizable: true,
+            decorated: true,
+            floating: false,
+            transparent: false,
+            position: None,
+            min_size: None,
+            max_size: None,
+            aspect_ratio: None
+        }
+    }
+
+    pub fn display_mode(mut self, mode: DisplayMode) -> WindowBuilder {
+        self.display_mode = mode;
+        self
+    }
+
+    pub fn fullscreen(self, monitor: MonitorSelection, video_mode: Option<VideoMode>) -> WindowBuilder {
+        self.display_mode(DisplayMode::Fullscreen(monitor, video_mode))
+    }
+
+    pub fn borderless(self, monitor: MonitorSelection) -> WindowBuilder {
+        self.display_mode(DisplayMode::Borderless(monitor))
+    }
+
+    pub fn resizable(mut self, resizable: bool) -> WindowBuilder {
+        self.resizable = resizable;
+        self
+    }
+
+    pub fn decorated(mut self, decorated: bool) -> WindowBuilder {
+        self.decorated = decorated;
+        self
+    }
+
+    /// Keeps the window above other windows.
+    pub fn floating(mut self, floating: bool) -> WindowBuilder {
+        self.floating = floating;
+        self
+    }
+
+    /// A framebuffer whose alpha channel blends the window with what is behind it.
+    pub fn transparent(mut self, transparent: bool) -> WindowBuilder {
+        self.transparent = transparent;
+        self
+    }
+
+    /// Initial position of the window's content area, in screen coordinates.
+    pub fn position(mut self, x: i32, y: i32) -> WindowBuilder {
+        self.position = Some((x, y));
+        self
+    }
+
+    pub fn min_size(mut self, width: u32, height: u32) -> WindowBuilder {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    pub fn max_size(mut self, width: u32, height: u32) -> WindowBuilder {
+        self.max_size = Some((width, height));
+        self
+    }
+
+    /// Constrains resizing to `numerator:denominator`.
+    pub fn aspect_ratio(mut self, numerator: u32, denominator: u32) -> WindowBuilder {
+        self.aspect_ratio = Some((numerator, denominator));
+        self
+    }
+
+    pub fn client_api(mut self, api: ClientApi, creation: ContextCreation) -> WindowBuilder {
+        self.client_api = api;
+        self.context_creation = creation;
+        self
+    }
+
+    /// The minimum context version; drivers may return a newer compatible one.
+    pub fn gl_version(mut self, major: u32, minor: u32) -> WindowBuilder {
+        self.version = Some(GlVersion::of(major, minor));
+        self
+    }
+
+    pub fn profile(mut self, profile: GlProfile) -> WindowBuilder {
+        self.profile = profile;
+        self
+    }
+
+    pub fn forward_compatible(mut self, forward_compat: bool) -> WindowBuilder {
+        self.forward_compat = forward_compat;
+        self
+    }
+
+    pub fn debug_context(mut self, debug: bool) -> WindowBuilder {
+        self.debug_context = debug;
+        self
+    }
+
+    /// Requests a debug context and forwards its messages to the `log` crate.
+    pub fn debug_output(mut self, options: DebugOutput) -> WindowBuilder {
+        self.debug_context = true;
+        self.debug_output = Some(options);
+        self
+    }
+
+    /// Multisampled default framebuffer with this many samples; 0 disables MSAA.
+    pub fn samples(mut self, samples: u32) -> WindowBuilder {
+        self.samples = Some(samples);
+        self
+    }
+
+    /// An sRGB-capable default framebuffer, with sRGB writes enabled.
+    pub fn srgb(mut self, srgb: bool) -> WindowBuilder {
+        self.srgb = srgb;
+        self
+    }
+
+    pub fn depth_bits(mut self, bits: u32) -> WindowBuilder {
+        self.depth_bits = Some(bits);
+        self
+    }
+
+    pub fn stencil_bits(mut self, bits: u32) -> WindowBuilder {
+        self.stencil_bits = Some(bits);
+        self
+    }
+
+    pub fn vsync(mut self, vsync: bool) -> WindowBuilder {
+        self.swap_interval = if vsync { SwapInterval::Sync(1) } else { SwapInterval::Immediate };
+        self
+    }
+
+    pub fn swap_interval(mut self, interval: SwapInterval) -> WindowBuilder {
+        self.swap_interval = interval;
+        self
+    }
+
+    fn check_request(&self) -> Result<(), WindowError> {
+        if self.client_api == ClientApi::OpenGl {
+            let version = self.version.unwrap_or_default();
+            if self.profile != GlProfile::Any && version < GlVersion::of(3, 2) {
+                return Err(WindowError::InvalidRequest("Core and compatibility profiles need gl_version 3.2 or newer.".to_string()));
+            }
+            if self.forward_compat && version < GlVersion::of(3, 0) {
+                return Err(WindowError::InvalidRequest("Forward compatible contexts need gl_version 3.0 or newer.".to_string()));
+            }
+        } else if self.profile != GlProfile::Any || self.forward_compat {
+            return Err(WindowError::InvalidRequest("Profiles and forward compatibility only apply to desktop OpenGL.".to_string()));
+        }
+
+        if let Some((n, d)) = self.aspect_ratio {
+            if n == 0 || d == 0 {
+                return Err(WindowError::InvalidRequest("Aspect ratio terms must be non-zero.".to_string()));
+            }
+        }
+
+        if let (Some(min), Some(max)) = (self.min_size, self.max_size) {
+            if min.0 > max.0 || min.1 > max.1 {
+                return Err(WindowError::InvalidRequest("Minimum size is larger than the maximum size.".to_string()));
+            }
+        }
+
+        if let Some(v) = self.version {
+            let minimum = if self.client_api == ClientApi::OpenGlEs { MINIMUM_ES_VERSION } else { MINIMUM_VERSION };
+            if v < minimum {
+                return Err(WindowError::InvalidRequest(format!("The engine needs at least version {}, not {}.", minimum, v)));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_hints(&self, glfw: &mut glfw::Glfw) {
+        glfw.default_window_hints();
+
+        glfw.window_hint(glfw::WindowHint::ContextCreationApi(match self.context_creation {
+            ContextCreation::Native => glfw::ContextCreationApi::Native,
+            ContextCreation::Egl => glfw::ContextCreationApi::Egl
+        }));
+
+        match self.client_api {
+            ClientApi::OpenGl => {
+                glfw.window_hint(glfw::WindowHint::ClientApi(glfw::ClientApiHint::OpenGl));
+                if let Some(v) = self.version {
+                    glfw.window_hint(glfw::WindowHint::ContextVersion(v.major, v.minor));
+                }
+                glfw.window_hint(glfw::WindowHint::OpenGlProfile(match self.profile {
+                    GlProfile::Any => glfw::OpenGlProfileHint::Any,
+                    GlProfile::Core => glfw::OpenGlProfileHint::Core,
+                    GlProfile::Compatibility => glfw::OpenGlProfileHint::Compat
+                }));
+                glfw.window_hint(glfw::WindowHint::OpenGlForwardCompat(self.forward_compat));
+            }
+            ClientApi::OpenGlEs => {
+                let v = self.version.unwrap_or(MINIMUM_ES_VERSION);
+                glfw.window_hint(glfw::WindowHint::ClientApi(glfw::ClientApiHint::OpenGlEs));
+                glfw.window_hint(glfw::WindowHint::ContextVersion(v.major, v.minor));
+            }
+        }
+
+        glfw.window_hint(glfw::WindowHint::OpenGlDebugContext(self.debug_context));
+        glfw.window_hint(glfw::WindowHint::Samples(self.samples.filter(|s| *s > 0)));
+        glfw.window_hint(glfw::WindowHint::SRgbCapable(self.srgb));
+        if self.depth_bits.is_some() {
+            glfw.window_hint(glfw::WindowHint::DepthBits(self.depth_bits));
+        }
+        if self.stencil_bits.is_some() {
+            glfw.window_hint(glfw::WindowHint::StencilBits(self.stencil_bits));
+        }
+
+        glfw.window_hint(glfw::WindowHint::Resizable(self.resizable));
+        glfw.window_hint(glfw::WindowHint::Decorated(self.decorated && !matches!(self.display_mode, DisplayMode::Borderless(_))));
+        glfw.window_hint(glfw::WindowHint::Floating(self.floating));
+        glfw.window_hint(glfw::WindowHint::TransparentFramebuffer(self.transparent));
+        // Positioned windows are shown after they are moved so they don't flash at the default spot.
+        glfw.window_hint(glfw::WindowHint::Visible(self.position.is_none()));
+    }
+
+    fn create(&self, glfw: &mut glfw::Glfw, share: Option<&glfw::Window>)
+        -> Result<(glfw::Window, std::sync::mpsc::Receiver<(f64, glfw::WindowEvent)>), WindowError> {
+        let create = |g: &mut glfw::Glfw, width: u32, height: u32, mode: WindowMode<'_>| match share {
+            Some(s) => s.create_shared(width, height, &self.title, mode),
+            None => g.create_window(width, height, &self.title, mode)
+        };
+
+        match self.display_mode {
+            DisplayMode::Windowed => {
+                create(glfw, self.width, self.height, WindowMode::Windowed)
+                    .ok_or(WindowError::CreationFailed)
+            }
+            DisplayMode::Fullscreen(monitor, video_mode) => {
+                with_monitor(glfw, monitor, |g, m| {
+                    let mode = resolve_video_mode(m, video_mode);
+                    let (width, height) = mode.map_or((self.width, self.height), |v| (v.width, v.height));
+                    if let Some(v) = mode {
+                        g.window_hint(glfw::WindowHint::RefreshRate(Some(v.refresh_rate)));
+                        g.window_hint(glfw::WindowHint::RedBits(Some(v.red_bits)));
+                        g.window_hint(glfw::WindowHint::GreenBits(Some(v.green_bits)));
+                        g.window_hint(glfw::WindowHint::BlueBits(Some(v.blue_bits)));
+                    }
+                    create(g, width, height, WindowMode::FullScreen(m))
+                }).ok_or(WindowError::NoSuchMonitor(monitor))?.ok_or(WindowError::CreationFailed)
+            }
+            DisplayMode::Borderless(monitor) => {
+                let (created, position) = with_monitor(glfw, monitor, |g, m| {
+                    let (width, height) = m.get_video_mode().map_or((self.width, self.height), |v| (v.width, v.height));
+                    (create(g, width, height, WindowMode::Windowed), m.get_pos())
+                }).ok_or(WindowError::NoSuchMonitor(monitor))?;
+                let (mut win, events) = created.ok_or(WindowError::CreationFailed)?;
+                win.set_pos(position.0, position.1);
+                Ok((win, events))
+            }
+        }
+    }
+
+    /// Compares the current context against the request, listing everything that falls short.
+    fn validate(&self, info: &GlInfo) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if let Some(v) = self.version {
+            if info.version < v {
+                problems.push(format!("requested version {} but got {}", v, info.version));
+            }
+        }
+
+        if self.client_api == ClientApi::OpenGlEs && !info.es {
+            problems.push("requested OpenGL ES but got desktop OpenGL".to_string());
+        }
+
+        if !info.es {
+            let flags = get_integer(gl::CONTEXT_FLAGS) as GLenum;
+
+            if self.profile != GlProfile::Any && info.is_version_at_least(3, 2) {
+                let mask = get_integer(gl::CONTEXT_PROFILE_MASK) as GLenum;
+                let core = mask & gl::CONTEXT_CORE_PROFILE_BIT != 0;
+                if (self.profile == GlProfile::Core) != core {
+                    problems.push(format!("requested a {:?} profile but got a {} profile", self.profile,
+                                          if core { "core" } else { "compatibility" }));
+                }
+            }
+
+            if self.forward_compat && flags & gl::CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT == 0 {
+                problems.push("requested a forward compatible context".to_string());
+            }
+
+            if self.debug_context && flags & gl::CONTEXT_FLAG_DEBUG_BIT == 0 {
+                problems.push("requested a debug context".to_string());
+            }
+        } else if self.debug_context && !info.supports(GlFeature::DebugOutput) {
+            problems.push("requested a debug context but the context has no debug output".to_string());
+        }
+
+        if let Some(requested) = self.samples.filter(|s| *s > 0) {
+            let samples = get_integer(gl::SAMPLES) as u32;
+            if samples < requested {
+                problems.push(format!("requested {} samples but got {}", requested, samples));
+            }
+        }
+
+        let back = if info.es { gl::BACK } else { gl::BACK_LEFT };
+
+        if self.srgb && default_attachment_parameter(back, gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING) as GLenum != gl::SRGB {
+            problems.push("requested an sRGB framebuffer".to_string());
+        }
+
+        if let Some(requested) = self.depth_bits.filter(|b| *b > 0) {
+            let bits = default_attachment_parameter(gl::DEPTH, gl::FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE) as u32;
+            if bits < requested {
+                problems.push(format!("requested {} depth bits but got {}", requested, bits));
+            }
+        }
+
+        if let Some(requested) = self.stencil_bits.filter(|b| *b > 0) {
+            let bits = default_attachment_parameter(gl::STENCIL, gl::FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE) as u32;
+            if bits < requested {
+                problems.push(format!("requested {} stencil bits but got {}", requested, bits));
+            }
+        }
+
+        problems
+    }
+
+    pub fn build(self, engine: &mut Engine) -> Result<Window, WindowError> {
+        self.build_in(engine, None, None)
+    }
+
+    /// Creates the window with a context sharing buffers, shaders and textures with `share`.
+    pub fn build_shared(self, engine: &mut Engine, share: &Window) -> Result<Window, WindowError> {
+        self.build_in(engine, Some(&share.win), Some(share.context.group()))
+    }
+
+    fn build_in(self, engine: &mut Engine, share: Option<&glfw::Window>, share_group: Option<GroupToken>) -> Result<Window, WindowError> {
+        self.check_request()?;
+        self.apply_hints(&mut engine.glfw_ctx);
+
+        let (mut win, events) = self.create(&mut engine.glfw_ctx, share)?;
+
+        win.set_all_polling(true);
+
+        if self.min_size.is_some() || self.max_size.is_some() {
+            win.set_size_limits(self.min_size.map(|s| s.0), self.min_size.map(|s| s.1),
+                                self.max_size.map(|s| s.0), self.max_size.map(|s| s.1));
+        }
+        if let Some((n, d)) = self.aspect_ratio {
+            win.set_aspect_ratio(n, d);
+        }
+        if let Some((x, y)) = self.position {
+            win.set_pos(x, y);
+            win.show();
+        }
+
+        let metrics = WindowMetrics::query(&win);
+        let mut window = Window {
+            render_targets: Vec::new(), win, events, debug_output: None, info: GlInfo::default(), windowed_rect: None,
+            windowed_size: (self.width, self.height), decorated: self.decorated,
+            borderless: matches!(self.display_mode, DisplayMode::Borderless(_)),
+            context: context::new_context(share_group), input: Input::new(), gamepad: None,
+            metrics, auto_viewport: true, resize_pending: false, resize_callbacks: Vec::new()
+        };
+
+        load_gl(&mut window);
+
+        let minimum = if window.info.es { MINIMUM_ES_VERSION } else { MINIMUM_VERSION };
+        window.info.require_version(minimum).map_err(WindowError::Unsupported)?;
+
+        let problems = self.validate(&window.info);
+        if !problems.is_empty() {
+            return Err(WindowError::ContextMismatch(problems));
+        }
+
+        engine.glfw_ctx.set_swap_interval(match self.swap_interval {
+            SwapInterval::Immediate => glfw::SwapInterval::None,
+            SwapInterval::Sync(n) => glfw::SwapInterval::Sync(n),
+            SwapInterval::Adaptive => glfw::SwapInterval::Adaptive
+        });
+
+        if !window.info.es {
+            unsafe {
+                if self.srgb {
+                    gl::Enable(gl::FRAMEBUFFER_SRGB);
+                }
+                if self.samples.unwrap_or(0) > 0 {
+                    gl::Enable(gl::MULTISAMPLE);
+                }
+            }
+        }
+
+        if let Some(options) = self.debug_output {
+            if window.info.supports(GlFeature::DebugOutput) {
+                install_debug_output(&mut window, options);
+            } else {
+                log::warn!(target: "katengine::gl", "Debug output was requested but the context does not support it.");
+            }
+        }
+
+        Ok(window)
+    }
+}
+
+fn get_integer(name: GLenum) -> GLint {
+    let mut value: GLint = 0;
+    unsafe { gl::GetIntegerv(name, &mut value); }
+    value
+}
+
+fn default_attachment_parameter(attachment: GLenum, pname: GLenum) -> GLint {
+    let mut value: GLint = 0;
+    unsafe { gl::GetFramebufferAttachmentParameteriv(gl::DRAW_FRAMEBUFFER, attachment, pname, &mut value); }
+    value
+}
+
+impl Window {
+    pub fn is_fullscreen(&self) -> bool {
+        self.win.with_window_mode(|m| matches!(m, WindowMode::FullScreen(_)))
+    }
+
+    /// The size the window was built with, centred on the monitor it currently covers.
+    fn centered_windowed_rect(&self) -> Rect {
+        let (width, height) = (self.windowed_size.0 as i32, self.windowed_size.1 as i32);
+        let ((x, y), (monitor_width, monitor_height)) = self.win.with_window_mode(|mode| match mode {
+            WindowMode::FullScreen(m) => {
+                let size = m.get_video_mode().map_or((width, height), |v| (v.width as i32, v.height as i32));
+                (m.get_pos(), size)
+            }
+            // A borderless window covers its monitor.
+            WindowMode::Windowed => (self.win.get_pos(), self.win.get_size())
+        });
+        Rect::create(x + (monitor_width - width) / 2, y + (monitor_height - height) / 2, width, height)
+    }
+
+    /// Switches between windowed, exclusive fullscreen and borderless modes. The windowed
+    /// position and size are remembered and restored when switching back; a window created
+    /// fullscreen or borderless returns to its built size, centred on its monitor.
+    pub fn set_display_mode(&mut self, engine: &mut Engine, mode: DisplayMode) -> Result<(), WindowError> {
+        if self.windowed_rect.is_none() && !self.is_fullscreen() && !self.borderless {
+            let (x, y) = self.win.get_pos();
+            let (width, height) = self.win.get_size();
+            self.windowed_rect = Some(Rect::create(x, y, width, height));
+        }
+
+        match mode {
+            DisplayMode::Windowed => {
+                let r = match self.windowed_rect.take() {
+                    Some(r) => r,
+                    None => self.centered_windowed_rect()
+                };
+                self.win.set_monitor(WindowMode::Windowed, r.x, r.y, r.width as u32, r.height as u32, None);
+                self.win.set_decorated(self.decorated);
+                self.borderless = false;
+            }
+            DisplayMode::Fullscreen(monitor, video_mode) => {
+                let win = &mut self.win;
+                with_monitor(&mut engine.glfw_ctx, monitor, |_, m| {
+                    let mode = resolve_video_mode(m, video_mode);
+                    let (width, height) = mode.map_or_else(|| {
+                        let (w, h) = win.get_size();
+                        (w as u32, h as u32)
+                    }, |v| (v.width, v.height));
+                    win.set_monitor(WindowMode::FullScreen(m), 0, 0, width, height, mode.map(|v| v.refresh_rate));
+                }).ok_or(WindowError::NoSuchMonitor(monitor))?;
+                self.borderless = false;
+            }
+            DisplayMode::Borderless(monitor) => {
+                let (position, size) = with_monitor(&mut engine.glfw_ctx, monitor, |_, m| {
+                    (m.get_pos(), m.get_video_mode().map(|v| (v.width, v.height)))
+                }).ok_or(WindowError::NoSuchMonitor(monitor))?;
+                let (width, height) = size.unwrap_or_else(|| {
+                    let (w, h) = self.win.get_size();
+                    (w as u32, h as u32)
+                });
+                self.win.set_monitor(WindowMode::Windowed, position.0, position.1, width, height, None);
+                self.win.set_decorated(false);
+                self.borderless = true;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Toggles between the windowed state and exclusive fullscreen on the primary monitor.
+    pub fn toggle_fullscreen(&mut self, engine: &mut Engine) -> Result<(), WindowError> {
+        if self.is_fullscreen() {
+            self.set_display_mode(engine, DisplayMode::Windowed)
+        } else {
+            self.set_display_mode(engine, DisplayMode::Fullscreen(MonitorSelection::Primary, None))
+        }
+    }
+
+    pub fn set_resizable(&mut self, resizable: bool) {
+        self.win.set_resizable(resizable);
+    }
+
+    /// Also the decoration restored when leaving fullscreen or borderless mode.
+    pub fn set_decorated(&mut self, decorated: bool) {
+        self.decorated = decorated;
+        self.win.set_decorated(decorated);
+    }
+
+    pub fn set_floating(&mut self, floating: bool) {
+        self.win.set_floating(floating);
+    }
+
+    pub fn position(&self) -> (i32, i32) {
+        self.win.get_pos()
+    }
+
+    pub fn set_position(&mut self, x: i32, y: i32) {
+        self.win.set_pos(x, y);
+    }
+}
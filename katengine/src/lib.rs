@@ -1,25 +1,91 @@
 extern crate glfw;
 extern crate num_traits;
 extern crate glm;
+extern crate log;
 
 pub mod gl;
+/// OpenGL ES 3.2 bindings. The engine itself calls the ES subset through `gl`, which is
+/// loaded from the ES context as well; these are for code that needs ES-only entry points.
+pub mod gles;
 
 pub mod kat {
-    use std::borrow::BorrowMut;
-    use std::ffi::c_void;
+    pub mod actions;
+    pub mod app;
+    pub mod backend;
+    pub mod context;
+    pub mod deletion;
+    pub mod event;
+    pub mod gamepad;
+    pub mod glsl;
+    pub mod gltf;
+    pub mod info;
+    pub mod input;
+    pub mod mesh;
+    pub mod profiler;
+    pub mod render_target;
+    pub mod resize;
+    pub mod resources;
+    pub mod time;
+    pub mod window;
+
+    pub use self::actions::{ActionMap, Axis2Binding, AxisBinding, Binding, BindingError};
+    pub use self::app::{App, LoopSettings};
+    pub use self::context::{ContextId, LiveObject, ObjectKind, ShareGroupId};
+    pub use self::deletion::DeletionStats;
+    pub use self::event::{Action, Event, Key, Modifiers, MouseButton};
+    pub use self::gamepad::{GamepadAxis, GamepadButton, GamepadId, GamepadState, JoystickInfo, JoystickState, MappingError};
+    pub use self::info::{GlFeature, GlInfo, GlLimits, GlVersion, UnsupportedContext};
+    pub use self::input::{CursorMode, Input};
+    pub use self::mesh::{Aabb, BoundingSphere, Mesh, MeshData, Submesh};
+    pub use self::render_target::{RenderTarget, RenderTargetDesc};
+    pub use self::resize::{RenderTargetId, WindowMetrics};
+    pub use self::resources::{Handle, Pool, Resource, Resources};
+    pub use self::time::{Clock, GlfwClock, ManualClock, MonotonicClock, Time};
+    pub use self::window::{ClientApi, ContextCreation, DisplayMode, GlProfile, MonitorInfo, MonitorSelection, SwapInterval,
+                           VideoMode, WindowBuilder, WindowError};
+
+    use std::ffi::{c_void, CStr, CString};
+    use std::cell::RefCell;
     use std::marker::PhantomData;
     use std::ptr::{null, null_mut};
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicBool, Ordering};
     use glfw;
-    use glfw::{Context, Glfw, WindowMode};
+    use glfw::{Context, Glfw};
     use num_traits::Num;
     use crate::gl;
+    use crate::gles;
     use crate::gl::types::*;
+    use self::context::{ContextToken, GroupToken};
+    use self::deletion::DeletionQueue;
+    use self::gamepad::Gamepads;
+    use self::resize::ResizeCallback;
 
     pub struct Window {
+        render_targets: Vec<Option<RenderTarget>>,
         win: glfw::Window,
-        events: std::sync::mpsc::Receiver<(f64, glfw::WindowEvent)>
+        events: std::sync::mpsc::Receiver<(f64, glfw::WindowEvent)>,
+        debug_output: Option<Box<DebugState>>,
+        info: GlInfo,
+        /// Windowed position and size to return to when leaving fullscreen or borderless mode.
+        windowed_rect: Option<Rect>,
+        /// Size and decoration the window was built with, used when a window created fullscreen
+        /// or borderless switches to windowed mode and has no rect to return to.
+        windowed_size: (u32, u32),
+        decorated: bool,
+        borderless: bool,
+        context: ContextToken,
+        input: Input,
+        gamepad: Option<GamepadId>,
+        metrics: WindowMetrics,
+        auto_viewport: bool,
+        /// Set when the framebuffer size or content scale changed but the viewport, render
+        /// targets and callbacks haven't caught up, because the context wasn't current.
+        resize_pending: bool,
+        resize_callbacks: Vec<ResizeCallback>
     }
 
+    #[derive(Clone,Copy,PartialEq,Debug)]
     pub struct Color {
         r: f32,
         g: f32,
@@ -47,46 +113,366 @@ pub mod kat {
     }
 
     pub struct Engine {
-        glfw_ctx: Glfw
+        glfw_ctx: Glfw,
+        gamepads: Gamepads,
+        time: Time,
+        resources: Resources,
+        deletions: Rc<RefCell<DeletionQueue>>
     }
 
     impl Engine {
         pub fn create() -> Engine {
+            let glfw_ctx = glfw::init(glfw::FAIL_ON_ERRORS).unwrap();
+            let time = Time::new(Box::new(GlfwClock::new(&glfw_ctx)));
             Engine {
-                glfw_ctx: glfw::init(glfw::FAIL_ON_ERRORS).unwrap()
+                glfw_ctx,
+                gamepads: Gamepads::new(),
+                time,
+                resources: Resources::new(),
+                deletions: deletion::install()
             }
         }
 
-        pub fn new_window(&mut self, width: u32, height: u32, title: &str) -> Window {
-            let (mut win, events) = self.glfw_ctx.create_window(width, height, title, WindowMode::Windowed)
-                .expect("Failed to create window.");
+        /// Frame timing, ticked by `run` at the start of every frame.
+        pub fn time(&self) -> &Time {
+            &self.time
+        }
 
-            win.set_all_polling(true);
+        /// For pausing, scaling time or swapping the clock.
+        pub fn time_mut(&mut self) -> &mut Time {
+            &mut self.time
+        }
 
-            let mut window: Window = Window {
-                win, events
-            };
+        pub fn new_window(&mut self, width: u32, height: u32, title: &str) -> Window {
+            WindowBuilder::new(width, height, title).build(self).unwrap_or_else(|e| panic!("{}", e))
+        }
 
-            load_gl(window.borrow_mut());
+        /// Creates a window whose context shares buffers, shaders and textures with `share`.
+        pub fn new_shared_window(&mut self, share: &Window, width: u32, height: u32, title: &str) -> Window {
+            WindowBuilder::new(width, height, title).build_shared(self, share).unwrap_or_else(|e| panic!("{}", e))
+        }
 
-            return window;
+        /// Polls events once and hands each window's events to `f` together with that window.
+        pub fn poll<F>(&mut self, windows: &mut [&mut Window], mut f: F) where F: FnMut(&mut Window, Event) {
+            self.glfw_ctx.poll_events();
+            let gamepad_events = self.gamepads.update(&self.glfw_ctx);
+            for window in windows.iter_mut() {
+                window.dispatch_events(&self.gamepads, &gamepad_events, &mut f);
+            }
         }
 
         pub fn clear(&self, color: &Color) {
-            unsafe {
-                gl::ClearColor(color.r, color.g, color.b, color.a);
-                gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT);
-            }
+            self.clear_with(&ClearOptions::all(*color));
         }
 
         pub fn clear_default(&self) {
             self.clear(&colors::BLACK)
         }
+
+        /// Clears only the attachments selected in `options` on the currently bound draw framebuffer.
+        pub fn clear_with(&self, options: &ClearOptions) {
+            let mut mask: GLbitfield = 0;
+            unsafe {
+                if let Some(color) = options.color {
+                    gl::ClearColor(color.r, color.g, color.b, color.a);
+                    mask |= gl::COLOR_BUFFER_BIT;
+                }
+                if let Some(depth) = options.depth {
+                    // ClearDepthf is only core on desktop GL since 4.1.
+                    if backend::is_gles() {
+                        gl::ClearDepthf(depth);
+                    } else {
+                        gl::ClearDepth(depth as f64);
+                    }
+                    mask |= gl::DEPTH_BUFFER_BIT;
+                }
+                if let Some(stencil) = options.stencil {
+                    gl::ClearStencil(stencil);
+                    mask |= gl::STENCIL_BUFFER_BIT;
+                }
+            }
+
+            if mask == 0 {
+                return;
+            }
+
+            with_scissor(options.scissor, || unsafe { gl::Clear(mask) });
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+    pub struct Rect {
+        pub x: i32,
+        pub y: i32,
+        pub width: i32,
+        pub height: i32
+    }
+
+    impl Rect {
+        pub const fn create(x: i32, y: i32, width: i32, height: i32) -> Rect {
+            Rect{x, y, width, height}
+        }
+    }
+
+    /// Selects which attachments a clear touches and what they are cleared to.
+    /// Attachments left as `None` keep their contents.
+    #[derive(Clone,Copy,PartialEq,Debug)]
+    pub struct ClearOptions {
+        pub color: Option<Color>,
+        pub depth: Option<f32>,
+        pub stencil: Option<i32>,
+        /// Confines the clear to this rectangle (window coordinates, origin bottom-left).
+        pub scissor: Option<Rect>
+    }
+
+    impl ClearOptions {
+        pub const fn none() -> ClearOptions {
+            ClearOptions{color: None, depth: None, stencil: None, scissor: None}
+        }
+
+        pub const fn all(color: Color) -> ClearOptions {
+            ClearOptions{color: Some(color), depth: Some(1.0), stencil: Some(0), scissor: None}
+        }
+
+        pub const fn with_color(mut self, color: Color) -> ClearOptions {
+            self.color = Some(color);
+            self
+        }
+
+        pub const fn with_depth(mut self, depth: f32) -> ClearOptions {
+            self.depth = Some(depth);
+            self
+        }
+
+        pub const fn with_stencil(mut self, stencil: i32) -> ClearOptions {
+            self.stencil = Some(stencil);
+            self
+        }
+
+        pub const fn with_scissor(mut self, scissor: Rect) -> ClearOptions {
+            self.scissor = Some(scissor);
+            self
+        }
+    }
+
+    impl Default for ClearOptions {
+        fn default() -> Self {
+            ClearOptions::none()
+        }
+    }
+
+    /// Runs `f` with the scissor test set to `scissor`, restoring the previous scissor state afterwards.
+    fn with_scissor<F: FnOnce()>(scissor: Option<Rect>, f: F) {
+        let rect = match scissor {
+            Some(r) => r,
+            None => {
+                f();
+                return;
+            }
+        };
+
+        let mut previous: [GLint; 4] = [0; 4];
+        let was_enabled = unsafe {
+            let enabled = gl::IsEnabled(gl::SCISSOR_TEST) == gl::TRUE;
+            gl::GetIntegerv(gl::SCISSOR_BOX, previous.as_mut_ptr());
+            gl::Enable(gl::SCISSOR_TEST);
+            gl::Scissor(rect.x, rect.y, rect.width, rect.height);
+            enabled
+        };
+
+        f();
+
+        unsafe {
+            gl::Scissor(previous[0], previous[1], previous[2], previous[3]);
+            if !was_enabled {
+                gl::Disable(gl::SCISSOR_TEST);
+            }
+        }
     }
 
+    /// The oldest context version the engine runs on. Newer features are used when present.
+    pub const MINIMUM_VERSION: GlVersion = GlVersion::of(3, 3);
+    pub const MINIMUM_ES_VERSION: GlVersion = GlVersion::of(3, 0);
+
+    static GL_LOADED: AtomicBool = AtomicBool::new(false);
+    static GLES_LOADED: AtomicBool = AtomicBool::new(false);
+
+    /// Makes the window current and loads the GL function pointers. Pointers are global and
+    /// identical for every context of one client API, so they are only loaded for the first window.
     pub fn load_gl(win: &mut Window) {
         win.win.make_current();
-        gl::load_with(|s| win.win.get_proc_address(s) as *const _);
+        context::set_current(&win.context);
+        if !GL_LOADED.swap(true, Ordering::Relaxed) {
+            gl::load_with(|s| win.win.get_proc_address(s) as *const _);
+        }
+        win.info = GlInfo::query();
+        if win.info.es && !GLES_LOADED.swap(true, Ordering::Relaxed) {
+            gles::load_with(|s| win.win.get_proc_address(s) as *const _);
+            load_es_extensions(win);
+        }
+        backend::select(&win.info);
+    }
+
+    /// Binds the `gl` entry points that ES only has with an extension suffix. The generated
+    /// loader tries the suffix only when the core name is missing, which some platforms
+    /// never report.
+    fn load_es_extensions(win: &mut Window) {
+        fn suffixed<'a>(win: &'a mut glfw::Window, suffix: &'a str) -> impl FnMut(&'static str) -> *const c_void + 'a {
+            move |s| win.get_proc_address(&format!("{}{}", s, suffix)) as *const _
+        }
+        let (info, glfw_win) = (&win.info, &mut win.win);
+        if info.supports(GlFeature::TimerQueries) {
+            gl::QueryCounter::load_with(suffixed(glfw_win, "EXT"));
+            gl::GetQueryObjectui64v::load_with(suffixed(glfw_win, "EXT"));
+        }
+        if info.supports(GlFeature::DebugOutput) && !info.is_version_at_least(3, 2) {
+            gl::DebugMessageCallback::load_with(suffixed(glfw_win, "KHR"));
+            gl::DebugMessageControl::load_with(suffixed(glfw_win, "KHR"));
+            gl::ObjectLabel::load_with(suffixed(glfw_win, "KHR"));
+            gl::PushDebugGroup::load_with(suffixed(glfw_win, "KHR"));
+            gl::PopDebugGroup::load_with(suffixed(glfw_win, "KHR"));
+        }
+    }
+
+    /// Configuration for routing `KHR_debug` messages into the `log` crate.
+    #[derive(Clone,Debug,Default)]
+    pub struct DebugOutput {
+        /// Message ids the driver should not report at all.
+        pub ignored_ids: Vec<u32>,
+        /// Panic when the driver reports an error. A panic can't unwind out of the callback,
+        /// so the first error is kept and the panic happens at the next `Window::swap`.
+        /// Intended for tests.
+        pub panic_on_error: bool
+    }
+
+    impl DebugOutput {
+        pub fn ignore(mut self, id: u32) -> DebugOutput {
+            self.ignored_ids.push(id);
+            self
+        }
+
+        pub fn panic_on_error(mut self, panic_on_error: bool) -> DebugOutput {
+            self.panic_on_error = panic_on_error;
+            self
+        }
+    }
+
+    /// What the debug callback reads and writes. Boxed by the window so the pointer the
+    /// driver holds stays valid until the window removes the callback.
+    struct DebugState {
+        options: DebugOutput,
+        /// The first error reported while `panic_on_error` is set.
+        error: RefCell<Option<String>>
+    }
+
+    /// Installs the debug message callback on the window's context, making it current. The
+    /// window must have been created with a debug context for the driver to report anything.
+    pub fn install_debug_output(win: &mut Window, options: DebugOutput) {
+        win.make_current();
+        let state = Box::new(DebugState { options, error: RefCell::new(None) });
+
+        unsafe {
+            gl::Enable(gl::DEBUG_OUTPUT);
+            gl::Enable(gl::DEBUG_OUTPUT_SYNCHRONOUS);
+            gl::DebugMessageCallback(Some(debug_message_callback), state.as_ref() as *const DebugState as *const c_void);
+
+            let ignored = &state.options.ignored_ids;
+            if !ignored.is_empty() {
+                gl::DebugMessageControl(gl::DONT_CARE, gl::DONT_CARE, gl::DONT_CARE,
+                                        ignored.len() as GLsizei, ignored.as_ptr(), gl::FALSE);
+            }
+        }
+
+        win.debug_output = Some(state);
+    }
+
+    const fn debug_source_name(source: GLenum) -> &'static str {
+        match source {
+            gl::DEBUG_SOURCE_API => "api",
+            gl::DEBUG_SOURCE_WINDOW_SYSTEM => "window system",
+            gl::DEBUG_SOURCE_SHADER_COMPILER => "shader compiler",
+            gl::DEBUG_SOURCE_THIRD_PARTY => "third party",
+            gl::DEBUG_SOURCE_APPLICATION => "application",
+            _ => "other"
+        }
+    }
+
+    const fn debug_type_name(t: GLenum) -> &'static str {
+        match t {
+            gl::DEBUG_TYPE_ERROR => "error",
+            gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR => "deprecated behavior",
+            gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR => "undefined behavior",
+            gl::DEBUG_TYPE_PORTABILITY => "portability",
+            gl::DEBUG_TYPE_PERFORMANCE => "performance",
+            gl::DEBUG_TYPE_MARKER => "marker",
+            gl::DEBUG_TYPE_PUSH_GROUP => "push group",
+            gl::DEBUG_TYPE_POP_GROUP => "pop group",
+            _ => "other"
+        }
+    }
+
+    const fn debug_log_level(t: GLenum, severity: GLenum) -> log::Level {
+        if t == gl::DEBUG_TYPE_ERROR {
+            return log::Level::Error;
+        }
+        match severity {
+            gl::DEBUG_SEVERITY_HIGH => log::Level::Error,
+            gl::DEBUG_SEVERITY_MEDIUM => log::Level::Warn,
+            gl::DEBUG_SEVERITY_LOW => log::Level::Info,
+            _ => log::Level::Debug
+        }
+    }
+
+    extern "system" fn debug_message_callback(source: GLenum, t: GLenum, id: GLuint, severity: GLenum,
+                                              _length: GLsizei, message: *const GLchar, user_param: *mut c_void) {
+        let state = unsafe { &*(user_param as *const DebugState) };
+        let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+
+        log::log!(target: "katengine::gl", debug_log_level(t, severity), "[{} {} #{}] {}",
+                  debug_source_name(source), debug_type_name(t), id, message);
+
+        if state.options.panic_on_error && t == gl::DEBUG_TYPE_ERROR {
+            // Unwinding out of an `extern "system"` function aborts, so only record it.
+            let mut error = state.error.borrow_mut();
+            if error.is_none() {
+                *error = Some(format!("OpenGL error #{}: {}", id, message));
+            }
+        }
+    }
+
+    /// Attaches a human readable name to a GL object, shown in debug messages and graphics debuggers.
+    pub trait Labeled {
+        fn set_label(&mut self, label: &str);
+    }
+
+    fn object_label(identifier: GLenum, handle: u32, label: &str) {
+        if !backend::has_debug_labels() {
+            return;
+        }
+        unsafe { gl::ObjectLabel(identifier, handle, label.len() as GLsizei, label.as_ptr() as *const GLchar); }
+    }
+
+    /// Names a region of GL commands in graphics debuggers and debug output. The group is
+    /// popped when the guard is dropped.
+    pub struct DebugGroup {
+        _not_send: PhantomData<*const ()>
+    }
+
+    impl DebugGroup {
+        pub fn push(name: &str) -> DebugGroup {
+            if backend::has_debug_labels() {
+                unsafe { gl::PushDebugGroup(gl::DEBUG_SOURCE_APPLICATION, 0, name.len() as GLsizei, name.as_ptr() as *const GLchar); }
+            }
+            DebugGroup { _not_send: PhantomData }
+        }
+    }
+
+    impl Drop for DebugGroup {
+        fn drop(&mut self) {
+            if backend::has_debug_labels() {
+                unsafe { gl::PopDebugGroup(); }
+            }
+        }
     }
 
     impl Window {
@@ -95,29 +481,136 @@ pub mod kat {
             !self.win.should_close()
         }
 
-        pub fn update_events<F>(&mut self, engine: &mut Engine, mut f: F) where F: FnMut(&mut glfw::Window, glfw::WindowEvent) {
+        pub fn update_events<F>(&mut self, engine: &mut Engine, mut f: F) where F: FnMut(&mut Window, Event) {
             engine.glfw_ctx.poll_events();
-            for (_, event) in glfw::flush_messages(&self.events) {
-                f(self.win.borrow_mut(), event);
+            let gamepad_events = engine.gamepads.update(&engine.glfw_ctx);
+            self.dispatch_events(&engine.gamepads, &gamepad_events, &mut f);
+        }
+
+        fn dispatch_events<F>(&mut self, gamepads: &Gamepads, gamepad_events: &[Event], f: &mut F)
+            where F: FnMut(&mut Window, Event) {
+            let events: Vec<Event> = glfw::flush_messages(&self.events)
+                .filter_map(|(_, e)| Event::from_glfw(e))
+                .chain(gamepad_events.iter().cloned())
+                .collect();
+            for event in &events {
+                self.track_size(event);
+            }
+            self.apply_resize();
+            self.input.begin_frame();
+            let gamepad = self.gamepad.or_else(|| gamepads.first());
+            self.input.set_gamepad(gamepad.and_then(|id| gamepads.state(id)));
+            for event in events {
+                self.input.handle_event(&event);
+                f(self, event);
             }
         }
 
+        /// Keyboard, mouse and gamepad state as of the last event poll.
+        pub fn input(&self) -> &Input {
+            &self.input
+        }
+
+        /// Picks the gamepad feeding `input()`. `None`, the default, follows the first
+        /// connected gamepad.
+        pub fn use_gamepad(&mut self, gamepad: Option<GamepadId>) {
+            self.gamepad = gamepad;
+        }
+
+        pub fn set_cursor_mode(&mut self, mode: CursorMode) {
+            self.win.set_cursor_mode(match mode {
+                CursorMode::Normal => glfw::CursorMode::Normal,
+                CursorMode::Hidden => glfw::CursorMode::Hidden,
+                CursorMode::Captured => glfw::CursorMode::Disabled
+            });
+            self.input.reset_mouse_delta();
+        }
+
+        /// Uses unaccelerated mouse motion while the cursor is captured, where the platform
+        /// supports it. Returns whether raw motion is in effect.
+        pub fn set_raw_mouse_motion(&mut self, raw: bool) -> bool {
+            if raw && !self.win.glfw.supports_raw_motion() {
+                return false;
+            }
+            self.win.set_raw_mouse_motion(raw);
+            raw
+        }
+
+        /// Makes this window's context current, so following GL calls draw into it.
+        pub fn make_current(&mut self) {
+            self.win.make_current();
+            context::set_current(&self.context);
+            backend::select(&self.info);
+            self.apply_resize();
+        }
+
+        pub fn context(&self) -> ContextId {
+            self.context.id()
+        }
+
+        pub fn share_group(&self) -> ShareGroupId {
+            self.context.group().id()
+        }
+
+        /// GL objects created on this window's context or share group that are still alive.
+        pub fn live_objects(&self) -> Vec<LiveObject> {
+            self.context.live_objects()
+        }
+
+        pub fn close(&mut self) {
+            self.win.set_should_close(true);
+        }
+
+        /// Version, vendor, extensions and limits of this window's context.
+        pub fn gl_info(&self) -> &GlInfo {
+            &self.info
+        }
+
+        /// Presents the frame. Panics with the first error of the frame if debug output was
+        /// installed with `panic_on_error`.
         pub fn swap(&mut self) {
+            if let Some(error) = self.debug_output.as_ref().and_then(|state| state.error.borrow_mut().take()) {
+                panic!("{}", error);
+            }
             self.win.swap_buffers();
         }
     }
 
+    impl Drop for Engine {
+        fn drop(&mut self) {
+            self.resources.clear();
+            self.deletions.borrow_mut().shutdown();
+        }
+    }
+
+    impl Drop for Window {
+        fn drop(&mut self) {
+            // Delete what the window owns while its context still exists, then report what
+            // the application left behind.
+            self.win.make_current();
+            context::set_current(&self.context);
+            if self.debug_output.is_some() {
+                unsafe { gl::DebugMessageCallback(None, null()); }
+            }
+            self.render_targets.clear();
+            self.resize_callbacks.clear();
+            for object in context::destroy_context(&self.context) {
+                log::warn!(target: "katengine::gl", "{} is still alive as context {:?} is destroyed", object, self.context.id());
+            }
+        }
+    }
+
     pub trait Bindable {
         fn bind(&mut self);
     }
 
-    #[derive(Clone,Copy,PartialEq,Eq,Hash)]
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
     pub enum BufferTarget {
         Array,
         ElementArray
     }
 
-    #[derive(Clone,Copy,PartialEq,Eq,Hash)]
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
     pub enum DrawMode {
         Triangles,
         TriangleFan,
@@ -155,6 +648,7 @@ pub mod kat {
         handle: u32,
         pub size: usize,
         target: BufferTarget,
+        group: GroupToken,
         _phantom: PhantomData<T>
     }
 
@@ -164,13 +658,11 @@ pub mod kat {
         }
 
         pub fn create_blank_sized(target: BufferTarget, size: usize) -> Buffer<T> {
-            let mut i: u32 = 0;
-            unsafe {
-                gl::CreateBuffers(1, &mut i);
-                gl::NamedBufferData(i, (size * std::mem::size_of::<T>()) as GLsizeiptr, null(), gl::DYNAMIC_DRAW);
-            }
+            let i = backend::create_buffer();
+            backend::buffer_data(i, size * std::mem::size_of::<T>(), null(), gl::DYNAMIC_DRAW);
             return Buffer::<T>{
                 handle: i,
+                group: context::require_current().adopt_shared(ObjectKind::Buffer, i),
                 size,
                 target,
                 _phantom: Default::default()
@@ -178,15 +670,12 @@ pub mod kat {
         }
 
         pub fn create(target: BufferTarget, data: Vec<T>) -> Buffer<T> {
-            let mut i: u32 = 0;
-            unsafe {
-                gl::CreateBuffers(1, &mut i);
-                let l: GLsizeiptr = (data.len() * std::mem::size_of::<T>()) as GLsizeiptr;
-                gl::NamedBufferData(i, l, data.as_ptr() as *const c_void, gl::STATIC_DRAW);
-            }
+            let i = backend::create_buffer();
+            backend::buffer_data(i, data.len() * std::mem::size_of::<T>(), data.as_ptr() as *const c_void, gl::STATIC_DRAW);
 
             return Buffer::<T>{
                 handle: i,
+                group: context::require_current().adopt_shared(ObjectKind::Buffer, i),
                 size: data.len(),
                 target,
                 _phantom: Default::default()
@@ -196,45 +685,47 @@ pub mod kat {
 
     pub struct VertexArray {
         handle: u32,
+        context: ContextToken,
         next_attrib: usize,
         next_binding: usize
     }
 
     impl VertexArray {
         pub fn new() -> VertexArray {
-            let mut i: u32 = 0;
-            unsafe { gl::CreateVertexArrays(1, &mut i); }
+            let i = backend::create_vertex_array();
             return VertexArray {
                 handle: i,
+                context: context::require_current().adopt(ObjectKind::VertexArray, i),
                 next_attrib: 0,
                 next_binding: 0
             }
         }
 
         pub fn vertex_buffer(&mut self, buf: &mut Buffer<f32>, attribs: Vec<usize>) {
+            self.context.check("VertexArray");
+            buf.group.check("Buffer");
             let mut stride: usize = 0;
-            buf.bind();
+            let mut formats: Vec<backend::FloatAttrib> = Vec::new();
 
             for a in attribs {
-                unsafe {
-                    gl::VertexArrayAttribFormat(self.handle, self.next_attrib as GLuint, a as GLint, gl::FLOAT, gl::FALSE, stride as GLuint);
-                    gl::VertexArrayAttribBinding(self.handle, self.next_attrib as GLuint, self.next_binding as GLuint);
-                    gl::EnableVertexArrayAttrib(self.handle, self.next_attrib as GLuint);
-                }
+                formats.push(backend::FloatAttrib {
+                    index: self.next_attrib as u32,
+                    components: a as i32,
+                    offset: stride
+                });
 
                 stride += a * std::mem::size_of::<f32>();
                 self.next_attrib += 1;
             }
 
-            unsafe { gl::VertexArrayVertexBuffer(self.handle, self.next_binding as GLuint, buf.handle, 0, stride as GLsizei); }
+            backend::vertex_buffer(self.handle, self.next_binding as u32, buf.handle, stride, &formats);
             self.next_binding += 1;
         }
 
         pub fn element_buffer(&mut self, buf: &mut Buffer<u32>) {
-            buf.bind();
-            unsafe {
-                gl::VertexArrayElementBuffer(self.handle, buf.handle);
-            }
+            self.context.check("VertexArray");
+            buf.group.check("Buffer");
+            backend::element_buffer(self.handle, buf.handle);
         }
 
         pub fn draw_arrays(&mut self, mode: DrawMode, count: usize, start: usize) {
@@ -254,21 +745,44 @@ pub mod kat {
 
     impl <T: Num> Bindable for Buffer<T> {
         fn bind(&mut self) {
+            self.group.check("Buffer");
             unsafe { gl::BindBuffer(translate_buffer_target(self.target), self.handle as GLuint); }
         }
     }
 
     impl Bindable for VertexArray {
         fn bind(&mut self) {
+            self.context.check("VertexArray");
             unsafe { gl::BindVertexArray(self.handle); }
         }
     }
 
+    impl <T: Num> Labeled for Buffer<T> {
+        fn set_label(&mut self, label: &str) {
+            self.group.set_label(ObjectKind::Buffer, self.handle, label);
+            object_label(gl::BUFFER, self.handle, label);
+        }
+    }
+
+    impl Labeled for VertexArray {
+        fn set_label(&mut self, label: &str) {
+            self.context.set_label(ObjectKind::VertexArray, self.handle, label);
+            object_label(gl::VERTEX_ARRAY, self.handle, label);
+        }
+    }
+
+    impl Drop for VertexArray {
+        fn drop(&mut self) {
+            self.context.release(ObjectKind::VertexArray, self.handle);
+        }
+    }
+
     pub struct Shader {
-        handle: u32
+        handle: u32,
+        group: GroupToken
     }
 
-    #[derive(Clone,Copy,PartialEq,Eq,Hash)]
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
     pub enum ShaderType {
         Vertex,
         Fragment,
@@ -303,15 +817,45 @@ pub mod kat {
         }
     }
 
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+    pub enum FeedbackBufferMode {
+        /// All varyings are written one after another into a single buffer.
+        Interleaved,
+        /// Each varying is written into its own buffer binding.
+        Separate
+    }
+
+    pub const fn translate_feedback_buffer_mode(mode: FeedbackBufferMode) -> u32 {
+        match mode {
+            FeedbackBufferMode::Interleaved => { gl::INTERLEAVED_ATTRIBS }
+            FeedbackBufferMode::Separate => { gl::SEPARATE_ATTRIBS }
+        }
+    }
+
     impl Shader {
         pub fn load(paths: Vec<ShaderFile>) -> Shader {
-            let sh = Shader { handle: unsafe { gl::CreateProgram() } };
+            Self::link(paths, None)
+        }
+
+        /// Loads a shader whose `varyings` are captured by transform feedback.
+        pub fn load_with_feedback(paths: Vec<ShaderFile>, varyings: &[&str], mode: FeedbackBufferMode) -> Shader {
+            Self::link(paths, Some((varyings, mode)))
+        }
+
+        fn link(paths: Vec<ShaderFile>, feedback: Option<(&[&str], FeedbackBufferMode)>) -> Shader {
+            let handle = unsafe { gl::CreateProgram() };
+            let sh = Shader { handle, group: context::require_current().adopt_shared(ObjectKind::Shader, handle) };
             unsafe {
                 let mut shs: Vec<u32> = Vec::new();
 
                 for p in paths {
                     let i: u32 = gl::CreateShader(translate_shader_type(p.t));
-                    let mut content = std::fs::read(p.path).unwrap();
+                    let source = std::fs::read_to_string(&p.path).unwrap();
+                    let source = match glsl::prepare_source(&source, backend::glsl_target()) {
+                        Ok(s) => s,
+                        Err(e) => panic!("{}: {}", p.path, e)
+                    };
+                    let mut content = source.into_bytes();
                     content.push(0);
                     let ptr = content.as_ptr() as *const GLchar;
                     gl::ShaderSource(i, 1, &ptr, null());
@@ -333,6 +877,14 @@ pub mod kat {
                     shs.push(i);
                 }
 
+                if let Some((varyings, mode)) = feedback {
+                    let names: Vec<CString> = varyings.iter()
+                        .map(|v| CString::new(*v).expect("Varying names cannot contain NUL bytes"))
+                        .collect();
+                    let ptrs: Vec<*const GLchar> = names.iter().map(|n| n.as_ptr()).collect();
+                    gl::TransformFeedbackVaryings(sh.handle, ptrs.len() as GLsizei, ptrs.as_ptr(), translate_feedback_buffer_mode(mode));
+                }
+
                 gl::LinkProgram(sh.handle);
 
                 let mut status: i32 = 0;
@@ -356,47 +908,39 @@ pub mod kat {
         }
 
         pub fn uniform_1f(&self, name: &str, value: f32) {
+            self.group.check("Shader");
             let mut namez = String::from(name);
             namez.push('\0');
 
-            unsafe { gl::ProgramUniform1f(
-                self.handle,
-                gl::GetUniformLocation(
-                    self.handle, namez.as_ptr() as *const GLchar),
-                value); }
+            let location = unsafe { gl::GetUniformLocation(self.handle, namez.as_ptr() as *const GLchar) };
+            backend::uniform_1f(self.handle, location, value);
         }
 
         pub fn uniform_2f(&self, name: &str, x: f32, y: f32) {
+            self.group.check("Shader");
             let mut namez = String::from(name);
             namez.push('\0');
 
-            unsafe { gl::ProgramUniform2f(
-                self.handle,
-                gl::GetUniformLocation(
-                    self.handle, namez.as_ptr() as *const GLchar),
-                x, y); }
+            let location = unsafe { gl::GetUniformLocation(self.handle, namez.as_ptr() as *const GLchar) };
+            backend::uniform_2f(self.handle, location, x, y);
         }
 
         pub fn uniform_3f(&self, name: &str, x: f32, y: f32, z: f32) {
+            self.group.check("Shader");
             let mut namez = String::from(name);
             namez.push('\0');
 
-            unsafe { gl::ProgramUniform3f(
-                self.handle,
-                gl::GetUniformLocation(
-                    self.handle, namez.as_ptr() as *const GLchar),
-                x, y, z); }
+            let location = unsafe { gl::GetUniformLocation(self.handle, namez.as_ptr() as *const GLchar) };
+            backend::uniform_3f(self.handle, location, x, y, z);
         }
 
         pub fn uniform_4f(&self, name: &str, x: f32, y: f32, z: f32, w: f32) {
+            self.group.check("Shader");
             let mut namez = String::from(name);
             namez.push('\0');
 
-            unsafe { gl::ProgramUniform4f(
-                self.handle,
-                gl::GetUniformLocation(
-                    self.handle, namez.as_ptr() as *const GLchar),
-                x, y, z, w); }
+            let location = unsafe { gl::GetUniformLocation(self.handle, namez.as_ptr() as *const GLchar) };
+            backend::uniform_4f(self.handle, location, x, y, z, w);
         }
 
         pub fn uniform_2fv(&self, name: &str, value: glm::Vec2) {
@@ -418,24 +962,614 @@ pub mod kat {
 
     impl Bindable for Shader {
         fn bind(&mut self) {
+            self.group.check("Shader");
             unsafe { gl::UseProgram(self.handle) }
         }
     }
 
+    impl Labeled for Shader {
+        fn set_label(&mut self, label: &str) {
+            self.group.set_label(ObjectKind::Shader, self.handle, label);
+            object_label(gl::PROGRAM, self.handle, label);
+        }
+    }
+
     impl Drop for Shader {
         fn drop(&mut self) {
-            unsafe { gl::DeleteProgram(self.handle); }
+            self.group.release(ObjectKind::Shader, self.handle);
         }
     }
 
     impl <T: Num> Drop for Buffer<T> {
         fn drop(&mut self) {
-            unsafe { gl::DeleteBuffers(1, &self.handle); }
+            self.group.release(ObjectKind::Buffer, self.handle);
+        }
+    }
+
+    /// Sized internal formats for textures that are rendered to.
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+    pub enum TextureFormat {
+        R8,
+        Rg8,
+        Rgba8,
+        Srgb8Alpha8,
+        R16F,
+        Rgba16F,
+        R32F,
+        Rgba32F,
+        Depth24,
+        Depth32F,
+        Depth24Stencil8
+    }
+
+    pub const fn translate_texture_format(format: TextureFormat) -> u32 {
+        match format {
+            TextureFormat::R8 => { gl::R8 }
+            TextureFormat::Rg8 => { gl::RG8 }
+            TextureFormat::Rgba8 => { gl::RGBA8 }
+            TextureFormat::Srgb8Alpha8 => { gl::SRGB8_ALPHA8 }
+            TextureFormat::R16F => { gl::R16F }
+            TextureFormat::Rgba16F => { gl::RGBA16F }
+            TextureFormat::R32F => { gl::R32F }
+            TextureFormat::Rgba32F => { gl::RGBA32F }
+            TextureFormat::Depth24 => { gl::DEPTH_COMPONENT24 }
+            TextureFormat::Depth32F => { gl::DEPTH_COMPONENT32F }
+            TextureFormat::Depth24Stencil8 => { gl::DEPTH24_STENCIL8 }
+        }
+    }
+
+    /// A pixel format and type `TexImage2D` accepts for `format`.
+    const fn texture_pixel_format(format: TextureFormat) -> (u32, u32) {
+        match format {
+            TextureFormat::R8 => { (gl::RED, gl::UNSIGNED_BYTE) }
+            TextureFormat::Rg8 => { (gl::RG, gl::UNSIGNED_BYTE) }
+            TextureFormat::Rgba8 | TextureFormat::Srgb8Alpha8 => { (gl::RGBA, gl::UNSIGNED_BYTE) }
+            TextureFormat::R16F => { (gl::RED, gl::HALF_FLOAT) }
+            TextureFormat::Rgba16F => { (gl::RGBA, gl::HALF_FLOAT) }
+            TextureFormat::R32F => { (gl::RED, gl::FLOAT) }
+            TextureFormat::Rgba32F => { (gl::RGBA, gl::FLOAT) }
+            TextureFormat::Depth24 => { (gl::DEPTH_COMPONENT, gl::UNSIGNED_INT) }
+            TextureFormat::Depth32F => { (gl::DEPTH_COMPONENT, gl::FLOAT) }
+            TextureFormat::Depth24Stencil8 => { (gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8) }
+        }
+    }
+
+    impl TextureFormat {
+        pub const fn is_depth(&self) -> bool {
+            matches!(self, TextureFormat::Depth24 | TextureFormat::Depth32F | TextureFormat::Depth24Stencil8)
+        }
+
+        pub const fn has_stencil(&self) -> bool {
+            matches!(self, TextureFormat::Depth24Stencil8)
+        }
+    }
+
+    pub struct Texture {
+        handle: u32,
+        width: i32,
+        height: i32,
+        format: TextureFormat,
+        group: GroupToken
+    }
+
+    impl Texture {
+        /// Allocates an uninitialized 2D texture without mipmaps.
+        pub fn create_2d(format: TextureFormat, width: i32, height: i32) -> Texture {
+            let (pixel_format, ty) = texture_pixel_format(format);
+            let i = backend::create_texture_2d(translate_texture_format(format), pixel_format, ty, width, height);
+            return Texture {
+                handle: i,
+                width,
+                height,
+                format,
+                group: context::require_current().adopt_shared(ObjectKind::Texture, i)
+            }
+        }
+
+        pub fn width(&self) -> i32 {
+            self.width
+        }
+
+        pub fn height(&self) -> i32 {
+            self.height
+        }
+
+        pub fn format(&self) -> TextureFormat {
+            self.format
+        }
+
+        /// Binds to texture unit `unit`, for samplers set to that unit.
+        pub fn bind_unit(&self, unit: u32) {
+            self.group.check("Texture");
+            backend::bind_texture_unit(unit, self.handle);
+        }
+    }
+
+    impl Labeled for Texture {
+        fn set_label(&mut self, label: &str) {
+            self.group.set_label(ObjectKind::Texture, self.handle, label);
+            object_label(gl::TEXTURE, self.handle, label);
+        }
+    }
+
+    impl Drop for Texture {
+        fn drop(&mut self) {
+            self.group.release(ObjectKind::Texture, self.handle);
+        }
+    }
+
+    /// Where a texture is attached to a framebuffer.
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+    pub enum Attachment {
+        Color(u32),
+        Depth,
+        Stencil,
+        DepthStencil
+    }
+
+    pub const fn translate_attachment(attachment: Attachment) -> u32 {
+        match attachment {
+            Attachment::Color(i) => { gl::COLOR_ATTACHMENT0 + i }
+            Attachment::Depth => { gl::DEPTH_ATTACHMENT }
+            Attachment::Stencil => { gl::STENCIL_ATTACHMENT }
+            Attachment::DepthStencil => { gl::DEPTH_STENCIL_ATTACHMENT }
+        }
+    }
+
+    pub struct Framebuffer {
+        handle: u32,
+        /// `None` for the default framebuffer, which every context has.
+        context: Option<ContextToken>
+    }
+
+    impl Framebuffer {
+        pub fn create() -> Framebuffer {
+            let i = backend::create_framebuffer();
+            return Framebuffer {
+                handle: i,
+                context: Some(context::require_current().adopt(ObjectKind::Framebuffer, i))
+            }
+        }
+
+        /// The window's default framebuffer.
+        pub const fn default_framebuffer() -> Framebuffer {
+            Framebuffer { handle: 0, context: None }
+        }
+
+        fn check_context(&self) {
+            if let Some(ctx) = &self.context {
+                ctx.check("Framebuffer");
+            }
+        }
+
+        /// Attaches `texture`. The framebuffer doesn't keep it alive; it must outlive the
+        /// attachment.
+        pub fn attach_texture(&mut self, attachment: Attachment, texture: &Texture) {
+            self.check_context();
+            texture.group.check("Texture");
+            backend::framebuffer_texture(self.handle, translate_attachment(attachment), texture.handle);
+        }
+
+        /// Draws fragment outputs 0..count to color attachments 0..count.
+        pub fn set_draw_buffers(&mut self, count: u32) {
+            self.check_context();
+            let buffers: Vec<GLenum> = (0..count).map(|i| gl::COLOR_ATTACHMENT0 + i).collect();
+            backend::framebuffer_draw_buffers(self.handle, &buffers);
+        }
+
+        /// Whether the attachments make a framebuffer that can be drawn to.
+        pub fn is_complete(&self) -> bool {
+            self.status() == gl::FRAMEBUFFER_COMPLETE
+        }
+
+        pub(crate) fn status(&self) -> GLenum {
+            self.check_context();
+            backend::framebuffer_status(self.handle)
+        }
+
+        /// Clears a single color attachment, selected by its draw buffer index.
+        pub fn clear_color(&mut self, draw_buffer: usize, color: &Color) {
+            self.check_context();
+            let values: [f32; 4] = [color.r, color.g, color.b, color.a];
+            backend::clear_framebuffer_fv(self.handle, gl::COLOR, draw_buffer as GLint, values.as_ptr());
+        }
+
+        pub fn clear_depth(&mut self, depth: f32) {
+            self.check_context();
+            backend::clear_framebuffer_fv(self.handle, gl::DEPTH, 0, &depth);
+        }
+
+        pub fn clear_stencil(&mut self, stencil: i32) {
+            self.check_context();
+            backend::clear_framebuffer_iv(self.handle, gl::STENCIL, 0, &stencil);
+        }
+
+        pub fn clear_depth_stencil(&mut self, depth: f32, stencil: i32) {
+            self.check_context();
+            backend::clear_framebuffer_fi(self.handle, depth, stencil);
+        }
+
+        /// Clears the attachments selected in `options`; the color is written to draw buffer 0.
+        pub fn clear(&mut self, options: &ClearOptions) {
+            with_scissor(options.scissor, || {
+                if let Some(color) = options.color {
+                    self.clear_color(0, &color);
+                }
+                match (options.depth, options.stencil) {
+                    (Some(depth), Some(stencil)) => self.clear_depth_stencil(depth, stencil),
+                    (Some(depth), None) => self.clear_depth(depth),
+                    (None, Some(stencil)) => self.clear_stencil(stencil),
+                    (None, None) => {}
+                }
+            });
+        }
+    }
+
+    impl Bindable for Framebuffer {
+        fn bind(&mut self) {
+            self.check_context();
+            unsafe { gl::BindFramebuffer(gl::FRAMEBUFFER, self.handle); }
+        }
+    }
+
+    impl Labeled for Framebuffer {
+        fn set_label(&mut self, label: &str) {
+            if let Some(ctx) = &self.context {
+                ctx.set_label(ObjectKind::Framebuffer, self.handle, label);
+            }
+            object_label(gl::FRAMEBUFFER, self.handle, label);
+        }
+    }
+
+    impl Drop for Framebuffer {
+        fn drop(&mut self) {
+            if let Some(ctx) = &self.context {
+                ctx.release(ObjectKind::Framebuffer, self.handle);
+            }
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+    pub enum QueryTarget {
+        SamplesPassed,
+        AnySamplesPassed,
+        AnySamplesPassedConservative,
+        PrimitivesGenerated,
+        TransformFeedbackPrimitivesWritten,
+        TimeElapsed
+    }
+
+    pub const fn translate_query_target(target: QueryTarget) -> u32 {
+        match target {
+            QueryTarget::SamplesPassed => { gl::SAMPLES_PASSED }
+            QueryTarget::AnySamplesPassed => { gl::ANY_SAMPLES_PASSED }
+            QueryTarget::AnySamplesPassedConservative => { gl::ANY_SAMPLES_PASSED_CONSERVATIVE }
+            QueryTarget::PrimitivesGenerated => { gl::PRIMITIVES_GENERATED }
+            QueryTarget::TransformFeedbackPrimitivesWritten => { gl::TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN }
+            QueryTarget::TimeElapsed => { gl::TIME_ELAPSED }
+        }
+    }
+
+    /// How draws inside a conditional render scope wait for the query result.
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+    pub enum ConditionalRenderMode {
+        Wait,
+        NoWait,
+        ByRegionWait,
+        ByRegionNoWait,
+        WaitInverted,
+        NoWaitInverted,
+        ByRegionWaitInverted,
+        ByRegionNoWaitInverted
+    }
+
+    pub const fn translate_conditional_render_mode(mode: ConditionalRenderMode) -> u32 {
+        match mode {
+            ConditionalRenderMode::Wait => { gl::QUERY_WAIT }
+            ConditionalRenderMode::NoWait => { gl::QUERY_NO_WAIT }
+            ConditionalRenderMode::ByRegionWait => { gl::QUERY_BY_REGION_WAIT }
+            ConditionalRenderMode::ByRegionNoWait => { gl::QUERY_BY_REGION_NO_WAIT }
+            ConditionalRenderMode::WaitInverted => { gl::QUERY_WAIT_INVERTED }
+            ConditionalRenderMode::NoWaitInverted => { gl::QUERY_NO_WAIT_INVERTED }
+            ConditionalRenderMode::ByRegionWaitInverted => { gl::QUERY_BY_REGION_WAIT_INVERTED }
+            ConditionalRenderMode::ByRegionNoWaitInverted => { gl::QUERY_BY_REGION_NO_WAIT_INVERTED }
+        }
+    }
+
+    pub struct Query {
+        handle: u32,
+        context: ContextToken,
+        target: QueryTarget,
+        active: bool
+    }
+
+    impl Query {
+        pub fn create(target: QueryTarget) -> Query {
+            let i = backend::create_query(translate_query_target(target));
+            return Query {
+                handle: i,
+                context: context::require_current().adopt(ObjectKind::Query, i),
+                target,
+                active: false
+            }
+        }
+
+        pub fn target(&self) -> QueryTarget {
+            self.target
+        }
+
+        pub fn begin(&mut self) {
+            assert!(!self.active, "Query is already active.");
+            self.context.check("Query");
+            unsafe { gl::BeginQuery(translate_query_target(self.target), self.handle); }
+            self.active = true;
+        }
+
+        pub fn end(&mut self) {
+            assert!(self.active, "Query is not active.");
+            self.context.check("Query");
+            unsafe { gl::EndQuery(translate_query_target(self.target)); }
+            self.active = false;
+        }
+
+        /// Begins the query and ends it when the returned guard is dropped.
+        pub fn scope(&mut self) -> QueryScope<'_> {
+            self.begin();
+            QueryScope { query: self }
+        }
+
+        /// Whether the result can be read without stalling.
+        pub fn is_available(&self) -> bool {
+            backend::query_available(self.handle)
+        }
+
+        /// The result if the GPU has produced it, without waiting.
+        pub fn try_result(&self) -> Option<u64> {
+            if self.active || !self.is_available() {
+                return None;
+            }
+            Some(self.result())
+        }
+
+        /// The result, blocking until the GPU has produced it.
+        pub fn result(&self) -> u64 {
+            assert!(!self.active, "Cannot read the result of an active query.");
+            backend::query_result(self.handle)
+        }
+
+        /// Has the GPU write the result into element `index` of `buf` without a CPU round trip.
+        /// With `wait` unset the element is left untouched if the result is not ready yet.
+        /// Needs `GlFeature::QueryBufferObjects`, which OpenGL ES lacks.
+        pub fn write_result<T: Num>(&self, buf: &mut Buffer<T>, index: usize, wait: bool) {
+            assert!(backend::has_query_buffers(), "Writing query results to buffers needs GlFeature::QueryBufferObjects.");
+            assert!(index < buf.size, "Query result index {} is out of bounds for a buffer of size {}.", index, buf.size);
+            self.context.check("Query");
+            buf.group.check("Buffer");
+            let wide = match std::mem::size_of::<T>() {
+                8 => true,
+                4 => false,
+                n => panic!("Query results can only be written to 4 or 8 byte elements, not {}.", n)
+            };
+            let pname = if wait { gl::QUERY_RESULT } else { gl::QUERY_RESULT_NO_WAIT };
+            backend::query_result_to_buffer(self.handle, buf.handle, pname, index * std::mem::size_of::<T>(), wide);
+        }
+
+        /// Draws issued while the returned guard is alive are skipped if this occlusion
+        /// query found no samples passed. Panics for queries that aren't occlusion queries.
+        pub fn conditional_render(&self, mode: ConditionalRenderMode) -> ConditionalRender<'_> {
+            assert!(matches!(self.target, QueryTarget::SamplesPassed | QueryTarget::AnySamplesPassed | QueryTarget::AnySamplesPassedConservative),
+                    "Conditional rendering needs an occlusion query, not {:?}.", self.target);
+            assert!(!self.active, "Cannot render conditionally on an active query.");
+            self.context.check("Query");
+            unsafe { gl::BeginConditionalRender(self.handle, translate_conditional_render_mode(mode)); }
+            ConditionalRender { _query: PhantomData }
+        }
+    }
+
+    pub struct QueryScope<'a> {
+        query: &'a mut Query
+    }
+
+    impl Drop for QueryScope<'_> {
+        fn drop(&mut self) {
+            self.query.end();
+        }
+    }
+
+    pub struct ConditionalRender<'a> {
+        _query: PhantomData<&'a Query>
+    }
+
+    impl Drop for ConditionalRender<'_> {
+        fn drop(&mut self) {
+            unsafe { gl::EndConditionalRender(); }
+        }
+    }
+
+    impl Labeled for Query {
+        fn set_label(&mut self, label: &str) {
+            self.context.set_label(ObjectKind::Query, self.handle, label);
+            object_label(gl::QUERY, self.handle, label);
+        }
+    }
+
+    impl Drop for Query {
+        fn drop(&mut self) {
+            self.context.release(ObjectKind::Query, self.handle);
+        }
+    }
+
+    /// The primitive type captured by transform feedback.
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+    pub enum FeedbackPrimitive {
+        Points,
+        Lines,
+        Triangles
+    }
+
+    pub const fn translate_feedback_primitive(primitive: FeedbackPrimitive) -> u32 {
+        match primitive {
+            FeedbackPrimitive::Points => { gl::POINTS }
+            FeedbackPrimitive::Lines => { gl::LINES }
+            FeedbackPrimitive::Triangles => { gl::TRIANGLES }
+        }
+    }
+
+    /// Whether draws in `mode` are allowed while capturing `primitive`.
+    pub const fn feedback_accepts(primitive: FeedbackPrimitive, mode: DrawMode) -> bool {
+        match primitive {
+            FeedbackPrimitive::Points => matches!(mode, DrawMode::Points),
+            FeedbackPrimitive::Lines => matches!(mode, DrawMode::Lines | DrawMode::LineStrip | DrawMode::LineLoop),
+            FeedbackPrimitive::Triangles => matches!(mode, DrawMode::Triangles | DrawMode::TriangleStrip | DrawMode::TriangleFan)
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+    enum FeedbackState {
+        Inactive,
+        Active(FeedbackPrimitive),
+        Paused(FeedbackPrimitive)
+    }
+
+    pub struct TransformFeedback {
+        handle: u32,
+        context: ContextToken,
+        state: FeedbackState
+    }
+
+    impl TransformFeedback {
+        /// Needs `GlFeature::TransformFeedbackObjects`.
+        pub fn new() -> TransformFeedback {
+            assert!(backend::has_transform_feedback_objects(), "Transform feedback objects need GlFeature::TransformFeedbackObjects.");
+            let i = backend::create_transform_feedback();
+            return TransformFeedback {
+                handle: i,
+                context: context::require_current().adopt(ObjectKind::TransformFeedback, i),
+                state: FeedbackState::Inactive
+            }
+        }
+
+        /// Captures output into `buf` at binding `index` (the varying index in `Separate` mode).
+        pub fn output_buffer<T: Num>(&mut self, index: usize, buf: &mut Buffer<T>) {
+            self.context.check("TransformFeedback");
+            buf.group.check("Buffer");
+            backend::transform_feedback_buffer_range(self.handle, index as u32, buf.handle, None);
+        }
+
+        /// Captures output into `count` elements of `buf` starting at element `start`.
+        pub fn output_buffer_range<T: Num>(&mut self, index: usize, buf: &mut Buffer<T>, start: usize, count: usize) {
+            self.context.check("TransformFeedback");
+            buf.group.check("Buffer");
+            assert!(start + count <= buf.size, "Transform feedback range is out of bounds for a buffer of size {}.", buf.size);
+            let size = std::mem::size_of::<T>();
+            backend::transform_feedback_buffer_range(self.handle, index as u32, buf.handle, Some((start * size, count * size)));
+        }
+
+        pub fn is_active(&self) -> bool {
+            self.state != FeedbackState::Inactive
+        }
+
+        pub fn begin(&mut self, primitive: FeedbackPrimitive) {
+            assert!(!self.is_active(), "Transform feedback is already active.");
+            self.bind();
+            unsafe { gl::BeginTransformFeedback(translate_feedback_primitive(primitive)); }
+            self.state = FeedbackState::Active(primitive);
+        }
+
+        pub fn pause(&mut self) {
+            match self.state {
+                FeedbackState::Active(p) => {
+                    unsafe { gl::PauseTransformFeedback(); }
+                    self.state = FeedbackState::Paused(p);
+                }
+                _ => panic!("Only active transform feedback can be paused.")
+            }
+        }
+
+        pub fn resume(&mut self) {
+            match self.state {
+                FeedbackState::Paused(p) => {
+                    self.bind();
+                    unsafe { gl::ResumeTransformFeedback(); }
+                    self.state = FeedbackState::Active(p);
+                }
+                _ => panic!("Only paused transform feedback can be resumed.")
+            }
+        }
+
+        pub fn end(&mut self) {
+            assert!(self.is_active(), "Transform feedback is not active.");
+            unsafe { gl::EndTransformFeedback(); }
+            self.state = FeedbackState::Inactive;
+        }
+
+        fn check_mode(&self, mode: DrawMode) {
+            match self.state {
+                FeedbackState::Active(p) => {
+                    assert!(feedback_accepts(p, mode), "Cannot draw {:?} while capturing {:?}.", mode, p);
+                }
+                _ => panic!("Transform feedback must be active to capture draws.")
+            }
+        }
+
+        /// Draws through `vao` into the capture buffers, checking `mode` against the captured primitive.
+        pub fn capture_arrays(&mut self, vao: &mut VertexArray, mode: DrawMode, count: usize, start: usize) {
+            self.check_mode(mode);
+            vao.draw_arrays(mode, count, start);
+        }
+
+        pub fn capture_elements(&mut self, vao: &mut VertexArray, mode: DrawMode, count: usize, start: i32) {
+            self.check_mode(mode);
+            vao.draw_elements(mode, count, start);
+        }
+
+        /// Draws `vao` with as many vertices as the last capture into this object produced.
+        /// Not available on OpenGL ES.
+        pub fn draw(&mut self, vao: &mut VertexArray, mode: DrawMode) {
+            self.context.check("TransformFeedback");
+            assert!(backend::has_transform_feedback_objects() && !backend::is_gles(),
+                    "Drawing from transform feedback needs GlFeature::TransformFeedbackObjects on desktop OpenGL.");
+            assert!(!self.is_active(), "Cannot draw from transform feedback that is still capturing.");
+            vao.bind();
+            unsafe { gl::DrawTransformFeedback(translate_draw_mode(mode), self.handle); }
+        }
+    }
+
+    impl Default for TransformFeedback {
+        fn default() -> Self {
+            TransformFeedback::new()
+        }
+    }
+
+    impl Bindable for TransformFeedback {
+        fn bind(&mut self) {
+            self.context.check("TransformFeedback");
+            unsafe { gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, self.handle); }
+        }
+    }
+
+    impl Labeled for TransformFeedback {
+        fn set_label(&mut self, label: &str) {
+            self.context.set_label(ObjectKind::TransformFeedback, self.handle, label);
+            object_label(gl::TRANSFORM_FEEDBACK, self.handle, label);
+        }
+    }
+
+    impl Drop for TransformFeedback {
+        fn drop(&mut self) {
+            self.context.release(ObjectKind::TransformFeedback, self.handle);
         }
     }
 
-    struct Texture {
-        handle: u32
+    /// Discards primitives before rasterization, for passes that only capture transform feedback.
+    pub fn set_rasterizer_discard(enabled: bool) {
+        unsafe {
+            if enabled {
+                gl::Enable(gl::RASTERIZER_DISCARD);
+            } else {
+                gl::Disable(gl::RASTERIZER_DISCARD);
+            }
+        }
     }
 
 }
\ No newline at end of file
@@ -1,59 +1,60 @@
-extern crate glfw;
-
-use std::borrow::BorrowMut;
-use glfw::{Action, Key};
-use glm::vec4;
 use katengine::kat;
-use katengine::kat::{Bindable, Buffer, BufferTarget, DrawMode, Shader,
-                     ShaderFile, ShaderType, VertexArray};
+use katengine::kat::mesh::shapes;
+use katengine::kat::{Action, App, Engine, Event, Key, Mesh, Shader, ShaderFile, ShaderType, Window, WindowBuilder};
 
-fn handle_events(window: &mut glfw::Window, event: glfw::WindowEvent) {
-    match event {
-        glfw::WindowEvent::Key(Key::Escape, _, Action::Press, _) => {
-            window.set_should_close(true)
-        }
-        _ => {}
-    }
+struct Scene {
+    mesh: Mesh,
+    shader: Shader
 }
 
-fn main() {
-    let mut engine = kat::Engine::create();
-    let mut window = engine.new_window(800, 800, "Hello!");
+struct TestApp {
+    background: kat::Color,
+    scene: Option<Scene>
+}
 
-    let col = kat::Color::create(209.0 / 255.0, 159.0 / 255.0, 42.0 / 255.0, 1.0);
+impl App for TestApp {
+    fn window(&self) -> WindowBuilder {
+        WindowBuilder::new(800, 800, "Hello!")
+    }
 
-    let mut vertex_buffer = Buffer::<f32>::create(
-        BufferTarget::Array,
-        vec!(
-            0.0, 0.0, 0.0, 0.0,
-            1.0, 1.0, 1.0, 1.0,
-            0.0, 1.0, 0.0, 1.0,
-            1.0, 0.0, 1.0, 0.0));
-    let mut element_buffer = Buffer::<u32>::create(
-        BufferTarget::ElementArray, vec!(0, 1, 2, 0, 3, 1));
-    let mut vertex_array = VertexArray::new();
+    fn init(&mut self, _engine: &mut Engine, _window: &mut Window) {
+        let mesh = shapes::quad(1.0, 1.0).upload();
 
-    vertex_array.vertex_buffer(&mut vertex_buffer, vec!(2, 2));
-    vertex_array.element_buffer(&mut element_buffer);
+        let shader = Shader::load(vec!(
+            ShaderFile::of("shaders/main.vsh", ShaderType::Vertex),
+            ShaderFile::of("shaders/main.fsh", ShaderType::Fragment)
+        ));
 
-    let mut shader = Shader::load(vec!(
-        ShaderFile::of("shaders/main.vsh", ShaderType::Vertex),
-        ShaderFile::of("shaders/main.fsh", ShaderType::Fragment)
-    ));
+        self.scene = Some(Scene { mesh, shader });
+    }
 
-    while window.is_open() {
-        window.update_events(engine.borrow_mut(), handle_events);
+    fn on_event(&mut self, window: &mut Window, event: Event) {
+        match event {
+            Event::Key { key: Key::Escape, action: Action::Press, .. } => {
+                window.close()
+            }
+            _ => {}
+        }
+    }
 
-        engine.clear(&col);
+    fn render(&mut self, engine: &Engine, _window: &mut Window, _alpha: f64) {
+        engine.clear(&self.background);
 
-        shader.bind();
+        let scene = self.scene.as_mut().unwrap();
+        scene.shader.uniform_color("uColor", &kat::colors::GREEN);
 
-        shader.uniform_color("uColor", &kat::colors::GREEN);
+        scene.mesh.draw(&scene.shader);
+    }
+}
 
-        vertex_array.draw_elements(DrawMode::Triangles, element_buffer.size, 0);
+fn main() {
+    let mut engine = kat::Engine::create();
+    let app = TestApp {
+        background: kat::Color::create(209.0 / 255.0, 159.0 / 255.0, 42.0 / 255.0, 1.0),
+        scene: None
+    };
 
-        window.swap();
-    }
+    engine.run(app).unwrap_or_else(|e| panic!("{}", e));
 
     println!("Goodbye!")
 }